@@ -0,0 +1,37 @@
+#![cfg(feature = "jcal")]
+
+//! jCal, the JSON format for iCalendar
+//!
+//! jCal has the same structure as iCalendar. A component is represented as
+//! `[name, properties, components]`, and a property as `[name, parameters,
+//! type, value, ...]`, where the names are lower-cased and the type is the
+//! lower-cased name of the value type:
+//!
+//! ```json
+//! ["vcalendar",
+//!   [
+//!     ["version", {}, "text", "2.0"],
+//!     ["prodid", {}, "text", "-//Example Inc.//Example Calendar//EN"]
+//!   ],
+//!   [
+//!     ["vevent",
+//!       [
+//!         ["dtstart", {"tzid": "Europe/Oslo"}, "date-time", "2024-06-26T12:00:00"],
+//!         ["rrule", {}, "recur", {"freq": "WEEKLY", "count": 4}]
+//!       ],
+//!       []
+//!     ]
+//!   ]
+//! ]
+//! ```
+//!
+//! [JCalWriter] writes jCal with the same property and component types as
+//! [crate::write::Writer], and [read] converts jCal back to iCalendar.
+//!
+//! Reference: [RFC 7265](https://www.rfc-editor.org/rfc/rfc7265)
+
+mod reader;
+mod writer;
+
+pub use reader::{read, ReadError};
+pub use writer::*;
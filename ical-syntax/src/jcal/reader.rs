@@ -0,0 +1,742 @@
+use std::fmt::Write;
+
+use serde_json::{Map, Value};
+
+use crate::{
+    structure::{icalstream::parameters, icalstream::properties::*, CompositeValueType, Property},
+    write::{text_writer::TextWriter, DynamicName, NameError, Writer},
+};
+
+/// Error for jCal that cannot be read
+#[derive(Debug)]
+pub enum ReadError {
+    /// The input is not valid JSON
+    Json(serde_json::Error),
+
+    /// The JSON is not a jCal component, property, parameter or value
+    InvalidStructure,
+
+    /// A component, property or parameter name is not valid
+    InvalidName(NameError),
+
+    /// The iCalendar could not be written to the underlying writer
+    Fmt(std::fmt::Error),
+}
+
+impl std::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Json(err) => write!(f, "invalid JSON: {err}"),
+            ReadError::InvalidStructure => write!(f, "invalid jCal structure"),
+            ReadError::InvalidName(err) => write!(f, "invalid name: {err}"),
+            ReadError::Fmt(_) => write!(f, "unable to write iCalendar"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Json(err) => Some(err),
+            ReadError::InvalidStructure => None,
+            ReadError::InvalidName(err) => Some(err),
+            ReadError::Fmt(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(err: serde_json::Error) -> Self {
+        ReadError::Json(err)
+    }
+}
+
+impl From<NameError> for ReadError {
+    fn from(err: NameError) -> Self {
+        ReadError::InvalidName(err)
+    }
+}
+
+impl From<std::fmt::Error> for ReadError {
+    fn from(err: std::fmt::Error) -> Self {
+        ReadError::Fmt(err)
+    }
+}
+
+/// Read jCal and write it as iCalendar to `ics`.
+///
+/// The input is either a single component, typically a `vcalendar`, or an
+/// array of components. The VALUE parameter is written when the type in jCal
+/// is not the default value type of the property, as given by the property
+/// types in [crate::structure::icalstream::properties].
+///
+/// The whole input is validated before anything is written: names must be
+/// valid, values must match the grammar of their value type and may not
+/// contain control characters, and parameter values may not contain DQUOTE.
+/// Invalid input is an [ReadError::InvalidStructure] or
+/// [ReadError::InvalidName] error, and nothing is written for it.
+///
+/// ```
+/// use ical_syntax::{jcal, write::Writer};
+///
+/// let mut buf = String::new();
+/// let mut ics = Writer::with_fmt(&mut buf);
+///
+/// jcal::read(
+///     r#"["vcalendar", [["version", {}, "text", "2.0"]], []]"#,
+///     &mut ics,
+/// )?;
+///
+/// assert_eq!(buf, "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nEND:VCALENDAR\r\n");
+/// # Ok::<(), jcal::ReadError>(())
+/// ```
+pub fn read<W: Write>(jcal: &str, ics: &mut Writer<W>) -> Result<(), ReadError> {
+    let json: Value = serde_json::from_str(jcal)?;
+
+    let components = match &json {
+        Value::Array(items) if matches!(items.first(), Some(Value::String(_))) => {
+            vec![ICalComponent::from_json(&json)?]
+        }
+        Value::Array(items) => items
+            .iter()
+            .map(ICalComponent::from_json)
+            .collect::<Result<_, _>>()?,
+        _ => return Err(ReadError::InvalidStructure),
+    };
+
+    for component in &components {
+        component.write(ics)?;
+    }
+    Ok(())
+}
+
+/// A component, validated and converted to iCalendar names and values
+struct ICalComponent {
+    name: DynamicName,
+    properties: Vec<ICalProperty>,
+    components: Vec<ICalComponent>,
+}
+
+impl ICalComponent {
+    fn from_json(json: &Value) -> Result<Self, ReadError> {
+        let Some([Value::String(name), Value::Array(properties), Value::Array(components)]) =
+            json.as_array().map(Vec::as_slice)
+        else {
+            return Err(ReadError::InvalidStructure);
+        };
+
+        Ok(Self {
+            name: DynamicName::new(name.to_ascii_uppercase())?,
+            properties: properties
+                .iter()
+                .map(ICalProperty::from_json)
+                .collect::<Result<_, _>>()?,
+            components: components
+                .iter()
+                .map(ICalComponent::from_json)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn write<W: Write>(&self, ics: &mut Writer<W>) -> std::fmt::Result {
+        let mut component = ics.dynamic_component(&self.name)?;
+
+        // The component must be ended even when writing its content fails
+        let mut result = Ok(());
+        for property in &self.properties {
+            result = result.and_then(|()| property.write(&mut component));
+        }
+        for subcomponent in &self.components {
+            result = result.and_then(|()| subcomponent.write(&mut component));
+        }
+
+        result.and(component.end())
+    }
+}
+
+/// A property, validated and converted to iCalendar names and values
+struct ICalProperty {
+    name: DynamicName,
+    params: Vec<(DynamicName, Vec<String>)>,
+    value_type: Option<String>,
+    value: String,
+}
+
+impl ICalProperty {
+    fn from_json(json: &Value) -> Result<Self, ReadError> {
+        let Some(
+            [Value::String(name), Value::Object(params), Value::String(value_type), values @ ..],
+        ) = json.as_array().map(Vec::as_slice)
+        else {
+            return Err(ReadError::InvalidStructure);
+        };
+        if values.is_empty() {
+            return Err(ReadError::InvalidStructure);
+        }
+
+        let name = DynamicName::new(name.to_ascii_uppercase())?;
+        // The value type is written as the VALUE parameter, so it must be a
+        // valid name
+        let value_type = DynamicName::new(value_type.to_ascii_uppercase())?
+            .as_str()
+            .to_owned();
+
+        let value = values
+            .iter()
+            .map(|value| ical_value(&value_type, value))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(",");
+
+        Ok(Self {
+            value_type: value_type_param(name.as_str(), value_type),
+            name,
+            params: ical_params(params)?,
+            value,
+        })
+    }
+
+    fn write<W: Write>(&self, ics: &mut Writer<W>) -> std::fmt::Result {
+        let mut property = ics.dynamic_property(&self.name)?;
+        if let Some(value_type) = &self.value_type {
+            property.param(parameters::Value, value_type.as_str())?;
+        }
+        for (name, values) in &self.params {
+            property.dynamic_param_values(name, values)?;
+        }
+        property.raw_value(&self.value)?;
+        property.end()
+    }
+}
+
+fn ical_params(params: &Map<String, Value>) -> Result<Vec<(DynamicName, Vec<String>)>, ReadError> {
+    params
+        .iter()
+        .map(|(name, value)| {
+            let values = match value {
+                Value::String(value) => vec![value.clone()],
+                Value::Array(values) => values
+                    .iter()
+                    .map(|value| value.as_str().map(str::to_owned))
+                    .collect::<Option<_>>()
+                    .ok_or(ReadError::InvalidStructure)?,
+                _ => return Err(ReadError::InvalidStructure),
+            };
+            // Parameter values are quoted when needed, but can never
+            // contain DQUOTE or control characters
+            if values
+                .iter()
+                .any(|value| value.contains(|c: char| c == '"' || (c.is_control() && c != '\t')))
+            {
+                return Err(ReadError::InvalidStructure);
+            }
+            Ok((DynamicName::new(name.to_ascii_uppercase())?, values))
+        })
+        .collect()
+}
+
+macro_rules! known_properties {
+    ($( $p:ty ),* $(,)?) => {
+        /// The default value type of a known property, and whether the VALUE
+        /// parameter is always written for it
+        fn known_property(name: &str) -> Option<(&'static str, bool)> {
+            $(
+                if name == <$p as Property>::NAME {
+                    return Some((
+                        <<$p as Property>::CompositeValueType as CompositeValueType>::DEFAULT_VALUE_TYPE,
+                        <$p as Property>::EXPLICIT_VALUE_TYPE,
+                    ));
+                }
+            )*
+            None
+        }
+    };
+}
+
+known_properties!(
+    alarm::Action,
+    alarm::RepeatCount,
+    alarm::Trigger,
+    alarm::Acknowledged,
+    alarm::Proximity,
+    calendar::Version,
+    calendar::ProdId,
+    calendar::CalendarScale,
+    calendar::Method,
+    change_management::DateTimeCreated,
+    change_management::DateTimeStamp,
+    change_management::LastModified,
+    change_management::SequenceNumber,
+    date_and_time::DateTimeCompleted,
+    date_and_time::DateTimeEnd,
+    date_and_time::DateTimeDue,
+    date_and_time::DateTimeStart,
+    date_and_time::DurationProperty,
+    date_and_time::FreeBusyTime,
+    date_and_time::TimeTransparency,
+    date_and_time::RecurrenceDateTimes,
+    descriptive::Attach,
+    descriptive::Categories,
+    descriptive::Classification,
+    descriptive::Comment,
+    descriptive::Description,
+    descriptive::Geo,
+    descriptive::Location,
+    descriptive::PercentComplete,
+    descriptive::Priority,
+    descriptive::Resources,
+    descriptive::Status,
+    descriptive::Summary,
+    misc::RequestStatus,
+    recurrence::ExceptionDateTimes,
+    recurrence::RecurrenceRule,
+    relationship::Attendee,
+    relationship::Contact,
+    relationship::Organizer,
+    relationship::RecurrenceId,
+    relationship::RelatedTo,
+    relationship::Url,
+    relationship::Uid,
+    rfc7986::Name,
+    rfc7986::RefreshInterval,
+    rfc7986::Source,
+    rfc7986::Color,
+    rfc7986::Image,
+    rfc7986::Conference,
+    rfc9073::LocationType,
+    rfc9073::ParticipantType,
+    rfc9073::ResourceType,
+    rfc9073::CalendarAddress,
+    rfc9073::StyledDescription,
+    rfc9073::StructuredData,
+    time_zone::TimeZoneIdentifier,
+    time_zone::TimeZoneName,
+    time_zone::TimeZoneOffsetFrom,
+    time_zone::TimeZoneOffsetTo,
+    time_zone::TimeZoneUrl,
+);
+
+/// The VALUE parameter to write, if any. The default value type of unknown
+/// properties is TEXT, while the value of the jCal type `unknown` is written
+/// as-is, without a VALUE parameter.
+fn value_type_param(property_name: &str, value_type: String) -> Option<String> {
+    let (default_value_type, explicit) = known_property(property_name).unwrap_or(("TEXT", false));
+
+    if value_type == "UNKNOWN" || (value_type == default_value_type && !explicit) {
+        None
+    } else {
+        Some(value_type)
+    }
+}
+
+/// Convert a jCal value to the iCalendar form. Structured values are arrays,
+/// and their parts are separated by `;`.
+fn ical_value(value_type: &str, value: &Value) -> Result<String, ReadError> {
+    match value {
+        Value::Array(parts) if value_type == "PERIOD" => match &parts[..] {
+            [start @ Value::String(_), end @ Value::String(end_str)] => {
+                let end_type = if end_str.contains('P') {
+                    "DURATION"
+                } else {
+                    "DATE-TIME"
+                };
+                Ok(format!(
+                    "{}/{}",
+                    ical_scalar("DATE-TIME", start)?,
+                    ical_scalar(end_type, end)?
+                ))
+            }
+            _ => Err(ReadError::InvalidStructure),
+        },
+        Value::Array(parts) => Ok(parts
+            .iter()
+            .map(|part| match part {
+                // A part with multiple values
+                Value::Array(values) => Ok(values
+                    .iter()
+                    .map(|value| ical_scalar(value_type, value))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(",")),
+                part => ical_scalar(value_type, part),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join(";")),
+        Value::Object(recur) if value_type == "RECUR" => ical_recur(recur),
+        value => ical_scalar(value_type, value),
+    }
+}
+
+/// Convert a single jCal value to the iCalendar form, and check that the
+/// result is valid for the value type
+fn ical_scalar(value_type: &str, value: &Value) -> Result<String, ReadError> {
+    let scalar = convert_scalar(value_type, value)?;
+    if is_valid_scalar(value_type, &scalar) {
+        Ok(scalar)
+    } else {
+        Err(ReadError::InvalidStructure)
+    }
+}
+
+fn convert_scalar(value_type: &str, value: &Value) -> Result<String, ReadError> {
+    match value {
+        Value::String(value) => Ok(match value_type {
+            "TEXT" => {
+                let mut text = String::new();
+                write!(TextWriter::new(&mut text), "{value}")?;
+                text
+            }
+            "DATE" | "DATE-TIME" | "TIME" => value.replace(['-', ':'], ""),
+            "UTC-OFFSET" => value.replace(':', ""),
+            _ => value.clone(),
+        }),
+        // Formatted without an exponent, which is not allowed in iCalendar
+        Value::Number(number) => Ok(match (number.as_i64(), number.as_u64()) {
+            (Some(integer), _) => integer.to_string(),
+            (_, Some(integer)) => integer.to_string(),
+            _ => number
+                .as_f64()
+                .ok_or(ReadError::InvalidStructure)?
+                .to_string(),
+        }),
+        Value::Bool(true) => Ok("TRUE".to_owned()),
+        Value::Bool(false) => Ok("FALSE".to_owned()),
+        _ => Err(ReadError::InvalidStructure),
+    }
+}
+
+/// Check a value in the iCalendar form against the grammar of its value type.
+/// Values of other types may contain anything but control characters.
+fn is_valid_scalar(value_type: &str, value: &str) -> bool {
+    fn digits(value: &str, count: usize) -> bool {
+        value.len() == count && value.bytes().all(|b| b.is_ascii_digit())
+    }
+    fn time(value: &str) -> bool {
+        digits(value.strip_suffix('Z').unwrap_or(value), 6)
+    }
+    fn unsigned(value: &str) -> &str {
+        value.strip_prefix(['+', '-']).unwrap_or(value)
+    }
+
+    if value.contains(|c: char| c.is_control() && c != '\t') {
+        return false;
+    }
+
+    match value_type {
+        "BOOLEAN" => value == "TRUE" || value == "FALSE",
+        "DATE" => digits(value, 8),
+        "DATE-TIME" => value
+            .split_once('T')
+            .is_some_and(|(date, time_part)| digits(date, 8) && time(time_part)),
+        "TIME" => time(value),
+        "DURATION" => unsigned(value).strip_prefix('P').is_some_and(|rest| {
+            !rest.is_empty()
+                && rest
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || "WDTHMS".contains(c))
+        }),
+        "FLOAT" => {
+            let (integer, fraction) = unsigned(value)
+                .split_once('.')
+                .unwrap_or((unsigned(value), "0"));
+            !integer.is_empty()
+                && !fraction.is_empty()
+                && (integer.bytes().chain(fraction.bytes())).all(|b| b.is_ascii_digit())
+        }
+        "INTEGER" => {
+            !unsigned(value).is_empty()
+                && unsigned(value).bytes().all(|b| b.is_ascii_digit())
+                && value.parse::<i32>().is_ok()
+        }
+        "UTC-OFFSET" => {
+            value.starts_with(['+', '-']) && (digits(&value[1..], 4) || digits(&value[1..], 6))
+        }
+        _ => true,
+    }
+}
+
+/// Check a name or value of a RECUR rule part, which must not contain the
+/// separators of the rule
+fn is_valid_recur_token(token: &str) -> bool {
+    !token.is_empty() && !token.contains(|c: char| c.is_control() || ";=,".contains(c))
+}
+
+/// The rule parts of a RECUR value, in the order they are written by
+/// [crate::write::value_types::Recur]
+const RECUR_RULE_PARTS: &[&str] = &[
+    "freq",
+    "rscale",
+    "until",
+    "count",
+    "interval",
+    "bysecond",
+    "byminute",
+    "byhour",
+    "byday",
+    "bymonthday",
+    "byyearday",
+    "byweekno",
+    "bymonth",
+    "bysetpos",
+    "skip",
+    "wkst",
+];
+
+fn ical_recur(recur: &Map<String, Value>) -> Result<String, ReadError> {
+    let known = RECUR_RULE_PARTS
+        .iter()
+        .filter_map(|&name| recur.get_key_value(name));
+    let other = recur
+        .iter()
+        .filter(|(name, _)| !RECUR_RULE_PARTS.contains(&name.as_str()));
+
+    let rule_part_value = |name: &str, value: &Value| {
+        let valid = if name == "until" {
+            convert_scalar("DATE-TIME", value).ok().filter(|value| {
+                is_valid_scalar("DATE-TIME", value) || is_valid_scalar("DATE", value)
+            })
+        } else {
+            convert_scalar("", value)
+                .ok()
+                .filter(|value| is_valid_recur_token(value))
+        };
+        valid.ok_or(ReadError::InvalidStructure)
+    };
+
+    let mut rule_parts = Vec::new();
+    for (name, value) in known.chain(other) {
+        if !is_valid_recur_token(name) {
+            return Err(ReadError::InvalidStructure);
+        }
+        let value = match value {
+            Value::Array(values) => values
+                .iter()
+                .map(|value| rule_part_value(name, value))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(","),
+            value => rule_part_value(name, value)?,
+        };
+        rule_parts.push(format!("{}={value}", name.to_ascii_uppercase()));
+    }
+    Ok(rule_parts.join(";"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{read, ReadError};
+    use crate::write::Writer;
+
+    fn to_ics(jcal: &str) -> Result<String, ReadError> {
+        let mut buf = String::new();
+        read(jcal, &mut Writer::with_fmt(&mut buf))?;
+        Ok(buf)
+    }
+
+    #[test]
+    fn jcal_reader() -> Result<(), ReadError> {
+        let ics = to_ics(
+            r#"["vcalendar",
+              [
+                ["version", {}, "text", "2.0"],
+                ["prodid", {}, "text", "-//test//"]
+              ],
+              [
+                ["vevent",
+                  [
+                    ["uid", {}, "text", "unique identifier"],
+                    ["dtstamp", {}, "date-time", "2024-06-26T12:00:00Z"],
+                    ["dtstart", {"tzid": "Europe/Oslo"}, "date-time", "2024-06-26T14:00:00"],
+                    ["dtend", {}, "date", "2024-06-27"],
+                    ["summary", {"language": "en-US"}, "text", "Lunch, then a meeting;\nbring notes"],
+                    ["categories", {}, "text", "lunch", "meeting"],
+                    ["geo", {}, "float", [37.386013, -122.082932]],
+                    ["request-status", {}, "text", ["2.0", "Success"]],
+                    ["rrule", {}, "recur", {"byday": ["MO", "TU"], "count": 4, "freq": "WEEKLY"}],
+                    ["rdate", {}, "period", ["2024-07-01T12:00:00Z", "PT1H"]],
+                    ["attendee", {"delegated-from": ["mailto:a@example.com", "mailto:b@example.com"], "partstat": "ACCEPTED"}, "cal-address", "mailto:c@example.com"],
+                    ["x-flags", {}, "boolean", true, false],
+                    ["x-raw", {}, "unknown", "a;b"]
+                  ],
+                  []
+                ]
+              ]
+            ]"#,
+        )?;
+
+        assert_eq!(
+            ics,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:unique identifier\r\n\
+            DTSTAMP:20240626T120000Z\r\n\
+            DTSTART;TZID=Europe/Oslo:20240626T140000\r\n\
+            DTEND;VALUE=DATE:20240627\r\n\
+            SUMMARY;LANGUAGE=en-US:Lunch\\, then a meeting\\;\\nbring notes\r\n\
+            CATEGORIES:lunch,meeting\r\n\
+            GEO:37.386013;-122.082932\r\n\
+            REQUEST-STATUS:2.0;Success\r\n\
+            RRULE:FREQ=WEEKLY;COUNT=4;BYDAY=MO,TU\r\n\
+            RDATE;VALUE=PERIOD:20240701T120000Z/PT1H\r\n\
+            ATTENDEE;DELEGATED-FROM=\"mailto:a@example.com\",\"mailto:b@example.com\";PARTS\r\n \
+            TAT=ACCEPTED:mailto:c@example.com\r\n\
+            X-FLAGS;VALUE=BOOLEAN:TRUE,FALSE\r\n\
+            X-RAW:a;b\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_jcal() {
+        assert!(matches!(to_ics("[\"vcalendar\""), Err(ReadError::Json(_))));
+        assert!(matches!(
+            to_ics(r#"["vcalendar", [], {}]"#),
+            Err(ReadError::InvalidStructure)
+        ));
+        assert!(matches!(
+            to_ics(r#"["vcalendar", [["version", {}, "text"]], []]"#),
+            Err(ReadError::InvalidStructure)
+        ));
+        assert!(matches!(
+            to_ics(r#"["vcalendar", [["ver sion", {}, "text", "2.0"]], []]"#),
+            Err(ReadError::InvalidName(_))
+        ));
+    }
+
+    /// Every property in [crate::structure::icalstream::properties] must be
+    /// listed in `known_properties!`
+    #[test]
+    fn known_properties() {
+        let sources = [
+            include_str!("../structure/icalstream/properties/alarm.rs"),
+            include_str!("../structure/icalstream/properties/calendar.rs"),
+            include_str!("../structure/icalstream/properties/change_management.rs"),
+            include_str!("../structure/icalstream/properties/date_and_time.rs"),
+            include_str!("../structure/icalstream/properties/descriptive.rs"),
+            include_str!("../structure/icalstream/properties/misc.rs"),
+            include_str!("../structure/icalstream/properties/recurrence.rs"),
+            include_str!("../structure/icalstream/properties/relationship.rs"),
+            include_str!("../structure/icalstream/properties/rfc7986.rs"),
+            include_str!("../structure/icalstream/properties/rfc9073.rs"),
+            include_str!("../structure/icalstream/properties/time_zone.rs"),
+        ];
+
+        let mut count = 0;
+        for source in sources {
+            for property in source.split("\nimpl Property for ").skip(1) {
+                let name = property
+                    .split_once("const NAME: &'static str = \"")
+                    .and_then(|(_, rest)| rest.split_once('"'))
+                    .map(|(name, _)| name)
+                    .unwrap();
+                assert!(super::known_property(name).is_some(), "{name}");
+                count += 1;
+            }
+        }
+        assert!(count > 50);
+    }
+
+    #[test]
+    fn invalid_values() {
+        for jcal in [
+            r#"["vevent",[["url",{},"uri","https://a\r\nBEGIN:VTODO"]],[]]"#,
+            r#"["vevent",[["summary",{},"text","a\rb"]],[]]"#,
+            r#"["vevent",[["summary",{"x-a":"b\"c"},"text","a"]],[]]"#,
+            r#"["vevent",[["summary",{"x-a":["b","c\n"]},"text","a"]],[]]"#,
+            r#"["vevent",[["dtstart",{},"date","2024-6-26"]],[]]"#,
+            r#"["vevent",[["dtstart",{},"date-time","2024-06-26T12:00"]],[]]"#,
+            r#"["vevent",[["dtstart",{},"date-time","2024-06-26T12:00:00;X=Y"]],[]]"#,
+            r#"["vevent",[["geo",{},"float",[1e20,"1e20"]]],[]]"#,
+            r#"["vevent",[["priority",{},"integer",1.5]],[]]"#,
+            r#"["vevent",[["priority",{},"integer","9999999999"]],[]]"#,
+            r#"["vevent",[["rrule",{},"recur",{"freq":"DAILY;COUNT=2"}]],[]]"#,
+            r#"["vevent",[["rrule",{},"recur",{"x-a=b":"c"}]],[]]"#,
+            r#"["vevent",[["rrule",{},"recur",{"freq":"DAILY","until":"2024"}]],[]]"#,
+            r#"["vevent",[["rdate",{},"period",["2024-07-01T12:00:00Z","1H"]]],[]]"#,
+        ] {
+            let mut buf = String::new();
+            let result = read(jcal, &mut Writer::with_fmt(&mut buf));
+            assert!(
+                matches!(result, Err(ReadError::InvalidStructure)),
+                "{jcal}: {result:?}"
+            );
+            assert_eq!(buf, "", "{jcal}");
+        }
+
+        assert!(matches!(
+            to_ics(r#"["vevent",[["summary",{},"text\r\n","a"]],[]]"#),
+            Err(ReadError::InvalidName(_))
+        ));
+    }
+
+    #[test]
+    fn float_without_exponent() -> Result<(), ReadError> {
+        assert_eq!(
+            to_ics(r#"["vevent",[["geo",{},"float",[1e20,-0.5]]],[]]"#)?,
+            "BEGIN:VEVENT\r\nGEO:100000000000000000000;-0.5\r\nEND:VEVENT\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn round_trip() -> Result<(), ReadError> {
+        use crate::{
+            jcal::JCalWriter,
+            structure::icalstream::{
+                components::{EventC, ICalObject},
+                properties::{
+                    calendar::{ProdId, Version},
+                    change_management::DateTimeStamp,
+                    date_and_time::DateTimeStart,
+                    descriptive::{Categories, Geo, Summary},
+                    recurrence::RecurrenceRule,
+                    relationship::Uid,
+                },
+            },
+            write::value_types::{Frequency, Recur, Weekday},
+        };
+
+        let dtstamp = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        macro_rules! write_calendar {
+            ($writer:expr) => {{
+                let mut ico = $writer.component(ICalObject)?;
+                ico.simple_property(Version, "2.0")?;
+                ico.simple_property(ProdId, "-//test//")?;
+
+                let mut ev = ico.component(EventC)?;
+                ev.simple_property(DateTimeStamp, dtstamp)?;
+                ev.simple_property(Uid, "unique identifier")?;
+                ev.simple_property(DateTimeStart, dtstamp.date_naive())?;
+                ev.simple_property(Summary, "a, b; c\\d")?;
+                ev.simple_property(Categories, ["lunch", "meeting"])?;
+                ev.simple_property(Geo, (37.386013, -122.082932))?;
+                ev.simple_property(
+                    RecurrenceRule,
+                    Recur::new(Frequency::Monthly)
+                        .until(dtstamp)
+                        .by_day([Weekday::Friday.nth(-1)])
+                        .by_month([1, 6]),
+                )?;
+                ev.end()?;
+
+                ico.end()?;
+            }};
+        }
+
+        let mut ics = String::new();
+        let mut ics_writer = Writer::with_fmt(&mut ics);
+        write_calendar!(ics_writer);
+
+        let mut jcal = String::new();
+        let mut jcal_writer = JCalWriter::with_fmt(&mut jcal);
+        write_calendar!(jcal_writer);
+
+        assert_eq!(to_ics(&jcal)?, ics);
+        Ok(())
+    }
+}
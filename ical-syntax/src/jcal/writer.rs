@@ -0,0 +1,865 @@
+use std::{
+    fmt::Write,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    structure::*,
+    write::{
+        composite_value_types::AsCompositeValueType, AsParamValue, ContentLine, DynamicName,
+        DynamicPropertyWriter, LineStream, PropertyWriter,
+    },
+};
+
+/// A component that has been started, but not ended
+struct OpenComponent {
+    name: String,
+    properties: Vec<Value>,
+    components: Vec<Value>,
+}
+
+/// Writer for jCal, with the same interface as [crate::write::Writer].
+///
+/// The values are formatted as for iCalendar and then converted to their
+/// jCal form, so everything that can be written as iCalendar can be written
+/// as jCal. Each top-level component is written as one JSON text when it is
+/// ended.
+///
+/// ```
+/// use ical_syntax::{
+///     jcal::JCalWriter,
+///     structure::icalstream::{
+///         components::ICalObject,
+///         properties::calendar::{ProdId, Version},
+///     },
+/// };
+///
+/// let mut buf = String::new();
+/// let mut jcal = JCalWriter::with_fmt(&mut buf);
+///
+/// let mut ico = jcal.component(ICalObject)?;
+/// ico.simple_property(Version, "2.0")?;
+/// ico.simple_property(ProdId, "-//test//")?;
+/// ico.end()?;
+///
+/// assert_eq!(
+///     buf,
+///     r#"["vcalendar",[["version",{},"text","2.0"],["prodid",{},"text","-//test//"]],[]]"#
+/// );
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+pub struct JCalWriter<W> {
+    inner: W,
+    open_components: Vec<OpenComponent>,
+}
+
+impl<W: Write> JCalWriter<W> {
+    pub fn with_fmt(inner: W) -> Self {
+        Self {
+            inner,
+            open_components: Vec::new(),
+        }
+    }
+
+    pub fn component<'x, 'y: 'x, C: Component>(
+        &'y mut self,
+        _component: C,
+    ) -> Result<JCalComponentWriter<'x, W, C>, std::fmt::Error> {
+        Ok(JCalComponentWriter {
+            inner: self.begin_component(C::NAME),
+            is_closed: false,
+            _component: PhantomData,
+        })
+    }
+
+    pub fn property<'x, 'y: 'x, P: Property>(
+        &'y mut self,
+        _property: P,
+    ) -> Result<JCalPropertyWriter<'x, W, P>, std::fmt::Error> {
+        Ok(JCalPropertyWriter {
+            inner: self,
+            property: JCalProperty::new(P::NAME),
+            _property: PhantomData,
+        })
+    }
+
+    pub fn simple_property<P: Property>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        let mut p = self.property(property)?;
+        p.value(value)?;
+        p.end()
+    }
+
+    /// Begin a component with a name that is only known at runtime, such as
+    /// a non-standard `X-` component
+    pub fn dynamic_component<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<JCalDynamicComponentWriter<'x, W>, std::fmt::Error> {
+        Ok(JCalDynamicComponentWriter {
+            inner: self.begin_component(name.as_str()),
+            is_closed: false,
+        })
+    }
+
+    /// Begin a property with a name that is only known at runtime, such as
+    /// a non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<JCalDynamicPropertyWriter<'x, W>, std::fmt::Error> {
+        Ok(JCalDynamicPropertyWriter {
+            inner: self,
+            name: name.clone(),
+            property: JCalProperty::new(name.as_str()),
+        })
+    }
+
+    fn begin_component(&mut self, name: &str) -> &mut Self {
+        self.open_components.push(OpenComponent {
+            name: name.to_ascii_lowercase(),
+            properties: Vec::new(),
+            components: Vec::new(),
+        });
+        self
+    }
+
+    fn end_component(&mut self) -> std::fmt::Result {
+        let component = self.open_components.pop().ok_or(std::fmt::Error)?;
+        let component = Value::Array(vec![
+            Value::String(component.name),
+            Value::Array(component.properties),
+            Value::Array(component.components),
+        ]);
+
+        match self.open_components.last_mut() {
+            Some(parent) => {
+                parent.components.push(component);
+                Ok(())
+            }
+            None => {
+                let json = serde_json::to_string(&component).map_err(|_| std::fmt::Error)?;
+                self.inner.write_str(&json)
+            }
+        }
+    }
+
+    fn end_property(&mut self, property: JCalProperty) -> std::fmt::Result {
+        // Properties can only be written inside of a component
+        let component = self.open_components.last_mut().ok_or(std::fmt::Error)?;
+        component.properties.push(property.into_json()?);
+        Ok(())
+    }
+}
+
+/// Writer for a jCal component. See [crate::write::ComponentWriter].
+pub struct JCalComponentWriter<'a, W, C> {
+    inner: &'a mut JCalWriter<W>,
+    is_closed: bool,
+    _component: PhantomData<C>,
+}
+
+impl<W: Write, C: Component> JCalComponentWriter<'_, W, C> {
+    pub fn end(mut self) -> std::fmt::Result {
+        self.is_closed = true;
+        self.inner.end_component()
+    }
+}
+
+impl<W, C> Drop for JCalComponentWriter<'_, W, C> {
+    fn drop(&mut self) {
+        assert!(
+            self.is_closed,
+            "JCalComponentWriter::end() must be called before drop"
+        );
+    }
+}
+
+impl<W: Write, C: Component> Deref for JCalComponentWriter<'_, W, C> {
+    type Target = JCalWriter<W>;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<W: Write, C: Component> DerefMut for JCalComponentWriter<'_, W, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+    }
+}
+
+/// Writer for a jCal component with a name that is only known at runtime.
+/// See [crate::write::DynamicComponentWriter].
+pub struct JCalDynamicComponentWriter<'a, W> {
+    inner: &'a mut JCalWriter<W>,
+    is_closed: bool,
+}
+
+impl<W: Write> JCalDynamicComponentWriter<'_, W> {
+    pub fn end(mut self) -> std::fmt::Result {
+        self.is_closed = true;
+        self.inner.end_component()
+    }
+}
+
+impl<W> Drop for JCalDynamicComponentWriter<'_, W> {
+    fn drop(&mut self) {
+        assert!(
+            self.is_closed,
+            "JCalDynamicComponentWriter::end() must be called before drop"
+        );
+    }
+}
+
+impl<W: Write> Deref for JCalDynamicComponentWriter<'_, W> {
+    type Target = JCalWriter<W>;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<W: Write> DerefMut for JCalDynamicComponentWriter<'_, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+    }
+}
+
+/// Writer for a jCal property. See [crate::write::PropertyWriter].
+pub struct JCalPropertyWriter<'a, W, P> {
+    inner: &'a mut JCalWriter<W>,
+    property: JCalProperty,
+    _property: PhantomData<P>,
+}
+
+impl<W: Write, P: Property> JCalPropertyWriter<'_, W, P> {
+    /// Write a parameter. Only the parameters that are allowed on the
+    /// property can be written this way, see [AcceptsParam].
+    pub fn param<PP: Param>(
+        &mut self,
+        _param: PP,
+        value: impl AsParamValue<PP::ParamValueType>,
+    ) -> std::fmt::Result
+    where
+        P: AcceptsParam<PP>,
+    {
+        let content_line = param_content_line::<PP>(P::NAME, value)?;
+        self.property.params_from_content_line(&content_line)
+    }
+
+    /// Write a parameter with a name that is only known at runtime, such as
+    /// an IANA or non-standard parameter, which are allowed on all
+    /// properties.
+    pub fn dynamic_param(
+        &mut self,
+        name: &DynamicName,
+        value: impl std::fmt::Display,
+    ) -> std::fmt::Result {
+        self.property.param(name.as_str(), vec![value.to_string()]);
+        Ok(())
+    }
+
+    pub fn value(
+        &mut self,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut p = PropertyWriter::<_, P>::new(&mut line_stream)?;
+        if let Err(err) = p.value(value) {
+            p.discard();
+            return Err(err);
+        }
+        p.end()?;
+
+        self.property
+            .value_from_content_line(&buf, P::CompositeValueType::DEFAULT_VALUE_TYPE)
+    }
+
+    pub fn end(self) -> std::fmt::Result {
+        self.inner.end_property(self.property)
+    }
+}
+
+/// Writer for a jCal property with a name that is only known at runtime.
+/// See [crate::write::DynamicPropertyWriter].
+pub struct JCalDynamicPropertyWriter<'a, W> {
+    inner: &'a mut JCalWriter<W>,
+    name: DynamicName,
+    property: JCalProperty,
+}
+
+impl<W: Write> JCalDynamicPropertyWriter<'_, W> {
+    pub fn param<PP: Param>(
+        &mut self,
+        _param: PP,
+        value: impl AsParamValue<PP::ParamValueType>,
+    ) -> std::fmt::Result {
+        let content_line = param_content_line::<PP>(self.name.as_str(), value)?;
+        self.property.params_from_content_line(&content_line)
+    }
+
+    /// Write a parameter with a name that is only known at runtime
+    pub fn dynamic_param(
+        &mut self,
+        name: &DynamicName,
+        value: impl std::fmt::Display,
+    ) -> std::fmt::Result {
+        self.property.param(name.as_str(), vec![value.to_string()]);
+        Ok(())
+    }
+
+    /// Write the value as the value type `V`
+    pub fn value<V: ValueType>(&mut self, value: impl AsCompositeValueType<V>) -> std::fmt::Result {
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut p = DynamicPropertyWriter::new(&mut line_stream, &self.name)?;
+        if let Err(err) = p.value::<V>(value) {
+            p.discard();
+            return Err(err);
+        }
+        p.end()?;
+
+        self.property.value_from_content_line(&buf, V::NAME)
+    }
+
+    /// Write the values as a list of the value type `V`
+    pub fn list_value<V: ValueType>(
+        &mut self,
+        values: impl AsCompositeValueType<composite_value_types::List<V>>,
+    ) -> std::fmt::Result {
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut p = DynamicPropertyWriter::new(&mut line_stream, &self.name)?;
+        if let Err(err) = p.list_value::<V>(values) {
+            p.discard();
+            return Err(err);
+        }
+        p.end()?;
+
+        self.property.list_value_from_content_line(&buf, V::NAME)
+    }
+
+    pub fn end(self) -> std::fmt::Result {
+        self.inner.end_property(self.property)
+    }
+}
+
+/// Write a parameter as a content line with an empty value to a scratch
+/// buffer. The line is discarded when the parameter value is invalid.
+fn param_content_line<PP: Param>(
+    property_name: &str,
+    value: impl AsParamValue<PP::ParamValueType>,
+) -> Result<String, std::fmt::Error> {
+    let mut buf = String::new();
+    let mut content_line = ContentLine::new(&mut buf);
+    let result = (|| {
+        content_line.name(property_name)?;
+        content_line.param_name(PP::NAME)?;
+        value.write_to(&mut content_line)?;
+        content_line.value("")
+    })();
+    match result {
+        Ok(()) => content_line.eol()?,
+        Err(err) => {
+            content_line.discard();
+            return Err(err);
+        }
+    }
+    Ok(buf)
+}
+
+/// A property under construction, collected from iCalendar content lines
+struct JCalProperty {
+    name: String,
+    params: Map<String, Value>,
+    value: Option<(String, Vec<Value>)>,
+}
+
+impl JCalProperty {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_ascii_lowercase(),
+            params: Map::new(),
+            value: None,
+        }
+    }
+
+    fn param(&mut self, name: &str, mut values: Vec<String>) {
+        let value = if values.len() == 1 {
+            Value::String(values.remove(0))
+        } else {
+            values.into_iter().map(Value::String).collect()
+        };
+        self.params.insert(name.to_ascii_lowercase(), value);
+    }
+
+    fn params_from_content_line(&mut self, content_line: &str) -> std::fmt::Result {
+        let (params, _) = split_content_line(content_line).ok_or(std::fmt::Error)?;
+        for (name, values) in params {
+            self.param(&name, values);
+        }
+        Ok(())
+    }
+
+    /// Take the parameters and value from a content line. The VALUE
+    /// parameter selects the type, and is not written as a parameter in jCal.
+    fn value_from_content_line(
+        &mut self,
+        content_line: &str,
+        default_value_type: &str,
+    ) -> std::fmt::Result {
+        self.take_value(content_line, default_value_type, false)
+    }
+
+    /// Like [Self::value_from_content_line], for a value that is known to be
+    /// a list, so that values of types that are not escaped, such as URI, are
+    /// split at `,` as well
+    fn list_value_from_content_line(
+        &mut self,
+        content_line: &str,
+        default_value_type: &str,
+    ) -> std::fmt::Result {
+        self.take_value(content_line, default_value_type, true)
+    }
+
+    fn take_value(
+        &mut self,
+        content_line: &str,
+        default_value_type: &str,
+        is_list: bool,
+    ) -> std::fmt::Result {
+        let (params, value) = split_content_line(content_line).ok_or(std::fmt::Error)?;
+
+        let mut value_type = default_value_type.to_owned();
+        for (name, mut values) in params {
+            if name.eq_ignore_ascii_case("VALUE") {
+                value_type = values.pop().ok_or(std::fmt::Error)?;
+            } else {
+                self.param(&name, values);
+            }
+        }
+
+        let values = json_values(&value_type, &value, is_list);
+        self.value = Some((value_type.to_ascii_lowercase(), values));
+        Ok(())
+    }
+
+    fn into_json(self) -> Result<Value, std::fmt::Error> {
+        let (value_type, values) = self.value.ok_or(std::fmt::Error)?;
+
+        let mut property = vec![
+            Value::String(self.name),
+            Value::Object(self.params),
+            Value::String(value_type),
+        ];
+        property.extend(values);
+        Ok(Value::Array(property))
+    }
+}
+
+type Params = Vec<(String, Vec<String>)>;
+
+/// Split a content line, as written by this crate, into the parameters and
+/// the value
+fn split_content_line(content_line: &str) -> Option<(Params, String)> {
+    let unfolded = content_line.replace("\r\n ", "");
+    let line = unfolded.strip_suffix("\r\n")?;
+
+    let mut rest = &line[line.find([';', ':'])?..];
+    let mut params = Vec::new();
+    while let Some(param) = rest.strip_prefix(';') {
+        let (name, mut param_values) = param.split_once('=')?;
+        let mut values = Vec::new();
+        loop {
+            let (value, after) = match param_values.strip_prefix('"') {
+                Some(quoted) => {
+                    let end = quoted.find('"')?;
+                    (&quoted[..end], &quoted[end + 1..])
+                }
+                None => param_values.split_at(param_values.find([',', ';', ':'])?),
+            };
+            values.push(value.to_owned());
+
+            match after.strip_prefix(',') {
+                Some(after) => param_values = after,
+                None => {
+                    rest = after;
+                    break;
+                }
+            }
+        }
+        params.push((name.to_owned(), values));
+    }
+
+    Some((params, rest.strip_prefix(':')?.to_owned()))
+}
+
+/// Split at the separator where it is not escaped with a backslash
+fn split_unescaped(value: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == separator {
+            parts.push(&value[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+/// Convert the value of a content line to the jCal values. Multiple values
+/// are separate values in jCal, and structured values are arrays.
+///
+/// Values of the types that are not escaped may contain `,` and `;`, so
+/// they are only split when they are known to be a list. A list of such
+/// values is as ambiguous in iCalendar as it is here.
+fn json_values(value_type: &str, value: &str, is_list: bool) -> Vec<Value> {
+    if value_type.eq_ignore_ascii_case("RECUR") {
+        return vec![json_recur(value)];
+    }
+    if [value_types::Uri::NAME, value_types::CalAddress::NAME]
+        .iter()
+        .any(|name| value_type.eq_ignore_ascii_case(name))
+    {
+        return if is_list {
+            value
+                .split(',')
+                .map(|value| json_value(value_type, value))
+                .collect()
+        } else {
+            vec![json_value(value_type, value)]
+        };
+    }
+
+    split_unescaped(value, ',')
+        .into_iter()
+        .map(|value| {
+            let parts = split_unescaped(value, ';');
+            if parts.len() > 1 {
+                parts
+                    .into_iter()
+                    .map(|part| json_value(value_type, part))
+                    .collect()
+            } else {
+                json_value(value_type, value)
+            }
+        })
+        .collect()
+}
+
+fn json_value(value_type: &str, value: &str) -> Value {
+    match &value_type.to_ascii_uppercase()[..] {
+        "TEXT" => Value::String(unescape_text(value)),
+        "BOOLEAN" => match value {
+            "TRUE" => Value::Bool(true),
+            "FALSE" => Value::Bool(false),
+            _ => Value::String(value.to_owned()),
+        },
+        "INTEGER" => json_integer(value),
+        "FLOAT" => value
+            .parse()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or_else(|| Value::String(value.to_owned())),
+        "DATE" => Value::String(json_date(value)),
+        "DATE-TIME" => Value::String(json_date_time(value)),
+        "TIME" => Value::String(json_time(value)),
+        "UTC-OFFSET" => Value::String(json_utc_offset(value)),
+        "PERIOD" => match value.split_once('/') {
+            Some((start, end)) => {
+                let end = if end.contains('P') {
+                    end.to_owned()
+                } else {
+                    json_date_time(end)
+                };
+                Value::Array(vec![
+                    Value::String(json_date_time(start)),
+                    Value::String(end),
+                ])
+            }
+            None => Value::String(value.to_owned()),
+        },
+        _ => Value::String(value.to_owned()),
+    }
+}
+
+fn json_integer(value: &str) -> Value {
+    value
+        .parse::<i64>()
+        .map(Value::from)
+        .unwrap_or_else(|_| Value::String(value.to_owned()))
+}
+
+/// RECUR values are objects with the lower-cased rule parts as keys
+fn json_recur(value: &str) -> Value {
+    let mut recur = Map::new();
+    for rule_part in value.split(';') {
+        let Some((name, value)) = rule_part.split_once('=') else {
+            continue;
+        };
+        let name = name.to_ascii_lowercase();
+
+        let mut values: Vec<Value> = value
+            .split(',')
+            .map(|value| match &name[..] {
+                "until" if value.contains('T') => Value::String(json_date_time(value)),
+                "until" => Value::String(json_date(value)),
+                "count" | "interval" | "bysecond" | "byminute" | "byhour" | "bymonthday"
+                | "byyearday" | "byweekno" | "bymonth" | "bysetpos" => json_integer(value),
+                _ => Value::String(value.to_owned()),
+            })
+            .collect();
+
+        let value = if values.len() == 1 {
+            values.remove(0)
+        } else {
+            Value::Array(values)
+        };
+        recur.insert(name, value);
+    }
+    Value::Object(recur)
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n' | 'N') => text.push('\n'),
+                Some(c) => text.push(c),
+                None => text.push('\\'),
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text
+}
+
+/// `YYYYMMDD` to `YYYY-MM-DD`
+fn json_date(value: &str) -> String {
+    match value.get(0..4).zip(value.get(4..6)).zip(value.get(6..8)) {
+        Some(((year, month), day)) if value.len() == 8 => format!("{year}-{month}-{day}"),
+        _ => value.to_owned(),
+    }
+}
+
+/// `YYYYMMDDTHHMMSS[Z]` to `YYYY-MM-DDTHH:MM:SS[Z]`
+fn json_date_time(value: &str) -> String {
+    match value.split_once('T') {
+        Some((date, time)) => format!("{}T{}", json_date(date), json_time(time)),
+        None => value.to_owned(),
+    }
+}
+
+/// `HHMMSS[Z]` to `HH:MM:SS[Z]`
+fn json_time(value: &str) -> String {
+    match value.get(0..2).zip(value.get(2..4)).zip(value.get(4..)) {
+        Some(((hour, minute), rest)) if rest.len() >= 2 => format!("{hour}:{minute}:{rest}"),
+        _ => value.to_owned(),
+    }
+}
+
+/// `+HHMM[SS]` to `+HH:MM[:SS]`
+fn json_utc_offset(value: &str) -> String {
+    match value.get(0..3).zip(value.get(3..5)) {
+        Some((hour, minute)) if value.len() == 5 => format!("{hour}:{minute}"),
+        Some((hour, minute)) if value.len() == 7 => format!("{hour}:{minute}:{}", &value[5..]),
+        _ => value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::JCalWriter;
+    use crate::{
+        structure::icalstream::{
+            components::{EventC, ICalObject},
+            parameters,
+            properties::{
+                calendar::{ProdId, Version},
+                descriptive::{Categories, Geo, Summary},
+                misc::RequestStatus,
+                recurrence::RecurrenceRule,
+                relationship::{Attendee, Uid},
+            },
+        },
+        write::{
+            value_types::{
+                CalAddress, Frequency, LanguageTag, ParticipationStatus, Recur, Weekday,
+            },
+            DynamicName,
+        },
+    };
+
+    #[test]
+    fn jcal_writer() -> std::fmt::Result {
+        let mut buf = String::new();
+        let mut jcal = JCalWriter::with_fmt(&mut buf);
+
+        let mut ico = jcal.component(ICalObject)?;
+        ico.simple_property(Version, "2.0")?;
+        ico.simple_property(ProdId, "-//test//")?;
+
+        let mut ev = ico.component(EventC)?;
+        ev.simple_property(Uid, "unique identifier")?;
+
+        let mut summary = ev.property(Summary)?;
+        summary.param(parameters::Language, LanguageTag::new("en-US").unwrap())?;
+        summary.value("Lunch, then a meeting;\nbring notes")?;
+        summary.end()?;
+
+        ev.simple_property(Categories, ["lunch", "meeting"])?;
+        ev.simple_property(Geo, (37.386013, -122.082932))?;
+        ev.simple_property(RequestStatus, ("2.0", "Success"))?;
+        ev.simple_property(
+            RecurrenceRule,
+            Recur::new(Frequency::Weekly)
+                .count(4)
+                .by_day([Weekday::Monday, Weekday::Tuesday]),
+        )?;
+
+        let mut attendee = ev.property(Attendee)?;
+        attendee.param(
+            parameters::ParticipationStatus,
+            ParticipationStatus::Accepted,
+        )?;
+        attendee.param(
+            parameters::Delegators,
+            [
+                CalAddress::new("mailto:a@example.com").unwrap(),
+                CalAddress::new("mailto:b@example.com").unwrap(),
+            ],
+        )?;
+        attendee.value(CalAddress::new("mailto:c@example.com").unwrap())?;
+        attendee.end()?;
+
+        let mut x = ev.dynamic_property(&DynamicName::x_name("X-FLAGS").unwrap())?;
+        x.list_value::<crate::structure::value_types::Boolean>([true, false])?;
+        x.end()?;
+
+        ev.end()?;
+        ico.end()?;
+
+        assert_eq!(
+            buf,
+            r#"["vcalendar",[["version",{},"text","2.0"],["prodid",{},"text","-//test//"]],[["vevent",["#
+                .to_owned()
+                + r#"["uid",{},"text","unique identifier"],"#
+                + r#"["summary",{"language":"en-US"},"text","Lunch, then a meeting;\nbring notes"],"#
+                + r#"["categories",{},"text","lunch","meeting"],"#
+                + r#"["geo",{},"float",[37.386013,-122.082932]],"#
+                + r#"["request-status",{},"text",["2.0","Success"]],"#
+                + r#"["rrule",{},"recur",{"byday":["MO","TU"],"count":4,"freq":"WEEKLY"}],"#
+                + r#"["attendee",{"delegated-from":["mailto:a@example.com","mailto:b@example.com"],"partstat":"ACCEPTED"},"cal-address","mailto:c@example.com"],"#
+                + r#"["x-flags",{},"boolean",true,false]"#
+                + r#"],[]]]]"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn uri_values() -> std::fmt::Result {
+        use crate::{
+            structure::icalstream::properties::relationship::Url, write::value_types::Uri,
+        };
+
+        let mut buf = String::new();
+        let mut jcal = JCalWriter::with_fmt(&mut buf);
+
+        let mut ev = jcal.component(EventC)?;
+        ev.simple_property(Url, Uri::new("http://example.com/a,b;c=d").unwrap())?;
+
+        let mut x = ev.dynamic_property(&DynamicName::x_name("X-LINKS").unwrap())?;
+        x.list_value::<crate::structure::value_types::Uri>([
+            Uri::new("http://example.com/a").unwrap(),
+            Uri::new("http://example.com/b").unwrap(),
+        ])?;
+        x.end()?;
+        ev.end()?;
+
+        assert_eq!(
+            buf,
+            r#"["vevent",["#.to_owned()
+                + r#"["url",{},"uri","http://example.com/a,b;c=d"],"#
+                + r#"["x-links",{},"uri","http://example.com/a","http://example.com/b"]"#
+                + r#"],[]]"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_values() -> std::fmt::Result {
+        let mut buf = String::new();
+        let mut jcal = JCalWriter::with_fmt(&mut buf);
+
+        let mut ev = jcal.component(EventC)?;
+        let mut attendee = ev.property(Attendee)?;
+        assert!(attendee.param(parameters::CommonName, "a \"b\"").is_err());
+        assert!(attendee
+            .param(
+                parameters::SentBy,
+                CalAddress::new("mailto:a@example.com").unwrap()
+            )
+            .is_ok());
+        let mut summary = ev.property(Summary)?;
+        assert!(summary.value("a\u{1}b").is_err());
+        summary.value("ab")?;
+        summary.end()?;
+        ev.end()?;
+
+        assert_eq!(buf, r#"["vevent",[["summary",{},"text","ab"]],[]]"#);
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn jcal_date_and_time() -> std::fmt::Result {
+        use crate::structure::icalstream::properties::{
+            change_management::DateTimeStamp,
+            date_and_time::{DateTimeEnd, DateTimeStart},
+            recurrence::RecurrenceRule,
+        };
+
+        let dtstamp = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        let mut buf = String::new();
+        let mut jcal = JCalWriter::with_fmt(&mut buf);
+
+        let mut ev = jcal.component(EventC)?;
+        ev.simple_property(DateTimeStamp, dtstamp)?;
+        ev.simple_property(DateTimeStart, dtstamp.date_naive())?;
+        ev.simple_property(DateTimeEnd, dtstamp.naive_utc())?;
+        ev.simple_property(RecurrenceRule, Recur::new(Frequency::Daily).until(dtstamp))?;
+        ev.end()?;
+
+        assert_eq!(
+            buf,
+            r#"["vevent",["#.to_owned()
+                + r#"["dtstamp",{},"date-time","2024-06-26T12:00:00Z"],"#
+                + r#"["dtstart",{},"date","2024-06-26"],"#
+                + r#"["dtend",{},"date-time","2024-06-26T12:00:00"],"#
+                + r#"["rrule",{},"recur",{"freq":"DAILY","until":"2024-06-26T12:00:00Z"}]"#
+                + r#"],[]]"#
+        );
+        Ok(())
+    }
+}
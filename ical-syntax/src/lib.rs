@@ -3,5 +3,6 @@
 //! iCalendar is extensible, many of the concrete details are registered at
 //! IANA: <https://www.iana.org/assignments/icalendar/icalendar.xhtml>
 
+pub mod jcal;
 pub mod structure;
 pub mod write;
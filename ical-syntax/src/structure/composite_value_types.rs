@@ -2,20 +2,32 @@ use std::marker::PhantomData;
 
 use super::{CompositeValueType, ValueType};
 
-// TODO: Maybe tuples should impl ValueType directly? This might allow
-// encoding the value type of Version as Any2<Text, (Text, Text)>
-
-// Tuples of value types can be used as CompositeValueType:
+/// Tuples of value types are structured values, where the parts are separated
+/// by `;`, as in the GEO property.
+///
+/// A tuple is a value type in its own right, so it can be used in choices and
+/// lists. For example, the VERSION property takes either `maxver` or
+/// `minver;maxver`, which is expressed as `Any2<Text, (Text, Text)>`.
+///
+/// All the parts of a tuple have the same value type, which gives the name
+/// that the VALUE parameter refers to, such as FLOAT for `(Float, Float)`. A
+/// structured value with parts of different value types must be given its own
+/// [ValueType], with an explicit NAME. Each part is escaped according to the
+/// value type, while the separators are written as-is.
 macro_rules! tuple_value_type {
-    ($( $x:ident ),+) => {
-        impl<$( $x ),+> CompositeValueType for ($( $x, )+) where $( $x: ValueType ),+ {}
+    ($( $v:ident ),+) => {
+        impl<V: ValueType> ValueType for ($( $v, )+) {
+            const NAME: &'static str = V::NAME;
+
+            const ESCAPED: bool = false;
+        }
     };
 }
 
-tuple_value_type!(T0);
-tuple_value_type!(T0, T1);
-tuple_value_type!(T0, T1, T2);
-tuple_value_type!(T0, T1, T2, T3);
+tuple_value_type!(V);
+tuple_value_type!(V, V);
+tuple_value_type!(V, V, V);
+tuple_value_type!(V, V, V, V);
 
 /// List of values, for property value types.
 ///
@@ -40,10 +52,14 @@ pub struct List<V> {
     _phantom: PhantomData<V>,
 }
 
-impl<V: ValueTypeChoice> CompositeValueType for List<V> {}
+impl<V: ValueTypeChoice> CompositeValueType for List<V> {
+    const DEFAULT_VALUE_TYPE: &'static str = V::DefaultType::NAME;
+}
 impl<V: ValueTypeChoice> ValueTypeList for List<V> {}
 
-impl<V: ValueTypeChoice> CompositeValueType for V {}
+impl<V: ValueTypeChoice> CompositeValueType for V {
+    const DEFAULT_VALUE_TYPE: &'static str = V::DefaultType::NAME;
+}
 
 pub trait ValueTypeList {}
 
@@ -80,24 +96,26 @@ impl<T: ValueType> ValueTypeChoice for T {
     type DefaultType = T;
 }
 
-pub struct Any2<T0, T1> {
-    _phantom0: T0,
-    _phantom1: T1,
-}
-
-impl<T0: ValueType, T1: ValueType> ValueTypeChoice for Any2<T0, T1> {
-    type DefaultType = T0;
-}
+// Choices of 2 to 8 value types. The first type argument is the default:
+macro_rules! any_value_type {
+    ($name:ident, $t0:ident $(, $t:ident )*) => {
+        pub struct $name<$t0, $( $t ),*> {
+            _phantom: PhantomData<($t0, $( $t, )*)>,
+        }
 
-pub struct Any3<T0, T1, T2> {
-    _phantom0: T0,
-    _phantom1: T1,
-    _phantom2: T2,
+        impl<$t0: ValueType, $( $t: ValueType ),*> ValueTypeChoice for $name<$t0, $( $t ),*> {
+            type DefaultType = $t0;
+        }
+    };
 }
 
-impl<T0: ValueType, T1: ValueType, T2: ValueType> ValueTypeChoice for Any3<T0, T1, T2> {
-    type DefaultType = T0;
-}
+any_value_type!(Any2, T0, T1);
+any_value_type!(Any3, T0, T1, T2);
+any_value_type!(Any4, T0, T1, T2, T3);
+any_value_type!(Any5, T0, T1, T2, T3, T4);
+any_value_type!(Any6, T0, T1, T2, T3, T4, T5);
+any_value_type!(Any7, T0, T1, T2, T3, T4, T5, T6);
+any_value_type!(Any8, T0, T1, T2, T3, T4, T5, T6, T7);
 
 /// Marker-trait for ValueType-s to indicate that they are a valid choice for
 /// a given ValueTypeChoice.
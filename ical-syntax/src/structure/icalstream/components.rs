@@ -1,4 +1,7 @@
 //! Calendar components, as defined in [RFC5545 3.6](https://www.rfc-editor.org/rfc/rfc5545.html#section-3.6)
+//!
+//! The PARTICIPANT, VLOCATION and VRESOURCE components are defined in [RFC
+//! 9073 7](https://www.rfc-editor.org/rfc/rfc9073#section-7).
 
 use crate::structure::{Component, Property};
 
@@ -292,6 +295,9 @@ impl ICalObjectComponent for EventC {}
 /// Property that belongs to an [EventC].
 pub trait EventCProperty: Property {}
 
+/// Component that belongs to an [EventC].
+pub trait EventCComponent: Component {}
+
 /// To-Do Component
 ///
 /// Component Name:  VTODO
@@ -404,6 +410,9 @@ impl ICalObjectComponent for TodoC {}
 /// Property that belongs to a [TodoC].
 pub trait TodoCProperty: Property {}
 
+/// Component that belongs to a [TodoC].
+pub trait TodoCComponent: Component {}
+
 /// Journal Component
 ///
 /// Component Name:  VJOURNAL
@@ -1041,6 +1050,7 @@ pub trait FreeBusyCProperty: Property {}
 /// ```
 ///
 /// Reference: [RFC5545 3.6.5](https://www.rfc-editor.org/rfc/rfc5545.html#section-3.6.5)
+#[allow(clippy::doc_overindented_list_items)] // Quoted as-is from the RFC
 pub struct TimeZoneC;
 impl Component for TimeZoneC {
     const NAME: &'static str = "VTIMEZONE";
@@ -1052,7 +1062,7 @@ impl ICalObjectComponent for TimeZoneC {}
 pub trait TimeZoneCProperty: Property {}
 
 /// A subcomponent of [TimeZoneC].
-pub trait TimeZoneCComponent {}
+pub trait TimeZoneCComponent: Component {}
 
 /// A subcomponent of [TimeZoneC].
 pub struct StandardC;
@@ -1076,6 +1086,13 @@ impl TimeZoneCComponent for DaylightC {}
 /// Property that belongs to a [DaylightC].
 pub trait DaylightCProperty: Property {}
 
+/// Marker trait for the properties that can be specified in the observance
+/// sub-component `C`, which is either [StandardC] or [DaylightC]
+pub trait ObservanceProperty<C: TimeZoneCComponent>: Property {}
+
+impl<P: StandardCProperty> ObservanceProperty<StandardC> for P {}
+impl<P: DaylightCProperty> ObservanceProperty<DaylightC> for P {}
+
 /// Alarm Component
 ///
 /// Component Name:  VALARM
@@ -1310,6 +1327,233 @@ impl Component for AlarmC {
 }
 
 impl ICalObjectComponent for AlarmC {}
+impl EventCComponent for AlarmC {}
+impl TodoCComponent for AlarmC {}
 
 /// Property that belongs to an [AlarmC].
 pub trait AlarmCProperty: Property {}
+
+/// Component that belongs to an [AlarmC]. A VLOCATION component in an alarm
+/// gives the location for a [Proximity](super::properties::alarm::Proximity)
+/// trigger.
+///
+/// Reference: [RFC 9074 8.2](https://www.rfc-editor.org/rfc/rfc9074#section-8.2)
+pub trait AlarmCComponent: Component {}
+
+/// Participant Component
+///
+/// Component Name:  PARTICIPANT
+///
+/// Purpose:  This component provides information about a participant in an
+/// event or task.
+///
+/// Conformance:  This component can be specified multiple times in a
+/// "VEVENT", "VTODO", "VJOURNAL", or "VFREEBUSY" calendar component.
+///
+/// Description:  This component provides information about a participant in
+/// an event, task, or poll.  A participant may be an individual or an
+/// organization.  The "PARTICIPANT-TYPE" property specifies the type of the
+/// participant, and the optional "CALENDAR-ADDRESS" property relates the
+/// participant to an "ATTENDEE" property with the same value.  The component
+/// can contain "VLOCATION" and "VRESOURCE" components describing where the
+/// participant is and what it uses.
+///
+/// Format Definition:  This component is defined by the following notation:
+///
+/// ```abnf
+/// participantc  = "BEGIN" ":" "PARTICIPANT" CRLF
+///                 partprop *(locationc / resourcec)
+///                 "END" ":" "PARTICIPANT" CRLF
+///
+/// partprop      = *(
+///               ;
+///               ; The following are REQUIRED
+///               ; but MUST NOT occur more than once.
+///               ;
+///               participanttype / uid /
+///               ;
+///               ; The following are OPTIONAL
+///               ; but MUST NOT occur more than once.
+///               ;
+///               calendaraddress / created / description /
+///               dtstamp / geo / last-mod / priority / seq /
+///               status / summary / url /
+///               ;
+///               ; The following is OPTIONAL
+///               ; and MAY occur more than once.
+///               ;
+///               attach / categories / comment /
+///               contact / location / rstatus / related /
+///               resources / strucloc / strucres /
+///               styleddescription / sdataprop /
+///               iana-prop
+///               ;
+///               )
+/// ```
+///
+/// Example:  The following is an example of this component.  It contains a
+/// "SOURCE" property, which points to a vCard providing information about
+/// the event participant.
+///
+/// ```ics
+/// BEGIN:PARTICIPANT
+/// UID:bd1f1c86-6fa7-4c52-a39c-a7dc2ae1e8ce
+/// PARTICIPANT-TYPE:SPEAKER
+/// CALENDAR-ADDRESS:mailto:ildoit@example.com
+/// SOURCE:http://example.com/vcard/the-speaker.vcf
+/// END:PARTICIPANT
+/// ```
+///
+/// Reference: [RFC 9073 7.1](https://www.rfc-editor.org/rfc/rfc9073#section-7.1)
+pub struct ParticipantC;
+impl Component for ParticipantC {
+    const NAME: &'static str = "PARTICIPANT";
+}
+
+impl EventCComponent for ParticipantC {}
+impl TodoCComponent for ParticipantC {}
+
+/// Property that belongs to a [ParticipantC].
+pub trait ParticipantCProperty: Property {}
+
+/// Component that belongs to a [ParticipantC].
+pub trait ParticipantCComponent: Component {}
+
+/// Location Component
+///
+/// Component Name:  VLOCATION
+///
+/// Purpose:  This component provides rich information about the location of
+/// an event using the structured data property or optionally a plain text
+/// typed value.
+///
+/// Conformance:  This component can be specified multiple times in a
+/// "VEVENT", "VTODO", "VJOURNAL", "VFREEBUSY", or "PARTICIPANT" calendar
+/// component.
+///
+/// Description:  There may be a number of significant locations associated
+/// with an event, for example, the venue, parking or a restaurant.  This
+/// component specifies the name and type of each location, as well as its
+/// address or structured details.  A "LOCATION-TYPE" property specifies the
+/// type of the location, such as "parking" or "restaurant".
+///
+/// Format Definition:  This component is defined by the following notation:
+///
+/// ```abnf
+/// locationc  = "BEGIN" ":" "VLOCATION" CRLF
+///              locprop
+///              "END" ":" "VLOCATION" CRLF
+///
+/// locprop    = *(
+///            ;
+///            ; The following is REQUIRED
+///            ; but MUST NOT occur more than once.
+///            ;
+///            uid /
+///            ;
+///            ; The following are OPTIONAL
+///            ; but MUST NOT occur more than once.
+///            ;
+///            description / geo / loctype / name /
+///            ;
+///            ; The following is OPTIONAL
+///            ; and MAY occur more than once.
+///            ;
+///            sdataprop / iana-prop
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this component.  It points to a
+/// venue.
+///
+/// ```ics
+/// BEGIN:VLOCATION
+/// UID:123456-abcdef-98765432
+/// NAME:The venue
+/// STRUCTURED-DATA;VALUE=URI:http://dir.example.com/venues/big-hall.vcf
+/// END:VLOCATION
+/// ```
+///
+/// Reference: [RFC 9073 7.2](https://www.rfc-editor.org/rfc/rfc9073#section-7.2)
+pub struct LocationC;
+impl Component for LocationC {
+    const NAME: &'static str = "VLOCATION";
+}
+
+impl EventCComponent for LocationC {}
+impl TodoCComponent for LocationC {}
+impl ParticipantCComponent for LocationC {}
+impl AlarmCComponent for LocationC {}
+
+/// Property that belongs to a [LocationC].
+pub trait LocationCProperty: Property {}
+
+/// Resource Component
+///
+/// Component Name:  VRESOURCE
+///
+/// Purpose:  This component provides information about a resource used for
+/// an event.
+///
+/// Conformance:  This component can be specified multiple times in a
+/// "VEVENT", "VTODO", "VJOURNAL", "VFREEBUSY", or "PARTICIPANT" calendar
+/// component.
+///
+/// Description:  The existing "RESOURCES" property is restricted to
+/// providing names of resources.  This component allows the name and type
+/// of each resource to be specified, as well as structured details.  A
+/// "RESOURCE-TYPE" property specifies the type of the resource, such as
+/// "PROJECTOR".
+///
+/// Format Definition:  This component is defined by the following notation:
+///
+/// ```abnf
+/// resourcec  = "BEGIN" ":" "VRESOURCE" CRLF
+///              resprop
+///              "END" ":" "VRESOURCE" CRLF
+///
+/// resprop    = *(
+///            ;
+///            ; The following is REQUIRED
+///            ; but MUST NOT occur more than once.
+///            ;
+///            uid /
+///            ;
+///            ; The following are OPTIONAL
+///            ; but MUST NOT occur more than once.
+///            ;
+///            description / geo / name / restype /
+///            ;
+///            ; The following is OPTIONAL
+///            ; and MAY occur more than once.
+///            ;
+///            sdataprop / iana-prop
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this component.  It refers to a
+/// projector.
+///
+/// ```ics
+/// BEGIN:VRESOURCE
+/// UID:456789-abcdef-98765432
+/// NAME:The projector
+/// RESOURCE-TYPE:PROJECTOR
+/// STRUCTURED-DATA;VALUE=URI:http://dir.example.com/projectors/3d.vcf
+/// END:VRESOURCE
+/// ```
+///
+/// Reference: [RFC 9073 7.3](https://www.rfc-editor.org/rfc/rfc9073#section-7.3)
+pub struct ResourceC;
+impl Component for ResourceC {
+    const NAME: &'static str = "VRESOURCE";
+}
+
+impl EventCComponent for ResourceC {}
+impl TodoCComponent for ResourceC {}
+impl ParticipantCComponent for ResourceC {}
+
+/// Property that belongs to a [ResourceC].
+pub trait ResourceCProperty: Property {}
@@ -1,4 +1,108 @@
-use crate::structure::{parameter_value_items::Name, One, Param};
+use crate::structure::{
+    parameter_value_items::{
+        self as items, CalAddress, LanguageTag, MediaType, Name, ParamText, QuotedString, Uri,
+    },
+    One, Param, SetOf,
+};
+
+/// Free/Busy Time Type
+///
+/// Parameter Name:  FBTYPE
+///
+/// Purpose:  To specify the free or busy time type.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// fbtypeparam        = "FBTYPE" "=" ("FREE" / "BUSY"
+///                    / "BUSY-UNAVAILABLE" / "BUSY-TENTATIVE"
+///                    / x-name
+///          ; Some experimental iCalendar free/busy type.
+///                    / iana-token)
+///          ; Some other IANA-registered iCalendar free/busy type.
+/// ```
+///
+/// Description:  This parameter specifies the free or busy time type.  The
+/// value FREE indicates that the time interval is free for scheduling.  The
+/// value BUSY indicates that the time interval is busy because one or more
+/// events have been scheduled for that interval.  The value BUSY-UNAVAILABLE
+/// indicates that the time interval is busy and that the interval can not be
+/// scheduled.  The value BUSY-TENTATIVE indicates that the time interval is
+/// busy because one or more events have been tentatively scheduled for that
+/// interval.  If not specified on a property that allows this parameter, the
+/// default is BUSY.  Applications MUST treat x-name and iana-token values
+/// they don't recognize the same way as they would the BUSY value.
+///
+/// Example:  The following is an example of this parameter on a "FREEBUSY"
+/// property.
+///
+/// ```text
+/// FREEBUSY;FBTYPE=BUSY:19980415T133000Z/19980415T170000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.9](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.9)
+pub struct FreeBusyTimeType;
+
+impl Param for FreeBusyTimeType {
+    const NAME: &'static str = "FBTYPE";
+
+    type ParamValueType = One<items::FreeBusyType>;
+}
+
+/// Inline Encoding
+///
+/// Parameter Name:  ENCODING
+///
+/// Purpose:  To specify an alternate inline encoding for the property value.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// encodingparam =    "ENCODING" "="
+///                    ( "8BIT"
+///    ; "8bit" text encoding is defined in [RFC2045]
+///                    / "BASE64"
+///    ; "BASE64" binary encoding format is defined in [RFC4648]
+///                    )
+/// ```
+///
+/// Description:  This property parameter identifies the inline encoding used
+/// in a property value.  The default encoding is "8BIT", corresponding to a
+/// property value consisting of text.  The "BASE64" encoding type
+/// corresponds to a property value encoded using the "BASE64" encoding
+/// defined in [RFC2045].
+///
+/// If the value type parameter is ";VALUE=BINARY", then the inline encoding
+/// parameter MUST be specified with the value ";ENCODING=BASE64".
+///
+/// Example:
+///
+/// ```text
+/// ATTACH;FMTTYPE=text/plain;ENCODING=BASE64;VALUE=BINARY:TG9yZW
+///  0gaXBzdW0gZG9sb3Igc2l0IGFtZXQsIGNvbnNlY3RldHVyIGFkaXBpc2ljaW
+///  5nIGVsaXQsIHNlZCBkbyBlaXVzbW9kIHRlbXBvciBpbmNpZGlkdW50IHV0IG
+///  xhYm9yZSBldCBkb2xvcmUgbWFnbmEgYWxpcXVhLiBVdCBlbmltIGFkIG1pbm
+///  ltIHZlbmlhbSwgcXVpcyBub3N0cnVkIGV4ZXJjaXRhdGlvbiB1bGxhbWNvIG
+///  xhYm9yaXMgbmlzaSB1dCBhbGlxdWlwIGV4IGVhIGNvbW1vZG8gY29uc2VxdW
+///  F0LiBEdWlzIGF1dGUgaXJ1cmUgZG9sb3IgaW4gcmVwcmVoZW5kZXJpdCBpbi
+///  B2b2x1cHRhdGUgdmVsaXQgZXNzZSBjaWxsdW0gZG9sb3JlIGV1IGZ1Z2lhdC
+///  BudWxsYSBwYXJpYXR1ci4gRXhjZXB0ZXVyIHNpbnQgb2NjYWVjYXQgY3VwaW
+///  RhdGF0IG5vbiBwcm9pZGVudCwgc3VudCBpbiBjdWxwYSBxdWkgb2ZmaWNpYS
+///  BkZXNlcnVudCBtb2xsaXQgYW5pbSBpZCBlc3QgbGFib3J1bS4=
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.7](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.7)
+pub struct InlineEncoding;
+
+impl Param for InlineEncoding {
+    const NAME: &'static str = "ENCODING";
+
+    type ParamValueType = One<items::InlineEncoding>;
+}
 
 /// Value Data Types
 ///
@@ -56,3 +160,1115 @@ impl Param for Value {
 
     type ParamValueType = One<Name>;
 }
+
+/// Time Zone Identifier
+///
+/// Parameter Name:  TZID
+///
+/// Purpose:  To specify the identifier for the time zone definition for a
+/// time component in the property value.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// tzidparam  = "TZID" "=" [tzidprefix] paramtext
+///
+/// tzidprefix = "/"
+/// ```
+///
+/// Description:  This parameter MUST be specified on the "DTSTART", "DTEND",
+/// "DUE", "EXDATE", and "RDATE" properties when either a DATE-TIME or TIME
+/// value type is specified and when the value is neither a UTC or a
+/// "floating" time.  Refer to the DATE-TIME or TIME value type definition for
+/// a description of UTC and "floating time" formats.  This property
+/// parameter specifies a text value that uniquely identifies the "VTIMEZONE"
+/// calendar component to be used when evaluating the time portion of the
+/// property.  The value of the "TZID" property parameter will be equal to the
+/// value of the "TZID" property for the matching time zone definition.  An
+/// individual "VTIMEZONE" calendar component MUST be specified for each
+/// unique "TZID" parameter value specified in the iCalendar object.
+///
+/// The parameter MUST be specified on properties with a DATE-TIME value if
+/// the DATE-TIME is not either a UTC or a "floating" time.  Failure to
+/// include and follow VTIMEZONE definitions in iCalendar objects may lead to
+/// inconsistent understanding of the local time at any given location.
+///
+/// The presence of the SOLIDUS character as a prefix, indicates that this
+/// "TZID" represents a unique ID in a globally defined time zone registry
+/// (when such registry is defined).
+///
+/// Note:  This document does not define a naming convention for time zone
+/// identifiers.  Implementers may want to use the naming conventions defined
+/// in existing time zone specifications such as the public-domain TZ
+/// database [TZDB].  The specification of globally unique time zone
+/// identifiers is not addressed by this document and is left for future
+/// study.
+///
+/// The following are examples of this property parameter:
+///
+/// ```text
+/// DTSTART;TZID=America/New_York:19980119T020000
+///
+/// DTEND;TZID=America/New_York:19980119T030000
+/// ```
+///
+/// The "TZID" property parameter MUST NOT be applied to DATE properties and
+/// DATE-TIME or TIME properties whose time values are specified in UTC.
+///
+/// The use of local time in a DATE-TIME or TIME value without the "TZID"
+/// property parameter is to be interpreted as floating time, regardless of
+/// the existence of "VTIMEZONE" calendar components in the iCalendar object.
+///
+/// For more information, see the sections on the value types DATE-TIME and
+/// TIME.
+///
+/// Reference: [RFC 5545
+/// 3.2.19](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.19)
+///
+/// [TZDB]: https://www.iana.org/time-zones
+pub struct TimeZoneIdentifier;
+
+impl Param for TimeZoneIdentifier {
+    const NAME: &'static str = "TZID";
+
+    type ParamValueType = One<ParamText>;
+}
+
+/// Common Name
+///
+/// Parameter Name:  CN
+///
+/// Purpose:  To specify the common name to be associated with the calendar
+/// user specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// cnparam    = "CN" "=" param-value
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter specifies the common name to be
+/// associated with the calendar user specified by the property.  The
+/// parameter value is text.  The parameter value can be used for display
+/// text to be associated with the calendar address specified by the
+/// property.
+///
+/// Example:
+///
+/// ```text
+/// ORGANIZER;CN="John Smith":mailto:jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.2)
+pub struct CommonName;
+
+impl Param for CommonName {
+    const NAME: &'static str = "CN";
+
+    type ParamValueType = One<QuotedString>;
+}
+
+/// Alarm Trigger Relationship
+///
+/// Parameter Name:  RELATED
+///
+/// Purpose:  To specify the relationship of the alarm trigger with respect to
+/// the start or end of the calendar component.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// trigrelparam       = "RELATED" "="
+///                     ("START"       ; Trigger off of start
+///                    / "END")        ; Trigger off of end
+/// ```
+///
+/// Description:  This parameter can be specified on properties that specify
+/// an alarm trigger with a "DURATION" value type.  The parameter specifies
+/// whether the alarm will trigger relative to the start or end of the
+/// calendar component.  The parameter value START will set the alarm to
+/// trigger off the start of the calendar component; the parameter value END
+/// will set the alarm to trigger off the end of the calendar component.  If
+/// the parameter is not specified on an allowable property, then the default
+/// is START.
+///
+/// Example:
+///
+/// ```text
+/// TRIGGER;RELATED=END:PT5M
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.14](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.14)
+pub struct AlarmTriggerRelationship;
+
+impl Param for AlarmTriggerRelationship {
+    const NAME: &'static str = "RELATED";
+
+    type ParamValueType = One<items::AlarmTriggerRelationship>;
+}
+
+/// Calendar User Type
+///
+/// Parameter Name:  CUTYPE
+///
+/// Purpose:  To identify the type of calendar user specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// cutypeparam        = "CUTYPE" "="
+///                    ("INDIVIDUAL"   ; An individual
+///                   / "GROUP"        ; A group of individuals
+///                   / "RESOURCE"     ; A physical resource
+///                   / "ROOM"         ; A room resource
+///                   / "UNKNOWN"      ; Otherwise not known
+///                   / x-name         ; Experimental type
+///                   / iana-token)    ; Other IANA-registered
+///                                    ; type
+/// ; Default is INDIVIDUAL
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter identifies the type of calendar
+/// user specified by the property.  If not specified on a property that
+/// allows this parameter, the default is INDIVIDUAL.  Applications MUST treat
+/// x-name and iana-token values they don't recognize the same way as they
+/// would the UNKNOWN value.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;CUTYPE=GROUP:mailto:ietf-calsch@example.org
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.3)
+pub struct CalendarUserType;
+
+impl Param for CalendarUserType {
+    const NAME: &'static str = "CUTYPE";
+
+    type ParamValueType = One<items::CalendarUserType>;
+}
+
+/// Delegators
+///
+/// Parameter Name:  DELEGATED-FROM
+///
+/// Purpose:  To specify the calendar users that have delegated their
+/// participation to the calendar user specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// delfromparam       = "DELEGATED-FROM" "=" DQUOTE cal-address
+///                       DQUOTE *("," DQUOTE cal-address DQUOTE)
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  This parameter specifies those calendar users
+/// that have delegated their participation in a group-scheduled event or
+/// to-do to the calendar user specified by the property.  The individual
+/// calendar address parameter values MUST each be specified in a quoted-
+/// string.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;DELEGATED-FROM="mailto:jsmith@example.com":mailto:
+///  jdoe@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.4](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.4)
+pub struct Delegators;
+
+impl Param for Delegators {
+    const NAME: &'static str = "DELEGATED-FROM";
+
+    type ParamValueType = SetOf<CalAddress>;
+}
+
+/// Delegatees
+///
+/// Parameter Name:  DELEGATED-TO
+///
+/// Purpose:  To specify the calendar users to whom the calendar user
+/// specified by the property has delegated participation.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// deltoparam = "DELEGATED-TO" "=" DQUOTE cal-address DQUOTE
+///             *("," DQUOTE cal-address DQUOTE)
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  This parameter specifies those calendar users
+/// whom have been delegated participation in a group-scheduled event or
+/// to-do by the calendar user specified by the property.  The individual
+/// calendar address parameter values MUST each be specified in a quoted-
+/// string.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;DELEGATED-TO="mailto:jdoe@example.com","mailto:jqpublic
+///  @example.com":mailto:jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.5](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.5)
+pub struct Delegatees;
+
+impl Param for Delegatees {
+    const NAME: &'static str = "DELEGATED-TO";
+
+    type ParamValueType = SetOf<CalAddress>;
+}
+
+/// Directory Entry Reference
+///
+/// Parameter Name:  DIR
+///
+/// Purpose:  To specify reference to a directory entry associated with the
+/// calendar user specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// dirparam   = "DIR" "=" DQUOTE uri DQUOTE
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter specifies a reference to the
+/// directory entry associated with the calendar user specified by the
+/// property.  The parameter value is a URI.  The URI parameter value MUST be
+/// specified in a quoted-string.
+///
+/// Example:
+///
+/// ```text
+/// ORGANIZER;DIR="ldap://example.com:6666/o=ABC%20Industries,
+///  c=US???(cn=Jim%20Dolittle)":mailto:jimdo@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.6](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.6)
+pub struct DirectoryEntryReference;
+
+impl Param for DirectoryEntryReference {
+    const NAME: &'static str = "DIR";
+
+    type ParamValueType = One<Uri>;
+}
+
+/// Group or List Membership
+///
+/// Parameter Name:  MEMBER
+///
+/// Purpose:  To specify the group or list membership of the calendar user
+/// specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// memberparam        = "MEMBER" "=" DQUOTE cal-address DQUOTE
+///                      *("," DQUOTE cal-address DQUOTE)
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter identifies the groups or list
+/// membership for the calendar user specified by the property.  The
+/// parameter value is either a single calendar address in a quoted-string or
+/// a COMMA-separated list of calendar addresses, each in a quoted-string.
+/// The individual calendar address parameter values MUST each be specified
+/// in a quoted-string.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;MEMBER="mailto:ietf-calsch@example.org":mailto:
+///  jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.11](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.11)
+pub struct Membership;
+
+impl Param for Membership {
+    const NAME: &'static str = "MEMBER";
+
+    type ParamValueType = SetOf<CalAddress>;
+}
+
+/// Participation Status
+///
+/// Parameter Name:  PARTSTAT
+///
+/// Purpose:  To specify the participation status for the calendar user
+/// specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// partstatparam    = "PARTSTAT" "="
+///                   (partstat-event
+///                  / partstat-todo
+///                  / partstat-jour)
+///
+/// partstat-event   = ("NEEDS-ACTION"    ; Event needs action
+///                  / "ACCEPTED"         ; Event accepted
+///                  / "DECLINED"         ; Event declined
+///                  / "TENTATIVE"        ; Event tentatively
+///                                       ; accepted
+///                  / "DELEGATED"        ; Event delegated
+///                  / x-name             ; Experimental status
+///                  / iana-token)        ; Other IANA-registered
+///                                       ; status
+/// ; These are the participation statuses for a "VEVENT".
+/// ; Default is NEEDS-ACTION.
+///
+/// partstat-todo    = ("NEEDS-ACTION"    ; To-do needs action
+///                  / "ACCEPTED"         ; To-do accepted
+///                  / "DECLINED"         ; To-do declined
+///                  / "TENTATIVE"        ; To-do tentatively
+///                                       ; accepted
+///                  / "DELEGATED"        ; To-do delegated
+///                  / "COMPLETED"        ; To-do completed
+///                                       ; COMPLETED property has
+///                                       ; DATE-TIME completed
+///                  / "IN-PROCESS"       ; To-do in process of
+///                                       ; being completed
+///                  / x-name             ; Experimental status
+///                  / iana-token)        ; Other IANA-registered
+///                                       ; status
+/// ; These are the participation statuses for a "VTODO".
+/// ; Default is NEEDS-ACTION.
+///
+/// partstat-jour    = ("NEEDS-ACTION"    ; Journal needs action
+///                  / "ACCEPTED"         ; Journal accepted
+///                  / "DECLINED"         ; Journal declined
+///                  / x-name             ; Experimental status
+///                  / iana-token)        ; Other IANA-registered
+///                                       ; status
+/// ; These are the participation statuses for a "VJOURNAL".
+/// ; Default is NEEDS-ACTION.
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter identifies the participation
+/// status for the calendar user specified by the property value.  The
+/// parameter values differ depending on whether they are associated with a
+/// group-scheduled "VEVENT", "VTODO", or "VJOURNAL".  The values MUST match
+/// one of the values allowed for the given calendar component.  If not
+/// specified on a property that allows this parameter, the default value is
+/// NEEDS-ACTION.  Applications MUST treat x-name and iana-token values they
+/// don't recognize the same way as they would the NEEDS-ACTION value.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;PARTSTAT=DECLINED:mailto:jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.12](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.12)
+pub struct ParticipationStatus;
+
+impl Param for ParticipationStatus {
+    const NAME: &'static str = "PARTSTAT";
+
+    type ParamValueType = One<items::ParticipationStatus>;
+}
+
+/// Recurrence Identifier Range
+///
+/// Parameter Name:  RANGE
+///
+/// Purpose:  To specify the effective range of recurrence instances from the
+/// instance specified by the recurrence identifier specified by the
+/// property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// rangeparam = "RANGE" "=" "THISANDFUTURE"
+/// ; To specify the instance specified by the recurrence identifier
+/// ; and all subsequent recurrence instances.
+/// ```
+///
+/// Description:  This parameter can be specified on a property that
+/// specifies a recurrence identifier.  The parameter specifies the effective
+/// range of recurrence instances that is specified by the property.  The
+/// effective range is from the recurrence identifier specified by the
+/// property.  If this parameter is not specified on an allowed property,
+/// then the default range is the single instance specified by the recurrence
+/// identifier value of the property.  The parameter value can only be
+/// "THISANDFUTURE" to indicate a range defined by the recurrence identifier
+/// and all subsequent instances.  The value "THISANDPRIOR" is deprecated by
+/// this revision of iCalendar and MUST NOT be generated by applications.
+///
+/// Example:
+///
+/// ```text
+/// RECURRENCE-ID;RANGE=THISANDFUTURE:19980401T133000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.13](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.13)
+pub struct RecurrenceIdentifierRange;
+
+impl Param for RecurrenceIdentifierRange {
+    const NAME: &'static str = "RANGE";
+
+    type ParamValueType = One<items::RecurrenceIdentifierRange>;
+}
+
+/// Relationship Type
+///
+/// Parameter Name:  RELTYPE
+///
+/// Purpose:  To specify the type of hierarchical relationship associated
+/// with the calendar component specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// reltypeparam       = "RELTYPE" "="
+///                     ("PARENT"    ; Parent relationship - Default
+///                    / "CHILD"     ; Child relationship
+///                    / "SIBLING"   ; Sibling relationship
+///                    / iana-token  ; Some other IANA-registered
+///                                  ; iCalendar relationship type
+///                    / x-name)     ; A non-standard, experimental
+///                                  ; relationship type
+/// ```
+///
+/// Description:  This parameter can be specified on a property that
+/// references another related calendar.  The parameter specifies the
+/// hierarchical relationship type of the calendar component referenced by
+/// the property.  The parameter value can be PARENT, to indicate that the
+/// referenced calendar component is a superior of calendar component; CHILD
+/// to indicate that the referenced calendar component is a subordinate of
+/// the calendar component; or SIBLING to indicate that the referenced
+/// calendar component is a peer of the calendar component.  If this
+/// parameter is not specified on an allowable property, the default
+/// relationship type is PARENT.  Applications MUST treat x-name and
+/// iana-token values they don't recognize the same way as they would the
+/// PARENT value.
+///
+/// Example:
+///
+/// ```text
+/// RELATED-TO;RELTYPE=SIBLING:19960401-080045-4000F192713@
+///  example.com
+/// ```
+///
+/// [RFC 9074 7](https://www.rfc-editor.org/rfc/rfc9074#section-7) adds the
+/// "SNOOZE" relationship type, for use in "VALARM" components. It relates a
+/// snoozed alarm to the alarm it repeats, by the "UID" of the latter.
+///
+/// Reference: [RFC 5545
+/// 3.2.15](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.15)
+pub struct RelationshipType;
+
+impl Param for RelationshipType {
+    const NAME: &'static str = "RELTYPE";
+
+    type ParamValueType = One<items::RelationshipType>;
+}
+
+/// Participation Role
+///
+/// Parameter Name:  ROLE
+///
+/// Purpose:  To specify the participation role for the calendar user
+/// specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// roleparam  = "ROLE" "="
+///             ("CHAIR"             ; Indicates chair of the
+///                                  ; calendar entity
+///            / "REQ-PARTICIPANT"   ; Indicates a participant whose
+///                                  ; participation is required
+///            / "OPT-PARTICIPANT"   ; Indicates a participant whose
+///                                  ; participation is optional
+///            / "NON-PARTICIPANT"   ; Indicates a participant who
+///                                  ; is copied for information
+///                                  ; purposes only
+///            / x-name              ; Experimental role
+///            / iana-token)         ; Other IANA role
+/// ; Default is REQ-PARTICIPANT
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter specifies the participation role
+/// for the calendar user specified by the property in the group schedule
+/// calendar component.  If not specified on a property that allows this
+/// parameter, the default value is REQ-PARTICIPANT.  Applications MUST treat
+/// x-name and iana-token values they don't recognize the same way as they
+/// would the REQ-PARTICIPANT value.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;ROLE=CHAIR:mailto:mrbig@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.16](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.16)
+pub struct ParticipationRole;
+
+impl Param for ParticipationRole {
+    const NAME: &'static str = "ROLE";
+
+    type ParamValueType = One<items::ParticipationRole>;
+}
+
+/// RSVP Expectation
+///
+/// Parameter Name:  RSVP
+///
+/// Purpose:  To specify whether there is an expectation of a favor of a reply
+/// from the calendar user specified by the property value.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// rsvpparam = "RSVP" "=" ("TRUE" / "FALSE")
+/// ; Default is FALSE
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter identifies the expectation of a
+/// reply from the calendar user specified by the property value.  This
+/// parameter is used by the "Organizer" to request a participation status
+/// reply from an "Attendee" of a group-scheduled event or to-do.  If not
+/// specified on a property that allows this parameter, the default value is
+/// FALSE.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;RSVP=TRUE:mailto:jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.17](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.17)
+pub struct RsvpExpectation;
+
+impl Param for RsvpExpectation {
+    const NAME: &'static str = "RSVP";
+
+    type ParamValueType = One<items::Boolean>;
+}
+
+/// Sent By
+///
+/// Parameter Name:  SENT-BY
+///
+/// Purpose:  To specify the calendar user that is acting on behalf of the
+/// calendar user specified by the property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// sentbyparam        = "SENT-BY" "=" DQUOTE cal-address DQUOTE
+/// ```
+///
+/// Description:  This parameter can be specified on properties with a
+/// CAL-ADDRESS value type.  The parameter specifies the calendar user that
+/// is acting on behalf of the calendar user specified by the property.  The
+/// parameter value MUST be a mailto URI as defined in [RFC2368].  The
+/// individual calendar address parameter values MUST each be specified in a
+/// quoted-string.
+///
+/// Example:
+///
+/// ```text
+/// ORGANIZER;SENT-BY="mailto:sray@example.com":mailto:
+///  jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.18](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.18)
+///
+/// [RFC2368]: https://www.rfc-editor.org/rfc/rfc2368
+pub struct SentBy;
+
+impl Param for SentBy {
+    const NAME: &'static str = "SENT-BY";
+
+    type ParamValueType = One<CalAddress>;
+}
+
+/// Alternate Text Representation
+///
+/// Parameter Name:  ALTREP
+///
+/// Purpose:  To specify an alternate text representation for the property
+/// value.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// altrepparam = "ALTREP" "=" DQUOTE uri DQUOTE
+/// ```
+///
+/// Description:  This parameter specifies a URI that points to an alternate
+/// representation for a textual property value.  A property specifying this
+/// parameter MUST also include a value that reflects the default
+/// representation of the text value.  The URI parameter value MUST be
+/// specified in a quoted-string.
+///
+/// Note: While there is no restriction imposed on the URI schemes allowed
+/// for this parameter, Content Identifier (CID) [RFC2392], HTTP [RFC2616],
+/// and HTTPS [RFC2818] are the URI schemes most commonly used by current
+/// implementations.
+///
+/// Example:
+///
+/// ```text
+/// DESCRIPTION;ALTREP="CID:part3.msg.970415T083000@example.com":
+///  Project XYZ Review Meeting will include the following agenda
+///   items: (a) Market Overview\, (b) Finances\, (c) Project Man
+///  agement
+/// ```
+///
+/// The "ALTREP" property parameter value might point to a "text/html"
+/// content portion.
+///
+/// Reference: [RFC 5545
+/// 3.2.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.1)
+///
+/// [RFC2392]: https://www.rfc-editor.org/rfc/rfc2392
+/// [RFC2616]: https://www.rfc-editor.org/rfc/rfc2616
+/// [RFC2818]: https://www.rfc-editor.org/rfc/rfc2818
+pub struct AlternateTextRepresentation;
+
+impl Param for AlternateTextRepresentation {
+    const NAME: &'static str = "ALTREP";
+
+    type ParamValueType = One<Uri>;
+}
+
+/// Format Type
+///
+/// Parameter Name:  FMTTYPE
+///
+/// Purpose:  To specify the content type of a referenced object.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// fmttypeparam = "FMTTYPE" "=" type-name "/" subtype-name
+///                ; Where "type-name" and "subtype-name" are
+///                ; defined in Section 4.2 of [RFC4288].
+/// ```
+///
+/// Description:  This parameter can be specified on properties that are used
+/// to reference an object.  The parameter specifies the media type [RFC4288]
+/// of the referenced object.  For example, on the "ATTACH" property, an FTP
+/// type URI value does not, by itself, necessarily convey the type of content
+/// associated with the resource.  The parameter value MUST be the text for
+/// either an IANA-registered media type or a non-standard media type.
+///
+/// Example:
+///
+/// ```text
+/// ATTACH;FMTTYPE=application/msword:ftp://example.com/pub/docs/
+///  agenda.doc
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.8](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.8)
+///
+/// [RFC4288]: https://www.rfc-editor.org/rfc/rfc4288
+pub struct FormatType;
+
+impl Param for FormatType {
+    const NAME: &'static str = "FMTTYPE";
+
+    type ParamValueType = One<MediaType>;
+}
+
+/// Language
+///
+/// Parameter Name:  LANGUAGE
+///
+/// Purpose:  To specify the language for text values in a property or
+/// property parameter.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// languageparam = "LANGUAGE" "=" language
+///
+/// language = Language-Tag
+///            ; As defined in [RFC5646].
+/// ```
+///
+/// Description:  This parameter identifies the language of the text in the
+/// property value and of all property parameter values of the property.  The
+/// value of the "LANGUAGE" property parameter is that defined in [RFC5646].
+///
+/// For transport in a MIME entity, the Content-Language header field can be
+/// used to set the default language for the entire body part.  Otherwise, no
+/// default language is assumed.
+///
+/// Example:  The following are examples of this parameter on the "SUMMARY"
+/// and "LOCATION" properties:
+///
+/// ```text
+/// SUMMARY;LANGUAGE=en-US:Company Holiday Party
+///
+/// LOCATION;LANGUAGE=en:Germany
+///
+/// LOCATION;LANGUAGE=no:Tyskland
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.10](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.10)
+///
+/// [RFC5646]: https://www.rfc-editor.org/rfc/rfc5646
+pub struct Language;
+
+impl Param for Language {
+    const NAME: &'static str = "LANGUAGE";
+
+    type ParamValueType = One<LanguageTag>;
+}
+
+/// Display
+///
+/// Parameter Name:  DISPLAY
+///
+/// Purpose:  To specify different ways in which an image for a calendar or
+/// component can be displayed.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// displayparam = "DISPLAY" "=" displayval *("," displayval)
+///
+/// displayval =  ("BADGE" /     ; image inline with the title of the
+///                              ; event
+///                "GRAPHIC" /   ; a full image replacement for the event
+///                              ; itself
+///                "FULLSIZE" /  ; an image that is used to enhance the
+///                              ; event
+///                "THUMBNAIL" / ; a smaller variant of "FULLSIZE" to be
+///                              ; used when space for the image is
+///                              ; constrained
+///                x-name / iana-token)       ; Other possible values
+/// ```
+///
+/// Description:  This property parameter MAY be specified on "IMAGE"
+/// properties.  In the absence of this parameter, the value "BADGE" MUST be
+/// used for the default behavior.  The value determines how a client ought
+/// to present an image supplied in iCalendar data.
+///
+/// Clients MAY completely ignore any value of this parameter.
+///
+/// Example:
+///
+/// ```text
+/// IMAGE;VALUE=URI;DISPLAY=BADGE,THUMBNAIL;FMTTYPE=image/png:https://exa
+///  mple.com/images/weather-cloudy.png
+/// ```
+///
+/// Reference: [RFC 7986
+/// 6.1](https://www.rfc-editor.org/rfc/rfc7986#section-6.1)
+pub struct ImageDisplay;
+
+impl Param for ImageDisplay {
+    const NAME: &'static str = "DISPLAY";
+
+    type ParamValueType = SetOf<items::ImageDisplay>;
+}
+
+/// Email
+///
+/// Parameter Name:  EMAIL
+///
+/// Purpose:  To specify an email address that is used to identify or contact
+/// an organizer or attendee.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// emailparam = "EMAIL" "=" param-value
+/// ```
+///
+/// Description:  This property parameter MAY be specified on "ORGANIZER" or
+/// "ATTENDEE" properties.  This property can be used in situations where the
+/// calendar user address value of "ORGANIZER" and "ATTENDEE" is not likely
+/// to be an identifier that recipients of scheduling messages could use to
+/// match the calendar user with, for example, an address book entry.  The
+/// value of this property is an email address that can easily be matched by
+/// recipients.  Recipients can also use this value as an alternative means
+/// of contacting the calendar user via email.  If a recipient's calendar user
+/// agent allows the recipient to save contact information based on the
+/// "ORGANIZER" or "ATTENDEE" properties, those calendar user agents SHOULD
+/// use any "EMAIL" property parameter value for the email address of the
+/// contact over any mailto: calendar user address specified as the value of
+/// the property.  Calendar user agents SHOULD NOT include an "EMAIL" property
+/// parameter when its value matches the calendar user address specified as
+/// the value of the property.
+///
+/// Example:
+///
+/// ```text
+/// ATTENDEE;CN=Cyrus Daboo;EMAIL=cyrus@example.com:mailto:opaque-toke
+///  n-1234@example.com
+/// ```
+///
+/// Reference: [RFC 7986
+/// 6.2](https://www.rfc-editor.org/rfc/rfc7986#section-6.2)
+pub struct Email;
+
+impl Param for Email {
+    const NAME: &'static str = "EMAIL";
+
+    type ParamValueType = One<QuotedString>;
+}
+
+/// Feature
+///
+/// Parameter Name:  FEATURE
+///
+/// Purpose:  To specify a feature or features of a conference or
+/// broadcast system.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// featureparam = "FEATURE" "=" featuretext *("," featuretext)
+/// featuretext  =  ("AUDIO" /     ; Audio capability
+///                  "CHAT" /      ; Chat or instant messaging
+///                  "FEED" /      ; Blog or Atom feed
+///                  "MODERATOR" / ; Moderator dial-in code
+///                  "PHONE" /     ; Phone conference
+///                  "SCREEN" /    ; Screen sharing
+///                  "VIDEO" /     ; Video capability
+///                  x-name /      ; Experimental type
+///                  iana-token)   ; Other IANA-registered type
+/// ```
+///
+/// Description:  This property parameter MAY be specified on the
+/// "CONFERENCE" property.  Multiple values can be specified.  The "MODERATOR"
+/// value is used to indicate that the property value is specific to the
+/// owner/initiator of the conference and contains a URI that "activates" the
+/// system (e.g., a "moderator" access code for a phone conference system that
+/// is different from the "public" access code).
+///
+/// Example:
+///
+/// ```text
+/// CONFERENCE;VALUE=URI;FEATURE=AUDIO:rtsp://audio.example.com/
+///  event
+/// CONFERENCE;VALUE=URI;FEATURE=AUDIO,VIDEO:https://video-chat.exam
+///  ple.com/;group-id=1234
+/// ```
+///
+/// Reference: [RFC 7986
+/// 6.3](https://www.rfc-editor.org/rfc/rfc7986#section-6.3)
+pub struct ConferenceFeature;
+
+impl Param for ConferenceFeature {
+    const NAME: &'static str = "FEATURE";
+
+    type ParamValueType = SetOf<items::ConferenceFeature>;
+}
+
+/// Label
+///
+/// Parameter Name:  LABEL
+///
+/// Purpose:  To provide a human-readable label.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// labelparam = "LABEL" "=" param-value
+/// ```
+///
+/// Description:  This property parameter MAY be specified on the
+/// "CONFERENCE" property.  It is anticipated that other extensions to
+/// iCalendar will reuse this property parameter on new properties that they
+/// define.  As a result, clients MUST expect to find this property parameter
+/// present on many different properties.  It provides a human-readable label
+/// that can be presented to calendar users to allow them to discriminate
+/// between properties that might be similar or provide additional information
+/// for properties that are not self-describing.  The "LANGUAGE" property
+/// parameter can be used to specify the language of the text in the
+/// parameter value (as per Section 3.2.10 of [RFC5545]).
+///
+/// Example:
+///
+/// ```text
+/// CONFERENCE;VALUE=URI;FEATURE=VIDEO;
+///  LABEL="Web video chat, access code=76543"
+///  :https://video-chat.example.com/;group-id=1234
+/// ```
+///
+/// Reference: [RFC 7986
+/// 6.4](https://www.rfc-editor.org/rfc/rfc7986#section-6.4)
+///
+/// [RFC5545]: https://www.rfc-editor.org/rfc/rfc5545
+pub struct Label;
+
+impl Param for Label {
+    const NAME: &'static str = "LABEL";
+
+    type ParamValueType = One<QuotedString>;
+}
+
+/// Order
+///
+/// Parameter Name:  ORDER
+///
+/// Purpose:  To define ordering for the associated property.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// orderparam    = "ORDER" "=" integer ;
+///                 Must be greater than or equal to 1
+/// ```
+///
+/// Description:  The "ORDER" parameter is optional and is used to indicate
+/// the relative ordering of the corresponding instance of a property.  Its
+/// value MUST be an integer greater than or equal to 1 that specifies the
+/// order, with 1 being the highest, most important, and highest priority
+/// property.  When the "ORDER" parameter is not present, the property has
+/// the lowest order.
+///
+/// Example:
+///
+/// ```text
+/// BEGIN:VCALENDAR
+/// ...
+/// STYLED-DESCRIPTION;ORDER=1;FMTTYPE=text/html:...
+/// ...
+/// ```
+///
+/// Reference: [RFC 9073
+/// 5.1](https://www.rfc-editor.org/rfc/rfc9073#section-5.1)
+pub struct Order;
+
+impl Param for Order {
+    const NAME: &'static str = "ORDER";
+
+    type ParamValueType = One<items::PositiveInteger>;
+}
+
+/// Schema
+///
+/// Parameter Name:  SCHEMA
+///
+/// Purpose:  To specify the schema used for the content of a
+/// "STRUCTURED-DATA" property value.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// schemaparam   = "SCHEMA" "=" DQUOTE uri DQUOTE
+/// ```
+///
+/// Description:  This property parameter SHOULD be specified on the
+/// "STRUCTURED-DATA" property.  When present, it provides identifying
+/// information about the nature of the content of the corresponding
+/// "STRUCTURED-DATA" property value.  This can be used to supplement the
+/// media type information provided by the "FMTTYPE" parameter on the
+/// corresponding property.
+///
+/// Example:
+///
+/// ```text
+/// STRUCTURED-DATA;FMTTYPE=application/ld+json;
+///  SCHEMA="https://schema.org/SportsEvent";
+///  ENCODING=BASE64;VALUE=BINARY:...
+/// ```
+///
+/// Reference: [RFC 9073
+/// 5.2](https://www.rfc-editor.org/rfc/rfc9073#section-5.2)
+pub struct Schema;
+
+impl Param for Schema {
+    const NAME: &'static str = "SCHEMA";
+
+    type ParamValueType = One<Uri>;
+}
+
+/// Derived
+///
+/// Parameter Name:  DERIVED
+///
+/// Purpose:  To specify that the value of the associated property is derived
+/// from some other property value or values.
+///
+/// Format Definition:  This property parameter is defined by the following
+/// notation:
+///
+/// ```abnf
+/// derivedparam   = "DERIVED" "=" ("TRUE" / "FALSE")
+///                ; Default is FALSE
+/// ```
+///
+/// Description:  This property parameter MAY be specified on any property
+/// when the value is derived from some other property or properties.  When
+/// present with a value of "TRUE", clients MUST NOT update the property.
+///
+/// As an example, if a "STYLED-DESCRIPTION" property is present with
+/// FMTTYPE="application/rtf", then there may be an additional
+/// "STYLED-DESCRIPTION" property with FMTTYPE="text/html" and DERIVED=TRUE
+/// which contains the HTML version of the RTF content.
+///
+/// Example:
+///
+/// ```text
+/// BEGIN:VCALENDAR
+/// ...
+/// STYLED-DESCRIPTION;FMTTYPE=text/html;DERIVED=TRUE:...
+/// ...
+/// ```
+///
+/// Reference: [RFC 9073
+/// 5.3](https://www.rfc-editor.org/rfc/rfc9073#section-5.3)
+pub struct Derived;
+
+impl Param for Derived {
+    const NAME: &'static str = "DERIVED";
+
+    type ParamValueType = One<items::Boolean>;
+}
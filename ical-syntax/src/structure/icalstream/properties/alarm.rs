@@ -1 +1,358 @@
 //! [RFC 5545 3.8.6: Alarm Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.6)
+//!
+//! Including the properties of [RFC 9074: "VALARM" Extensions for
+//! iCalendar](https://www.rfc-editor.org/rfc/rfc9074). RFC 9074 also allows
+//! [Uid](super::relationship::Uid) and
+//! [RelatedTo](super::relationship::RelatedTo) in the "VALARM" component.
+
+use crate::structure::{
+    composite_value_types::Any2,
+    icalstream::{components::AlarmCProperty, parameters},
+    value_types::{DateTimeUtc, Duration, Integer, Text},
+    AcceptsParam, Property,
+};
+
+/// Action
+///
+/// Property Name:  ACTION
+///
+/// Purpose:  This property defines the action to be invoked when an alarm is
+/// triggered.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MUST be specified once in a "VALARM" calendar
+/// component.
+///
+/// Description:  Each "VALARM" calendar component has a particular type of
+/// action with which it is associated.  This property specifies the type of
+/// action.  Applications MUST ignore alarms with x-name and iana-token values
+/// they don't recognize.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// action      = "ACTION" actionparam ":" actionvalue CRLF
+///
+/// actionparam = *(";" other-param)
+///
+///
+/// actionvalue = "AUDIO" / "DISPLAY" / "EMAIL"
+///             / iana-token / x-name
+/// ```
+///
+/// Example:  The following are examples of this property in a "VALARM"
+/// calendar component:
+///
+/// ```ics
+/// ACTION:AUDIO
+///
+/// ACTION:DISPLAY
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.6.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.6.1)
+pub struct Action;
+
+impl Property for Action {
+    const NAME: &'static str = "ACTION";
+
+    type CompositeValueType = Text;
+}
+
+impl AlarmCProperty for Action {}
+
+/// Repeat Count
+///
+/// Property Name:  REPEAT
+///
+/// Purpose:  This property defines the number of times the alarm should be
+/// repeated, after the initial trigger.
+///
+/// Value Type:  INTEGER
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in a "VALARM" calendar
+/// component.
+///
+/// Description:  This property defines the number of times an alarm should
+/// be repeated after its initial trigger.  If the alarm triggers more than
+/// once, then this property MUST be specified along with the "DURATION"
+/// property.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// repeat  = "REPEAT" repparam ":" integer CRLF
+/// ;Default is "0", zero.
+///
+/// repparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property for an alarm that
+/// repeats 4 additional times with a 5-minute delay after the initial
+/// triggering of the alarm:
+///
+/// ```ics
+/// REPEAT:4
+/// DURATION:PT5M
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.6.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.6.2)
+pub struct RepeatCount;
+
+impl Property for RepeatCount {
+    const NAME: &'static str = "REPEAT";
+
+    type CompositeValueType = Integer;
+}
+
+impl AlarmCProperty for RepeatCount {}
+
+/// Trigger
+///
+/// Property Name:  TRIGGER
+///
+/// Purpose:  This property specifies when an alarm will trigger.
+///
+/// Value Type:  The default value type is DURATION.  The value type can be
+/// set to a DATE-TIME value type, in which case the value MUST specify a UTC-
+/// formatted DATE-TIME value.
+///
+/// Property Parameters:  IANA, non-standard, value data type, time zone
+/// identifier, or trigger relationship property parameters can be specified
+/// on this property.  The trigger relationship property parameter MUST only
+/// be specified when the value type is "DURATION".
+///
+/// Conformance:  This property MUST be specified in the "VALARM" calendar
+/// component.
+///
+/// Description:  This property defines when an alarm will trigger.  The
+/// default value type is DURATION, specifying a relative time for the
+/// trigger of the alarm.  The default duration is relative to the start of
+/// an event or to-do with which the alarm is associated.  The duration can
+/// be explicitly set to trigger from either the end or the start of the
+/// associated event or to-do with the "RELATED" parameter.  A value of START
+/// will set the alarm to trigger off the start of the associated event or
+/// to-do.  A value of END will set the alarm to trigger off the end of the
+/// associated event or the due or end time of a to-do.
+///
+/// Either a positive or negative duration may be specified for the "TRIGGER"
+/// property.  An alarm with a positive duration is triggered after the
+/// associated start or end of the event or to-do.  An alarm with a negative
+/// duration is triggered before the associated start or end of the event or
+/// to-do.
+///
+/// The "RELATED" property parameter is not valid if the value type of the
+/// property is set to DATE-TIME (i.e., for an absolute date and time alarm
+/// trigger).  If a value type of DATE-TIME is specified, then the property
+/// value MUST be specified in the UTC time format.  If an absolute trigger
+/// is specified on an alarm for a recurring event or to-do, then the alarm
+/// will only trigger for the specified absolute DATE-TIME, along with any
+/// specified repeating instances.
+///
+/// If the trigger is set relative to START, then the "DTSTART" property MUST
+/// be present in the associated "VEVENT" or "VTODO" calendar component.  If
+/// an alarm is specified for an event with the trigger set relative to the
+/// END, then the "DTEND" property or the "DTSTART" and "DURATION" properties
+/// MUST be present in the associated "VEVENT" calendar component.  If the
+/// alarm is specified for a to-do with a trigger set relative to the END,
+/// then either the "DUE" property or the "DTSTART" and "DURATION" properties
+/// MUST be present in the associated "VTODO" calendar component.
+///
+/// Alarms specified in an event or to-do that is defined in terms of a DATE
+/// value type will be triggered relative to 00:00:00 of the user's
+/// configured time zone on the specified date, or relative to 00:00:00 UTC
+/// on the specified date if no configured time zone can be found for the
+/// user.  For example, if "DTSTART" is a DATE value set to 19980205 then the
+/// duration trigger will be relative to 19980205T000000 America/New_York for
+/// a user configured with the America/New_York time zone.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// trigger    = "TRIGGER" (trigrel / trigabs) CRLF
+///
+/// trigrel    = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///              (";" "VALUE" "=" "DURATION") /
+///              (";" trigrelparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///              (";" other-param)
+///              ) ":"  dur-value
+///
+/// trigabs    = *(
+///            ;
+///            ; The following is REQUIRED,
+///            ; but MUST NOT occur more than once.
+///            ;
+///              (";" "VALUE" "=" "DATE-TIME") /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///              (";" other-param)
+///              ) ":" date-time
+/// ```
+///
+/// Example:  A trigger set 15 minutes prior to the start of the event or
+/// to-do.
+///
+/// ```ics
+/// TRIGGER:-PT15M
+/// ```
+///
+/// A trigger set five minutes after the end of an event or the due date of a
+/// to-do.
+///
+/// ```ics
+/// TRIGGER;RELATED=END:PT5M
+/// ```
+///
+/// A trigger set to an absolute DATE-TIME.
+///
+/// ```ics
+/// TRIGGER;VALUE=DATE-TIME:19980101T050000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.6.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.6.3)
+pub struct Trigger;
+
+impl Property for Trigger {
+    const NAME: &'static str = "TRIGGER";
+
+    type CompositeValueType = Any2<Duration, DateTimeUtc>;
+}
+
+impl AlarmCProperty for Trigger {}
+
+impl AcceptsParam<parameters::AlarmTriggerRelationship> for Trigger {}
+
+/// Acknowledged
+///
+/// Property Name:  ACKNOWLEDGED
+///
+/// Purpose:  This property specifies the UTC date and time at which the
+/// related alarm was acknowledged.
+///
+/// Value Type:  DATE-TIME
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in "VALARM" calendar
+/// components.
+///
+/// Description:  This property is used to specify when an alarm was last
+/// sent or acknowledged.  This allows clients to determine when a pending
+/// alarm has been acknowledged by a calendar user so that any alerts can be
+/// dismissed across multiple devices.  It also allows clients to track
+/// repeating alarms or alarms on recurring events or to-dos to ensure that
+/// the right number of missed alarms can be tracked.
+///
+/// Clients SHOULD set this property to the current date-time value in UTC
+/// when a calendar user acknowledges a pending alarm.  Certain kinds of
+/// alarms, such as email-based alerts, might not provide feedback as to when
+/// the calendar user sees them.  For those kinds of alarms, the client
+/// SHOULD set this property when the alarm is triggered and the action is
+/// successfully carried out.
+///
+/// When an alarm is triggered on a client, clients can check to see if an
+/// "ACKNOWLEDGED" property is present.  If it is, and the value of that
+/// property is greater than or equal to the computed trigger time for the
+/// alarm, then the client SHOULD NOT trigger the alarm.
+///
+/// The value MUST be specified as a date with UTC time.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// acknowledged = "ACKNOWLEDGED" acknowledgedparam ":" datetime CRLF
+///
+/// acknowledgedparam = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// ACKNOWLEDGED:20090604T084500Z
+/// ```
+///
+/// Reference: [RFC 9074
+/// 6](https://www.rfc-editor.org/rfc/rfc9074#section-6)
+pub struct Acknowledged;
+
+impl Property for Acknowledged {
+    const NAME: &'static str = "ACKNOWLEDGED";
+
+    type CompositeValueType = DateTimeUtc;
+}
+
+impl AlarmCProperty for Acknowledged {}
+
+/// Proximity
+///
+/// Property Name:  PROXIMITY
+///
+/// Purpose:  This property indicates that a location-based trigger is
+/// applied to an alarm.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in "VALARM" calendar
+/// components.
+///
+/// Description:  This property is used to indicate that an alarm has a
+/// location-based trigger.  Its value identifies the direction of travel
+/// used to trigger the alarm.  One or more "VLOCATION" components are
+/// included in the "VALARM" component to specify the locations.
+///
+/// The value "ARRIVE" indicates that the alarm is triggered when the
+/// calendar user agent arrives at one of the locations, and "DEPART" when it
+/// leaves.  The values "CONNECT" and "DISCONNECT" are used for a connection
+/// to and disconnection from a vehicle, for example through Bluetooth.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// proximity = "PROXIMITY" proximityparam ":" proximityvalue CRLF
+///
+/// proximityparam = *(";" other-param)
+///
+/// proximityvalue = "ARRIVE" / "DEPART" / "CONNECT" / "DISCONNECT"
+///                  / iana-token / x-name
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// PROXIMITY:ARRIVE
+/// ```
+///
+/// Reference: [RFC 9074
+/// 8.1](https://www.rfc-editor.org/rfc/rfc9074#section-8.1)
+pub struct Proximity;
+
+impl Property for Proximity {
+    const NAME: &'static str = "PROXIMITY";
+
+    type CompositeValueType = Text;
+}
+
+impl AlarmCProperty for Proximity {}
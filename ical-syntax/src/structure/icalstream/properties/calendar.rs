@@ -1,6 +1,6 @@
 //! [RFC 5545 3.7: Calendar Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.7)
 
-use crate::structure::{value_types::*, Property};
+use crate::structure::{composite_value_types::Any2, value_types::*, Property};
 
 use crate::structure::icalstream::components::ICalObjectProperty;
 
@@ -50,8 +50,8 @@ pub struct Version;
 impl Property for Version {
     const NAME: &'static str = "VERSION";
 
-    // TODO: Model the maxver / (minver ";" maxver) grammar.
-    type CompositeValueType = Text;
+    // maxver, or (minver, maxver)
+    type CompositeValueType = Any2<Text, (Text, Text)>;
 }
 
 impl ICalObjectProperty for Version {}
@@ -107,3 +107,106 @@ impl Property for ProdId {
 }
 
 impl ICalObjectProperty for ProdId {}
+
+/// Property Name:  CALSCALE
+///
+/// Purpose:  This property defines the calendar scale used for the calendar
+/// information specified in the iCalendar object.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in an iCalendar object.
+/// The default value is "GREGORIAN".
+///
+/// Description:  This memo is based on the Gregorian calendar scale.  The
+/// Gregorian calendar scale is assumed if this property is not specified in
+/// the iCalendar object.  It is expected that other calendar scales will be
+/// defined in other specifications or by future versions of this memo.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// calscale   = "CALSCALE" calparam ":" calvalue CRLF
+///
+/// calparam   = *(";" other-param)
+///
+/// calvalue   = "GREGORIAN"
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// CALSCALE:GREGORIAN
+/// ```
+///
+/// Reference: [RFC5545
+/// 3.7.1](https://www.rfc-editor.org/rfc/rfc5545.html#section-3.7.1)
+pub struct CalendarScale;
+
+impl Property for CalendarScale {
+    const NAME: &'static str = "CALSCALE";
+
+    type CompositeValueType = Text;
+}
+
+impl ICalObjectProperty for CalendarScale {}
+
+/// Property Name:  METHOD
+///
+/// Purpose:  This property defines the iCalendar object method associated
+/// with the calendar object.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in an iCalendar object.
+///
+/// Description:  When used in a MIME message entity, the value of this
+/// property MUST be the same as the Content-Type "method" parameter value.
+/// If either the "METHOD" property or the Content-Type "method" parameter is
+/// specified, then the other MUST also be specified.
+///
+/// No methods are defined by this specification.  This is the subject of
+/// other specifications, such as the iCalendar Transport-independent
+/// Interoperability Protocol (iTIP) defined by [2446bis].
+///
+/// If this property is not present in the iCalendar object, then a
+/// scheduling transaction MUST NOT be assumed.  In such cases, the iCalendar
+/// object is merely being used to transport a snapshot of some calendar
+/// information; without the intention of conveying a scheduling semantic.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// method     = "METHOD" metparam ":" metvalue CRLF
+///
+/// metparam   = *(";" other-param)
+///
+/// metvalue   = iana-token
+/// ```
+///
+/// Example:  The following is a hypothetical example of this property to
+/// convey that the iCalendar object is a scheduling request:
+///
+/// ```text
+/// METHOD:REQUEST
+/// ```
+///
+/// Reference: [RFC5545
+/// 3.7.2](https://www.rfc-editor.org/rfc/rfc5545.html#section-3.7.2)
+///
+/// [2446bis]: https://www.rfc-editor.org/rfc/rfc5545#ref-2446bis
+pub struct Method;
+
+impl Property for Method {
+    const NAME: &'static str = "METHOD";
+
+    type CompositeValueType = Text;
+}
+
+impl ICalObjectProperty for Method {}
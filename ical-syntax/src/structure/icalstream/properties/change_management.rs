@@ -1,11 +1,66 @@
 //! [RFC 5545 3.8.7: Change Management Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.7)
 
 use crate::structure::{
-    icalstream::components::{EventCProperty, FreeBusyCProperty, JournalCProperty, TodoCProperty},
-    value_types::DateTimeUtc,
+    icalstream::components::{
+        EventCProperty, FreeBusyCProperty, ICalObjectProperty, JournalCProperty,
+        ParticipantCProperty, TimeZoneCProperty, TodoCProperty,
+    },
+    value_types::{DateTimeUtc, Integer},
     Property,
 };
 
+/// Date-Time Created
+///
+/// Property Name:  CREATED
+///
+/// Purpose:  This property specifies the date and time that the calendar
+/// information was created by the calendar user agent in the calendar store.
+///
+/// Note: This is analogous to the creation date and time for a file in the
+/// file system.
+///
+/// Value Type:  DATE-TIME
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  The property can be specified once in "VEVENT", "VTODO", or
+/// "VJOURNAL" calendar components.  The value MUST be specified as a date
+/// with UTC time.
+///
+/// Description:  This property specifies the date and time that the calendar
+/// information was created by the calendar user agent in the calendar store.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// created    = "CREATED" creaparam ":" date-time CRLF
+///
+/// creaparam  = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// CREATED:19960329T133000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.7.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.7.1)
+pub struct DateTimeCreated;
+
+impl Property for DateTimeCreated {
+    const NAME: &'static str = "CREATED";
+
+    type CompositeValueType = DateTimeUtc;
+}
+
+impl EventCProperty for DateTimeCreated {}
+impl TodoCProperty for DateTimeCreated {}
+impl JournalCProperty for DateTimeCreated {}
+
+impl ParticipantCProperty for DateTimeCreated {}
+
 /// Date-Time Stamp
 ///
 /// Property Name:  DTSTAMP
@@ -57,6 +112,8 @@ use crate::structure::{
 ///
 /// Reference: [RFC 5545
 /// 3.8.7.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.7.2)
+///
+/// [2447bis]: https://www.rfc-editor.org/rfc/rfc5545#ref-2447bis
 pub struct DateTimeStamp;
 
 impl Property for DateTimeStamp {
@@ -69,3 +126,135 @@ impl EventCProperty for DateTimeStamp {}
 impl TodoCProperty for DateTimeStamp {}
 impl JournalCProperty for DateTimeStamp {}
 impl FreeBusyCProperty for DateTimeStamp {}
+
+impl ParticipantCProperty for DateTimeStamp {}
+
+/// Last Modified
+///
+/// Property Name:  LAST-MODIFIED
+///
+/// Purpose:  This property specifies the date and time that the information
+/// associated with the calendar component was last revised in the calendar
+/// store.
+///
+/// Note: This is analogous to the modification date and time for a file in
+/// the file system.
+///
+/// Value Type:  DATE-TIME
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in the "VEVENT", "VTODO",
+/// "VJOURNAL", or "VTIMEZONE" calendar components.
+///
+/// Description:  The property value MUST be specified in the UTC time
+/// format.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// last-mod   = "LAST-MODIFIED" lstparam ":" date-time CRLF
+///
+/// lstparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// LAST-MODIFIED:19960817T133000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.7.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.7.3)
+pub struct LastModified;
+
+impl Property for LastModified {
+    const NAME: &'static str = "LAST-MODIFIED";
+
+    type CompositeValueType = DateTimeUtc;
+}
+
+impl EventCProperty for LastModified {}
+impl TodoCProperty for LastModified {}
+impl JournalCProperty for LastModified {}
+impl TimeZoneCProperty for LastModified {}
+
+// Allowed on the iCalendar object by RFC 7986 5.4
+impl ICalObjectProperty for LastModified {}
+
+impl ParticipantCProperty for LastModified {}
+
+/// Sequence Number
+///
+/// Property Name:  SEQUENCE
+///
+/// Purpose:  This property defines the revision sequence number of the
+/// calendar component within a sequence of revisions.
+///
+/// Value Type:  INTEGER
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  The property can be specified in "VEVENT", "VTODO", or
+/// "VJOURNAL" calendar component.
+///
+/// Description:  When a calendar component is created, its sequence number
+/// is 0.  It is monotonically incremented by the "Organizer's" CUA each time
+/// the "Organizer" makes a significant revision to the calendar component.
+///
+/// The "Organizer" includes this property in an iCalendar object that it
+/// sends to an "Attendee" to specify the current version of the calendar
+/// component.
+///
+/// The "Attendee" includes this property in an iCalendar object that it
+/// sends to the "Organizer" to specify the version of the calendar component
+/// to which the "Attendee" is referring.
+///
+/// A change to the sequence number is not the mechanism that an "Organizer"
+/// uses to request a response from the "Attendees".  The "RSVP" parameter
+/// on the "ATTENDEE" property is used by the "Organizer" to indicate that a
+/// response from the "Attendees" is requested.
+///
+/// Recurrence instances of a recurring component MAY have different sequence
+/// numbers.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// seq = "SEQUENCE" seqparam ":" integer CRLF
+/// ; Default is "0"
+///
+/// seqparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property for a calendar
+/// component that was just created by the "Organizer":
+///
+/// ```ics
+/// SEQUENCE:0
+/// ```
+///
+/// The following is an example of this property for a calendar component
+/// that has been revised two different times by the "Organizer":
+///
+/// ```ics
+/// SEQUENCE:2
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.7.4](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.7.4)
+pub struct SequenceNumber;
+
+impl Property for SequenceNumber {
+    const NAME: &'static str = "SEQUENCE";
+
+    type CompositeValueType = Integer;
+}
+
+impl EventCProperty for SequenceNumber {}
+impl TodoCProperty for SequenceNumber {}
+impl JournalCProperty for SequenceNumber {}
+
+impl ParticipantCProperty for SequenceNumber {}
@@ -2,13 +2,205 @@
 
 use crate::structure::{
     composite_value_types::{Any2, Any3, List},
-    icalstream::components::{
-        DaylightCProperty, EventCProperty, FreeBusyCProperty, StandardCProperty, TodoCProperty,
+    icalstream::{
+        components::{
+            AlarmCProperty, DaylightCProperty, EventCProperty, FreeBusyCProperty, JournalCProperty,
+            StandardCProperty, TodoCProperty,
+        },
+        parameters,
     },
-    value_types::{Date, DateTime, PeriodOfTime, Text},
-    Property,
+    value_types::{Date, DateTime, DateTimeUtc, Duration, PeriodOfTime, PeriodOfTimeUtc, Text},
+    AcceptsParam, Property,
 };
 
+/// Date-Time Completed
+///
+/// Property Name:  COMPLETED
+///
+/// Purpose:  This property defines the date and time that a to-do was
+/// actually completed.
+///
+/// Value Type:  DATE-TIME
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  The property can be specified in a "VTODO" calendar
+/// component.  The value MUST be specified as a date with UTC time.
+///
+/// Description:  This property defines the date and time that a to-do was
+/// actually completed.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// completed  = "COMPLETED" compparam ":" date-time CRLF
+///
+/// compparam  = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// COMPLETED:19960401T150000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.2.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.2.1)
+pub struct DateTimeCompleted;
+
+impl Property for DateTimeCompleted {
+    const NAME: &'static str = "COMPLETED";
+
+    type CompositeValueType = DateTimeUtc;
+}
+
+impl TodoCProperty for DateTimeCompleted {}
+
+/// Date-Time End
+///
+/// Property Name:  DTEND
+///
+/// Purpose:  This property specifies the date and time that a calendar
+/// component ends.
+///
+/// Value Type:  The default value type is DATE-TIME.  The value type can be
+/// set to a DATE value type.
+///
+/// Property Parameters:  IANA, non-standard, value data type, and time zone
+/// identifier property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified in "VEVENT" or "VFREEBUSY"
+/// calendar components.
+///
+/// Description:  Within the "VEVENT" calendar component, this property
+/// defines the date and time by which the event ends.  The value type of this
+/// property MUST be the same as the "DTSTART" property, and its value MUST be
+/// later in time than the value of the "DTSTART" property.  Furthermore, this
+/// property MUST be specified as a date with local time if and only if the
+/// "DTSTART" property is also specified as a date with local time.
+///
+/// Within the "VFREEBUSY" calendar component, this property defines the end
+/// date and time for the free or busy time information.  The time MUST be
+/// specified in the UTC time format.  The value MUST be later in time than
+/// the value of the "DTSTART" property.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// dtend      = "DTEND" dtendparam ":" dtendval CRLF
+///
+/// dtendparam = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" "VALUE" "=" ("DATE-TIME" / "DATE")) /
+///            (";" tzidparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+///
+/// dtendval   = date-time / date
+/// ;Value MUST match value type
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// DTEND:19960401T150000Z
+///
+/// DTEND;VALUE=DATE:19980704
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.2.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.2.2)
+pub struct DateTimeEnd;
+
+impl Property for DateTimeEnd {
+    const NAME: &'static str = "DTEND";
+
+    type CompositeValueType = Any2<DateTime, Date>;
+}
+
+impl EventCProperty for DateTimeEnd {}
+impl FreeBusyCProperty for DateTimeEnd {}
+
+impl AcceptsParam<parameters::TimeZoneIdentifier> for DateTimeEnd {}
+
+/// Date-Time Due
+///
+/// Property Name:  DUE
+///
+/// Purpose:  This property defines the date and time that a to-do is
+/// expected to be completed.
+///
+/// Value Type:  The default value type is DATE-TIME.  The value type can be
+/// set to a DATE value type.
+///
+/// Property Parameters:  IANA, non-standard, value data type, and time zone
+/// identifier property parameters can be specified on this property.
+///
+/// Conformance:  The property can be specified once in a "VTODO" calendar
+/// component.
+///
+/// Description:  This property defines the date and time before which a
+/// to-do is expected to be completed.  For cases where this property is
+/// specified in a "VTODO" calendar component that also specifies a "DTSTART"
+/// property, the value type of this property MUST be the same as the
+/// "DTSTART" property, and the value of this property MUST be later in time
+/// than the value of the "DTSTART" property.  Furthermore, this property MUST
+/// be specified as a date with local time if and only if the "DTSTART"
+/// property is also specified as a date with local time.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// due        = "DUE" dueparam ":" dueval CRLF
+///
+/// dueparam   = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" "VALUE" "=" ("DATE-TIME" / "DATE")) /
+///            (";" tzidparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+///
+/// dueval     = date-time / date
+/// ;Value MUST match value type
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// DUE:19980430T000000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.2.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.2.3)
+pub struct DateTimeDue;
+
+impl Property for DateTimeDue {
+    const NAME: &'static str = "DUE";
+
+    type CompositeValueType = Any2<DateTime, Date>;
+}
+
+impl TodoCProperty for DateTimeDue {}
+
+impl AcceptsParam<parameters::TimeZoneIdentifier> for DateTimeDue {}
+
 /// Date-Time Start
 ///
 /// Property Name:  DTSTART
@@ -84,10 +276,151 @@ impl Property for DateTimeStart {
 
 impl EventCProperty for DateTimeStart {}
 impl TodoCProperty for DateTimeStart {}
+impl JournalCProperty for DateTimeStart {}
 impl FreeBusyCProperty for DateTimeStart {}
 impl StandardCProperty for DateTimeStart {}
 impl DaylightCProperty for DateTimeStart {}
 
+impl AcceptsParam<parameters::TimeZoneIdentifier> for DateTimeStart {}
+
+/// Duration
+///
+/// Property Name:  DURATION
+///
+/// Purpose:  This property specifies a positive duration of time.
+///
+/// Value Type:  DURATION
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in "VEVENT", "VTODO", or
+/// "VALARM" calendar components.
+///
+/// Description:  In a "VEVENT" calendar component the property may be used
+/// to specify a duration of the event, instead of an explicit end DATE-TIME.
+/// In a "VTODO" calendar component the property may be used to specify a
+/// duration for the to-do, instead of an explicit due DATE-TIME.  In a
+/// "VALARM" calendar component the property may be used to specify the delay
+/// period prior to repeating an alarm.  When the "DURATION" property relates
+/// to a "DTSTART" property that is specified as a DATE value, then the
+/// "DURATION" property MUST be specified as a "dur-day" or "dur-week" value.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// duration   = "DURATION" durparam ":" dur-value CRLF
+///              ;consisting of a positive duration of time.
+///
+/// durparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property that specifies an
+/// interval of time of one hour and zero minutes and zero seconds:
+///
+/// ```ics
+/// DURATION:PT1H0M0S
+/// ```
+///
+/// The following is an example of this property that specifies an interval
+/// of time of 15 minutes.
+///
+/// ```ics
+/// DURATION:PT15M
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.2.5](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.2.5)
+pub struct DurationProperty;
+
+impl Property for DurationProperty {
+    const NAME: &'static str = "DURATION";
+
+    type CompositeValueType = Duration;
+}
+
+impl EventCProperty for DurationProperty {}
+impl TodoCProperty for DurationProperty {}
+impl AlarmCProperty for DurationProperty {}
+
+/// Free/Busy Time
+///
+/// Property Name:  FREEBUSY
+///
+/// Purpose:  This property defines one or more free or busy time intervals.
+///
+/// Value Type:  PERIOD
+///
+/// Property Parameters:  IANA, non-standard, and free/busy time type
+/// property parameters can be specified on this property.
+///
+/// Conformance:  The property can be specified in a "VFREEBUSY" calendar
+/// component.
+///
+/// Description:  These time periods can be specified as either a start and
+/// end DATE-TIME or a start DATE-TIME and DURATION.  The date and time MUST
+/// be a UTC time format.
+///
+/// "FREEBUSY" properties within the "VFREEBUSY" calendar component SHOULD be
+/// sorted in ascending order, based on start time and then end time, with the
+/// earliest periods first.
+///
+/// The "FREEBUSY" property can specify more than one value, separated by the
+/// COMMA character.  In such cases, the "FREEBUSY" property values MUST all
+/// be of the same "FBTYPE" property parameter type (e.g., all values of a
+/// particular "FBTYPE" listed together in a single property).
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// freebusy   = "FREEBUSY" fbparam ":" fbvalue CRLF
+///
+/// fbparam    = *(
+///            ;
+///            ; The following is OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" fbtypeparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+///
+/// fbvalue    = period *("," period)
+/// ;Time value MUST be in the UTC time format.
+/// ```
+///
+/// Example:  The following are some examples of this property:
+///
+/// ```ics
+/// FREEBUSY;FBTYPE=BUSY-UNAVAILABLE:19970308T160000Z/PT8H30M
+///
+/// FREEBUSY;FBTYPE=FREE:19970308T160000Z/PT3H,19970308T200000Z/PT1H
+///
+/// FREEBUSY;FBTYPE=FREE:19970308T160000Z/PT3H,19970308T200000Z/PT1H
+///  ,19970308T230000Z/19970309T000000Z
+/// ```
+///
+/// See [PeriodUtc](crate::write::value_types::PeriodUtc) for merging periods
+/// into sorted, non-overlapping values.
+///
+/// Reference: [RFC 5545
+/// 3.8.2.6](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.2.6)
+pub struct FreeBusyTime;
+
+impl Property for FreeBusyTime {
+    const NAME: &'static str = "FREEBUSY";
+
+    type CompositeValueType = List<PeriodOfTimeUtc>;
+}
+
+impl FreeBusyCProperty for FreeBusyTime {}
+
+impl AcceptsParam<parameters::FreeBusyTimeType> for FreeBusyTime {}
+
 /// Time Transparency
 ///
 /// Property Name:  TRANSP
@@ -243,3 +576,9 @@ impl Property for RecurrenceDateTimes {
 }
 
 impl EventCProperty for RecurrenceDateTimes {}
+impl TodoCProperty for RecurrenceDateTimes {}
+impl JournalCProperty for RecurrenceDateTimes {}
+impl StandardCProperty for RecurrenceDateTimes {}
+impl DaylightCProperty for RecurrenceDateTimes {}
+
+impl AcceptsParam<parameters::TimeZoneIdentifier> for RecurrenceDateTimes {}
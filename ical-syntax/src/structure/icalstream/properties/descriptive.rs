@@ -1,12 +1,109 @@
 //! [RFC 5545 3.8.1: Descriptive Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1)
 
 use crate::structure::{
-    composite_value_types::List,
-    icalstream::components::{AlarmCProperty, EventCProperty, JournalCProperty, TodoCProperty},
-    value_types::{Float, Text},
-    Property,
+    composite_value_types::{Any2, List},
+    icalstream::{
+        components::{
+            AlarmCProperty, DaylightCProperty, EventCProperty, FreeBusyCProperty,
+            ICalObjectProperty, JournalCProperty, LocationCProperty, ParticipantCProperty,
+            ResourceCProperty, StandardCProperty, TodoCProperty,
+        },
+        parameters,
+    },
+    value_types::{Binary, Float, Integer, Text, Uri},
+    AcceptsParam, Property,
 };
 
+/// Attachment
+///
+/// Property Name:  ATTACH
+///
+/// Purpose:  This property provides the capability to associate a document
+/// object with a calendar component.
+///
+/// Value Type:  The default value type for this property is URI.  The value
+/// type can also be set to BINARY to indicate inline binary encoded content
+/// information.
+///
+/// Property Parameters:  IANA, non-standard, inline encoding, and value data
+/// type property parameters can be specified on this property.  The format
+/// type parameter can be specified on this property and is RECOMMENDED for
+/// inline binary encoded content information.
+///
+/// Conformance:  This property can be specified multiple times in a "VEVENT",
+/// "VTODO", "VJOURNAL", or "VALARM" calendar component with the exception of
+/// AUDIO alarm that only allows this property to occur once.
+///
+/// Description:  This property is used in "VEVENT", "VTODO", and "VJOURNAL"
+/// calendar components to associate a resource (e.g., document) with the
+/// calendar component.  This property is used in "VALARM" calendar components
+/// to specify an audio sound resource or an email message attachment.  This
+/// property can be specified as a URI pointing to a resource or as inline
+/// binary encoded content.
+///
+/// When this property is specified as inline binary encoded content,
+/// calendar applications MAY attempt to guess the media type of the resource
+/// via inspection of its content if and only if the media type of the
+/// resource is not given by the "FMTTYPE" parameter.  If the media type
+/// remains unknown, calendar applications SHOULD treat it as type
+/// "application/octet-stream".
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// attach     = "ATTACH" attachparam ( ":" uri ) /
+///              (
+///                ";" "ENCODING" "=" "BASE64"
+///                ";" "VALUE" "=" "BINARY"
+///                ":" binary
+///              )
+///              CRLF
+///
+/// attachparam = *(
+///             ;
+///             ; The following is OPTIONAL for a URI value,
+///             ; RECOMMENDED for a BINARY value,
+///             ; and MUST NOT occur more than once.
+///             ;
+///             (";" fmttypeparam) /
+///             ;
+///             ; The following is OPTIONAL,
+///             ; and MAY occur more than once.
+///             ;
+///             (";" other-param)
+///             ;
+///             )
+/// ```
+///
+/// Example:  The following are examples of this property:
+///
+/// ```text
+/// ATTACH:CID:jsmith.part3.960817T083000.xyzMail@example.com
+///
+/// ATTACH;FMTTYPE=application/postscript:ftp://example.com/pub/
+///  reports/r-960812.ps
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.1)
+pub struct Attach;
+
+impl Property for Attach {
+    const NAME: &'static str = "ATTACH";
+
+    type CompositeValueType = Any2<Uri, Binary>;
+}
+
+impl EventCProperty for Attach {}
+impl TodoCProperty for Attach {}
+impl JournalCProperty for Attach {}
+impl AlarmCProperty for Attach {}
+
+impl ParticipantCProperty for Attach {}
+
+impl AcceptsParam<parameters::FormatType> for Attach {}
+impl AcceptsParam<parameters::InlineEncoding> for Attach {}
+
 /// Categories
 ///
 /// Property Name:  CATEGORIES
@@ -66,6 +163,232 @@ impl Property for Categories {
     type CompositeValueType = List<Text>;
 }
 
+impl EventCProperty for Categories {}
+impl TodoCProperty for Categories {}
+impl JournalCProperty for Categories {}
+
+// Allowed on the iCalendar object by RFC 7986 5.6
+impl ICalObjectProperty for Categories {}
+
+impl ParticipantCProperty for Categories {}
+
+impl AcceptsParam<parameters::Language> for Categories {}
+
+/// Classification
+///
+/// Property Name:  CLASS
+///
+/// Purpose:  This property defines the access classification for a calendar
+/// component.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  The property can be specified once in a "VEVENT", "VTODO",
+/// or "VJOURNAL" calendar components.
+///
+/// Description:  An access classification is only one component of the
+/// general security system within a calendar application.  It provides a
+/// method of capturing the scope of the access the calendar owner intends for
+/// information within an individual calendar entry.  The access
+/// classification of an individual iCalendar component is useful when
+/// measured along with the other security components of a calendar system
+/// (e.g., calendar user authentication, authorization, access rights, access
+/// role, etc.).  Hence, the semantics of the individual access
+/// classifications cannot be completely defined by this memo alone.
+/// Additionally, due to the "blind" nature of most exchange processes using
+/// this memo, these access classifications cannot serve as an enforcement
+/// statement for a system receiving an iCalendar object.  Rather, they
+/// provide a method for capturing the intention of the calendar owner for
+/// the access to the calendar component.  If not specified in a component
+/// that allows this property, the default value is PUBLIC.  Applications
+/// MUST treat x-name and iana-token values they don't recognize the same way
+/// as they would the PRIVATE value.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// class      = "CLASS" classparam ":" classvalue CRLF
+///
+/// classparam = *(";" other-param)
+///
+/// classvalue = "PUBLIC" / "PRIVATE" / "CONFIDENTIAL" / iana-token
+///            / x-name
+/// ;Default is PUBLIC
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// CLASS:PUBLIC
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.3)
+pub struct Classification;
+
+impl Property for Classification {
+    const NAME: &'static str = "CLASS";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for Classification {}
+impl TodoCProperty for Classification {}
+impl JournalCProperty for Classification {}
+
+/// Comment
+///
+/// Property Name:  COMMENT
+///
+/// Purpose:  This property specifies non-processing information intended to
+/// provide a comment to the calendar user.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, alternate text representation,
+/// and language property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified multiple times in "VEVENT",
+/// "VTODO", "VJOURNAL", and "VFREEBUSY" calendar components as well as in the
+/// "STANDARD" and "DAYLIGHT" sub-components.
+///
+/// Description:  This property is used to specify a comment to the calendar
+/// user.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// comment    = "COMMENT" commparam ":" text CRLF
+///
+/// commparam  = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" altrepparam) / (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// COMMENT:The meeting really needs to include both ourselves
+///   and the customer.  We can't hold this meeting without them.
+///   As a matter of fact\, the venue for the meeting ought to be at
+///   their site. - - John
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.4](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.4)
+pub struct Comment;
+
+impl Property for Comment {
+    const NAME: &'static str = "COMMENT";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for Comment {}
+impl TodoCProperty for Comment {}
+impl JournalCProperty for Comment {}
+impl FreeBusyCProperty for Comment {}
+impl StandardCProperty for Comment {}
+impl DaylightCProperty for Comment {}
+
+impl ParticipantCProperty for Comment {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Comment {}
+impl AcceptsParam<parameters::Language> for Comment {}
+
+/// Description
+///
+/// Property Name:  DESCRIPTION
+///
+/// Purpose:  This property provides a more complete description of the
+/// calendar component than that provided by the "SUMMARY" property.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, alternate text representation,
+/// and language property parameters can be specified on this property.
+///
+/// Conformance:  The property can be specified in the "VEVENT", "VTODO",
+/// "VJOURNAL", or "VALARM" calendar components.  The property can be
+/// specified multiple times only within a "VJOURNAL" calendar component.
+///
+/// Description:  This property is used in the "VEVENT" and "VTODO" to capture
+/// lengthy textual descriptions associated with the activity.
+///
+/// This property is used in the "VJOURNAL" calendar component to capture one
+/// or more textual journal entries.
+///
+/// This property is used in the "VALARM" calendar component to capture the
+/// display text for a DISPLAY category of alarm, and to capture the body text
+/// for an EMAIL category of alarm.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// description = "DESCRIPTION" descparam ":" text CRLF
+///
+/// descparam   = *(
+///             ;
+///             ; The following are OPTIONAL,
+///             ; but MUST NOT occur more than once.
+///             ;
+///             (";" altrepparam) / (";" languageparam) /
+///             ;
+///             ; The following is OPTIONAL,
+///             ; and MAY occur more than once.
+///             ;
+///             (";" other-param)
+///             ;
+///             )
+/// ```
+///
+/// Example:  The following is an example of this property with formatted
+/// line breaks in the property value:
+///
+/// ```text
+/// DESCRIPTION:Meeting to provide technical review for "Phoenix"
+///   design.\nHappy Face Conference Room. Phoenix design team
+///   MUST attend this meeting.\nRSVP to team leader.
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.5](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.5)
+pub struct Description;
+
+impl Property for Description {
+    const NAME: &'static str = "DESCRIPTION";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for Description {}
+impl TodoCProperty for Description {}
+impl JournalCProperty for Description {}
+impl AlarmCProperty for Description {}
+
+// Allowed on the iCalendar object by RFC 7986 5.2
+impl ICalObjectProperty for Description {}
+
+impl ParticipantCProperty for Description {}
+impl LocationCProperty for Description {}
+impl ResourceCProperty for Description {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Description {}
+impl AcceptsParam<parameters::Language> for Description {}
+
 /// Geographic Position
 ///
 /// Property Name:  GEO
@@ -155,6 +478,367 @@ impl Property for Geo {
     type CompositeValueType = (Float, Float); // Lat, long
 }
 
+impl EventCProperty for Geo {}
+impl TodoCProperty for Geo {}
+
+impl ParticipantCProperty for Geo {}
+impl LocationCProperty for Geo {}
+impl ResourceCProperty for Geo {}
+
+/// Location
+///
+/// Property Name:  LOCATION
+///
+/// Purpose:  This property defines the intended venue for the activity
+/// defined by a calendar component.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, alternate text representation,
+/// and language property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified in "VEVENT" or "VTODO"
+/// calendar component.
+///
+/// Description:  Specific venues such as conference or meeting rooms may be
+/// explicitly specified using this property.  An alternate representation
+/// may be specified that is a URI that points to directory information with
+/// more structured specification of the location.  For example, the
+/// alternate representation may specify either an LDAP URL [RFC4516]
+/// pointing to an LDAP server entry or a CID URL [RFC2392] pointing to a
+/// MIME body part containing a Virtual-Information Card (vCard) [RFC2426]
+/// for the location.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// location   = "LOCATION"  locparam ":" text CRLF
+///
+/// locparam   = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" altrepparam) / (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following are some examples of this property:
+///
+/// ```text
+/// LOCATION:Conference Room - F123\, Bldg. 002
+///
+/// LOCATION;ALTREP="http://xyzcorp.com/conf-rooms/f123.vcf":
+///  Conference Room - F123\, Bldg. 002
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.7](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.7)
+///
+/// [RFC4516]: https://www.rfc-editor.org/rfc/rfc4516
+/// [RFC2392]: https://www.rfc-editor.org/rfc/rfc2392
+/// [RFC2426]: https://www.rfc-editor.org/rfc/rfc2426
+pub struct Location;
+
+impl Property for Location {
+    const NAME: &'static str = "LOCATION";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for Location {}
+impl TodoCProperty for Location {}
+
+impl ParticipantCProperty for Location {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Location {}
+impl AcceptsParam<parameters::Language> for Location {}
+
+/// Percent Complete
+///
+/// Property Name:  PERCENT-COMPLETE
+///
+/// Purpose:  This property is used by an assignee or delegatee of a to-do to
+/// convey the percent completion of a to-do to the "Organizer".
+///
+/// Value Type:  INTEGER
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in a "VTODO" calendar
+/// component.
+///
+/// Description:  The property value is a positive integer between 0 and 100.
+/// A value of "0" indicates the to-do has not yet been started.  A value of
+/// "100" indicates that the to-do has been completed.  Integer values in
+/// between indicate the percent partially complete.
+///
+/// When a to-do is assigned to multiple individuals, the property value
+/// indicates the percent complete for that portion of the to-do assigned to
+/// the assignee or delegatee.  For example, if a to-do is assigned to both
+/// individuals "A" and "B".  A reply from "A" with a percent complete of "70"
+/// indicates that "A" has completed 70% of the to-do assigned to them.  A
+/// reply from "B" with a percent complete of "50" indicates "B" has completed
+/// 50% of the to-do assigned to them.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// percent = "PERCENT-COMPLETE" pctparam ":" integer CRLF
+///
+/// pctparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property to show 39%
+/// completion:
+///
+/// ```text
+/// PERCENT-COMPLETE:39
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.8](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.8)
+pub struct PercentComplete;
+
+impl Property for PercentComplete {
+    const NAME: &'static str = "PERCENT-COMPLETE";
+
+    type CompositeValueType = Integer;
+}
+
+impl TodoCProperty for PercentComplete {}
+
+/// Priority
+///
+/// Property Name:  PRIORITY
+///
+/// Purpose:  This property defines the relative priority for a calendar
+/// component.
+///
+/// Value Type:  INTEGER
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in "VEVENT" and "VTODO"
+/// calendar components.
+///
+/// Description:  This priority is specified as an integer in the range 0 to
+/// 9.  A value of 0 specifies an undefined priority.  A value of 1 is the
+/// highest priority.  A value of 2 is the second highest priority.
+/// Subsequent numbers specify a decreasing ordinal priority.  A value of 9 is
+/// the lowest priority.
+///
+/// A CUA with a three-level priority scheme of "HIGH", "MEDIUM", and "LOW" is
+/// mapped into this property such that a property value in the range of 1 to
+/// 4 specifies "HIGH" priority.  A value of 5 is the normal or "MEDIUM"
+/// priority.  A value in the range of 6 to 9 is "LOW" priority.
+///
+/// Other integer values are reserved for future use.
+///
+/// Within a "VEVENT" calendar component, this property specifies a priority
+/// for the event.  This property may be useful when more than one event is
+/// scheduled for a given time period.
+///
+/// Within a "VTODO" calendar component, this property specified a priority
+/// for the to-do.  This property is useful in prioritizing multiple action
+/// items for a given time period.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// priority   = "PRIORITY" prioparam ":" priovalue CRLF
+/// ;Default is zero (i.e., undefined).
+///
+/// prioparam  = *(";" other-param)
+///
+/// priovalue   = integer       ;Must be in the range [0..9]
+///    ; All other values are reserved for future use.
+/// ```
+///
+/// Example:  The following is an example of a property with the highest
+/// priority:
+///
+/// ```text
+/// PRIORITY:1
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.9](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.9)
+pub struct Priority;
+
+impl Property for Priority {
+    const NAME: &'static str = "PRIORITY";
+
+    type CompositeValueType = Integer;
+}
+
+impl EventCProperty for Priority {}
+impl TodoCProperty for Priority {}
+
+impl ParticipantCProperty for Priority {}
+
+/// Resources
+///
+/// Property Name:  RESOURCES
+///
+/// Purpose:  This property defines the equipment or resources anticipated
+/// for an activity specified by a calendar component.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, alternate text representation,
+/// and language property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified once in "VEVENT" or "VTODO"
+/// calendar component.
+///
+/// Description:  The property value is an arbitrary text.  More than one
+/// resource can be specified as a COMMA-separated list of resources.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// resources  = "RESOURCES" resrcparam ":" text *("," text) CRLF
+///
+/// resrcparam = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" altrepparam) / (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// RESOURCES:EASEL,PROJECTOR,VCR
+///
+/// RESOURCES;LANGUAGE=fr:Nettoyeur haute pression
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.10](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.10)
+pub struct Resources;
+
+impl Property for Resources {
+    const NAME: &'static str = "RESOURCES";
+
+    type CompositeValueType = List<Text>;
+}
+
+impl EventCProperty for Resources {}
+impl TodoCProperty for Resources {}
+
+impl ParticipantCProperty for Resources {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Resources {}
+impl AcceptsParam<parameters::Language> for Resources {}
+
+/// Status
+///
+/// Property Name:  STATUS
+///
+/// Purpose:  This property defines the overall status or confirmation for
+/// the calendar component.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in "VEVENT", "VTODO", or
+/// "VJOURNAL" calendar components.
+///
+/// Description:  In a group-scheduled calendar component, the property is
+/// used by the "Organizer" to provide a confirmation of the event to the
+/// "Attendees".  For example in a "VEVENT" calendar component, the
+/// "Organizer" can indicate that a meeting is tentative, confirmed, or
+/// cancelled.  In a "VTODO" calendar component, the "Organizer" can indicate
+/// that an action item needs action, is completed, is in process or being
+/// worked on, or has been cancelled.  In a "VJOURNAL" calendar component, the
+/// "Organizer" can indicate that a journal entry is draft, final, or has been
+/// cancelled or removed.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// status          = "STATUS" statparam ":" statvalue CRLF
+///
+/// statparam       = *(";" other-param)
+///
+/// statvalue       = (statvalue-event
+///                 /  statvalue-todo
+///                 /  statvalue-jour)
+///
+/// statvalue-event = "TENTATIVE"    ;Indicates event is tentative.
+///                 / "CONFIRMED"    ;Indicates event is definite.
+///                 / "CANCELLED"    ;Indicates event was cancelled.
+/// ;Status values for a "VEVENT"
+///
+/// statvalue-todo  = "NEEDS-ACTION" ;Indicates to-do needs action.
+///                 / "COMPLETED"    ;Indicates to-do completed.
+///                 / "IN-PROCESS"   ;Indicates to-do in process of.
+///                 / "CANCELLED"    ;Indicates to-do was cancelled.
+/// ;Status values for "VTODO".
+///
+/// statvalue-jour  = "DRAFT"        ;Indicates journal is draft.
+///                 / "FINAL"        ;Indicates journal is final.
+///                 / "CANCELLED"    ;Indicates journal is removed.
+/// ;Status values for "VJOURNAL".
+/// ```
+///
+/// Example:  The following is an example of this property for a "VEVENT"
+/// calendar component:
+///
+/// ```text
+/// STATUS:TENTATIVE
+/// ```
+///
+/// The following is an example of this property for a "VTODO" calendar
+/// component:
+///
+/// ```text
+/// STATUS:NEEDS-ACTION
+/// ```
+///
+/// The following is an example of this property for a "VJOURNAL" calendar
+/// component:
+///
+/// ```text
+/// STATUS:DRAFT
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.1.11](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.1.11)
+pub struct Status;
+
+impl Property for Status {
+    const NAME: &'static str = "STATUS";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for Status {}
+impl TodoCProperty for Status {}
+impl JournalCProperty for Status {}
+
+impl ParticipantCProperty for Status {}
+
 /// Summary
 ///
 /// Property Name:  SUMMARY
@@ -217,3 +901,8 @@ impl EventCProperty for Summary {}
 impl TodoCProperty for Summary {}
 impl JournalCProperty for Summary {}
 impl AlarmCProperty for Summary {}
+
+impl ParticipantCProperty for Summary {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Summary {}
+impl AcceptsParam<parameters::Language> for Summary {}
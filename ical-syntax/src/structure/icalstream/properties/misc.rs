@@ -1 +1,104 @@
 //! [RFC 5545 3.8.8: Miscellaneous Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.8)
+
+use crate::structure::{
+    composite_value_types::Any2,
+    icalstream::{
+        components::{
+            EventCProperty, FreeBusyCProperty, JournalCProperty, ParticipantCProperty,
+            TodoCProperty,
+        },
+        parameters,
+    },
+    value_types::Text,
+    AcceptsParam, Property,
+};
+
+/// Request Status
+///
+/// Property Name:  REQUEST-STATUS
+///
+/// Purpose:  This property defines the status code returned for a scheduling
+/// request.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, and language property parameters
+/// can be specified on this property.
+///
+/// Conformance:  The property can be specified in the "VEVENT", "VTODO",
+/// "VJOURNAL", or "VFREEBUSY" calendar component.
+///
+/// Description:  This property is used to return status code information
+/// related to the processing of an associated iCalendar object.  The value
+/// type for this property is TEXT.
+///
+/// The value consists of a short return status component, a longer return
+/// status description component, and optionally a status-specific data
+/// component.  The components of the value are separated by the SEMICOLON
+/// character.
+///
+/// The short return status is a PERIOD character separated pair or 3-tuple of
+/// integers.  For example, "3.1" or "3.1.1".  The successive levels of
+/// integers provide for a successive level of status code granularity.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// rstatus    = "REQUEST-STATUS" rstatparam ":"
+///              statcode ";" statdesc [";" extdata]
+///
+/// rstatparam = *(
+///              ;
+///              ; The following is OPTIONAL,
+///              ; but MUST NOT occur more than once.
+///              ;
+///              (";" languageparam) /
+///              ;
+///              ; The following is OPTIONAL,
+///              ; and MAY occur more than once.
+///              ;
+///              (";" other-param)
+///              ;
+///              )
+///
+/// statcode   = 1*DIGIT 1*2("." 1*DIGIT)
+/// ;Hierarchical, numeric return status code
+///
+/// statdesc   = text
+/// ;Textual status description
+///
+/// extdata    = text
+/// ;Textual exception data.  For example, the offending property
+/// ;name and value or complete property line.
+/// ```
+///
+/// Example:  The following are some possible examples of this property.
+///
+/// ```text
+/// REQUEST-STATUS:2.0;Success
+///
+/// REQUEST-STATUS:3.1;Invalid property value;DTSTART:96-Apr-01
+///
+/// REQUEST-STATUS:2.8; Success\, repeating event ignored. Scheduled
+///  as a single event.;RRULE:FREQ=WEEKLY\;INTERVAL=2
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.8.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.8.3)
+pub struct RequestStatus;
+
+impl Property for RequestStatus {
+    const NAME: &'static str = "REQUEST-STATUS";
+
+    // (statcode, statdesc), or (statcode, statdesc, extdata)
+    type CompositeValueType = Any2<(Text, Text), (Text, Text, Text)>;
+}
+
+impl EventCProperty for RequestStatus {}
+impl TodoCProperty for RequestStatus {}
+impl JournalCProperty for RequestStatus {}
+impl FreeBusyCProperty for RequestStatus {}
+
+impl ParticipantCProperty for RequestStatus {}
+
+impl AcceptsParam<parameters::Language> for RequestStatus {}
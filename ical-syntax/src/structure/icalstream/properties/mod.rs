@@ -6,4 +6,6 @@ pub mod descriptive;
 pub mod misc;
 pub mod recurrence;
 pub mod relationship;
+pub mod rfc7986;
+pub mod rfc9073;
 pub mod time_zone;
@@ -1 +1,205 @@
 //! [RFC 5545 3.8.5: Recurrence Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.5)
+//!
+//! See also [RecurrenceDateTimes](super::date_and_time::RecurrenceDateTimes).
+//!
+//! RRULE SHOULD NOT occur more than once in a component. The typed writers
+//! report this as a [Warning](crate::write::icalstream::typed_writers::Warning).
+
+use crate::structure::{
+    composite_value_types::{Any2, List},
+    icalstream::{
+        components::{
+            DaylightCProperty, EventCProperty, JournalCProperty, StandardCProperty, TodoCProperty,
+        },
+        parameters,
+    },
+    value_types::{Date, DateTime, RecurrenceRule as RecurrenceRuleValue},
+    AcceptsParam, Property,
+};
+
+/// Exception Date-Times
+///
+/// Property Name:  EXDATE
+///
+/// Purpose:  This property defines the list of DATE-TIME exceptions for
+/// recurring events, to-dos, journal entries, or time zone definitions.
+///
+/// Value Type:  The default value type for this property is DATE-TIME.  The
+/// value type can be set to DATE.
+///
+/// Property Parameters:  IANA, non-standard, value data type, and time zone
+/// identifier property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified in recurring "VEVENT",
+/// "VTODO", and "VJOURNAL" calendar components as well as in the "STANDARD"
+/// and "DAYLIGHT" sub-components of the "VTIMEZONE" calendar component.
+///
+/// Description:  The exception dates, if specified, are used in computing the
+/// recurrence set.  The recurrence set is the complete set of recurrence
+/// instances for a calendar component.  The recurrence set is generated by
+/// considering the initial "DTSTART" property along with the "RRULE",
+/// "RDATE", and "EXDATE" properties contained within the recurring
+/// component.  The "DTSTART" property defines the first instance in the
+/// recurrence set.  The "DTSTART" property value SHOULD match the pattern of
+/// the recurrence rule, if specified.  The recurrence set generated with a
+/// "DTSTART" property value that doesn't match the pattern of the rule is
+/// undefined.  The final recurrence set is generated by gathering all of the
+/// start DATE-TIME values generated by any of the specified "RRULE" and
+/// "RDATE" properties, and then excluding any start DATE-TIME values
+/// specified by "EXDATE" properties.  This implies that start DATE-TIME
+/// values specified by "EXDATE" properties take precedence over those
+/// specified by inclusion properties (i.e., "RDATE" and "RRULE").  When
+/// duplicate instances are generated by the "RRULE" and "RDATE" properties,
+/// only one recurrence is considered.  Duplicate instances are ignored.
+///
+/// The "EXDATE" property can be used to exclude the value specified in
+/// "DTSTART".  However, in such cases, the original "DTSTART" date MUST still
+/// be maintained by the calendaring and scheduling system because the
+/// original "DTSTART" value has inherent usage dependencies by other
+/// properties such as the "RECURRENCE-ID".
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// exdate     = "EXDATE" exdtparam ":" exdtval *("," exdtval) CRLF
+///
+/// exdtparam  = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" "VALUE" "=" ("DATE-TIME" / "DATE")) /
+///            ;
+///            (";" tzidparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+///
+/// exdtval    = date-time / date
+/// ;Value MUST match value type
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// EXDATE:19960402T010000Z,19960403T010000Z,19960404T010000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.5.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.5.1)
+pub struct ExceptionDateTimes;
+
+impl Property for ExceptionDateTimes {
+    const NAME: &'static str = "EXDATE";
+
+    type CompositeValueType = List<Any2<DateTime, Date>>;
+}
+
+impl EventCProperty for ExceptionDateTimes {}
+impl TodoCProperty for ExceptionDateTimes {}
+impl JournalCProperty for ExceptionDateTimes {}
+impl StandardCProperty for ExceptionDateTimes {}
+impl DaylightCProperty for ExceptionDateTimes {}
+
+impl AcceptsParam<parameters::TimeZoneIdentifier> for ExceptionDateTimes {}
+
+/// Recurrence Rule
+///
+/// Property Name:  RRULE
+///
+/// Purpose:  This property defines a rule or repeating pattern for recurring
+/// events, to-dos, journal entries, or time zone definitions.
+///
+/// Value Type:  RECUR
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in recurring "VEVENT",
+/// "VTODO", and "VJOURNAL" calendar components as well as in the "STANDARD"
+/// and "DAYLIGHT" sub-components of the "VTIMEZONE" calendar component, but
+/// it SHOULD NOT be specified more than once.  The recurrence set generated
+/// with multiple "RRULE" properties is undefined.
+///
+/// Description:  The recurrence rule, if specified, is used in computing the
+/// recurrence set.  The recurrence set is the complete set of recurrence
+/// instances for a calendar component.  The recurrence set is generated by
+/// considering the initial "DTSTART" property along with the "RRULE",
+/// "RDATE", and "EXDATE" properties contained within the recurring
+/// component.  The "DTSTART" property defines the first instance in the
+/// recurrence set.  The "DTSTART" property value SHOULD be synchronized with
+/// the recurrence rule, if specified.  The recurrence set generated with a
+/// "DTSTART" property value not synchronized with the recurrence rule is
+/// undefined.  The final recurrence set is generated by gathering all of the
+/// start DATE-TIME values generated by any of the specified "RRULE" and
+/// "RDATE" properties, and then excluding any start DATE-TIME values
+/// specified by "EXDATE" properties.  This implies that start DATE- TIME
+/// values specified by "EXDATE" properties take precedence over those
+/// specified by inclusion properties (i.e., "RDATE" and "RRULE").  Where
+/// duplicate instances are generated by the "RRULE" and "RDATE" properties,
+/// only one recurrence is considered.  Duplicate instances are ignored.
+///
+/// The "DTSTART" property specified within the iCalendar object defines the
+/// first instance of the recurrence.  In most cases, a "DTSTART" property of
+/// DATE-TIME value type used with a recurrence rule, should be specified as a
+/// date with local time and time zone reference to make sure all the
+/// recurrence instances start at the same local time regardless of time zone
+/// changes.
+///
+/// If the duration of the recurring component is specified with the "DTEND"
+/// or "DUE" property, then the same exact duration will apply to all the
+/// members of the generated recurrence set.  Else, if the duration of the
+/// recurring component is specified with the "DURATION" property, then the
+/// same nominal duration will apply to all the members of the generated
+/// recurrence set and the exact duration of each recurrence instance will
+/// depend on its specific start time.  For example, recurrence instances of a
+/// nominal duration of one day will have an exact duration of more or less
+/// than 24 hours on a day where a time zone shift occurs.  The duration of a
+/// specific recurrence may be modified in an exception component or simply by
+/// using an "RDATE" property of PERIOD value type.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// rrule      = "RRULE" rrulparam ":" recur CRLF
+///
+/// rrulparam  = *(";" other-param)
+/// ```
+///
+/// Example:  All examples assume the Eastern United States time zone.
+///
+/// Daily for 10 occurrences:
+///
+/// ```ics
+/// DTSTART;TZID=America/New_York:19970902T090000
+/// RRULE:FREQ=DAILY;COUNT=10
+/// ```
+///
+/// Yearly in June and July for 10 occurrences:
+///
+/// ```ics
+/// DTSTART;TZID=America/New_York:19970610T090000
+/// RRULE:FREQ=YEARLY;COUNT=10;BYMONTH=6,7
+/// ```
+///
+/// There are many more examples in the referenced section.
+///
+/// Reference: [RFC 5545
+/// 3.8.5.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.5.3)
+pub struct RecurrenceRule;
+
+impl Property for RecurrenceRule {
+    const NAME: &'static str = "RRULE";
+
+    type CompositeValueType = RecurrenceRuleValue;
+}
+
+impl EventCProperty for RecurrenceRule {}
+impl TodoCProperty for RecurrenceRule {}
+impl JournalCProperty for RecurrenceRule {}
+impl StandardCProperty for RecurrenceRule {}
+impl DaylightCProperty for RecurrenceRule {}
@@ -1,11 +1,563 @@
 //! [RFC 5545 3.8.4: Relationship Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4)
 
 use crate::structure::{
-    icalstream::components::{EventCProperty, FreeBusyCProperty, JournalCProperty, TodoCProperty},
-    value_types::Text,
-    Property,
+    composite_value_types::Any2,
+    icalstream::{
+        components::{
+            AlarmCProperty, EventCProperty, FreeBusyCProperty, ICalObjectProperty,
+            JournalCProperty, LocationCProperty, ParticipantCProperty, ResourceCProperty,
+            TodoCProperty,
+        },
+        parameters,
+    },
+    value_types::{CalAddress, Date, DateTime, Text, Uri},
+    AcceptsParam, Property,
 };
 
+/// Attendee
+///
+/// Property Name:  ATTENDEE
+///
+/// Purpose:  This property defines an "Attendee" within a calendar
+/// component.
+///
+/// Value Type:  CAL-ADDRESS
+///
+/// Property Parameters:  IANA, non-standard, language, calendar user type,
+/// group or list membership, participation role, participation status, RSVP
+/// expectation, delegatee, delegator, sent by, common name, or directory
+/// entry reference property parameters can be specified on this property.
+///
+/// Conformance:  This property MUST be specified in an iCalendar object that
+/// specifies a group-scheduled calendar entity.  This property MUST NOT be
+/// specified in an iCalendar object when publishing the calendar information
+/// (e.g., NOT in an iCalendar object that specifies the publication of a
+/// calendar user's busy time, event, to-do, or journal).  This property is
+/// not specified in an iCalendar object that specifies only a time zone
+/// definition or that defines calendar components that are not group-
+/// scheduled components, but are components only on a single user's
+/// calendar.
+///
+/// Description:  This property MUST only be specified within calendar
+/// components to specify participants, non-participants, and the chair of a
+/// group-scheduled calendar entity.  The property is specified within an
+/// "EMAIL" category of the "VALARM" calendar component to specify an email
+/// address that is to receive the email type of iCalendar alarm.
+///
+/// The property parameter "CN" is for the common or displayable name
+/// associated with the calendar address; "ROLE", for the intended role that
+/// the attendee will have in the calendar component; "PARTSTAT", for the
+/// status of the attendee's participation; "RSVP", for indicating whether
+/// the favor of a reply is requested; "CUTYPE", to indicate the type of
+/// calendar user; "MEMBER", to indicate the groups that the attendee belongs
+/// to; "DELEGATED-TO", to indicate the calendar users that the original
+/// request was delegated to; and "DELEGATED-FROM", to indicate whom the
+/// request was delegated from; "SENT-BY", to indicate whom is acting on
+/// behalf of the "ATTENDEE"; and "DIR", to indicate the URI that points to
+/// the directory information corresponding to the attendee.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// attendee   = "ATTENDEE" attparam ":" cal-address CRLF
+///
+/// attparam   = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" cutypeparam) / (";" memberparam) /
+///            (";" roleparam) / (";" partstatparam) /
+///            (";" rsvpparam) / (";" deltoparam) /
+///            (";" delfromparam) / (";" sentbyparam) /
+///            (";" cnparam) / (";" dirparam) /
+///            (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following are examples of this property's use for a to-do:
+///
+/// ```ics
+/// ATTENDEE;MEMBER="mailto:DEV-GROUP@example.com":
+///  mailto:joecool@example.com
+/// ATTENDEE;DELEGATED-FROM="mailto:immud@example.com":
+///  mailto:ildoit@example.com
+/// ```
+///
+/// The following is an example of this property used for specifying
+/// multiple attendees to an event:
+///
+/// ```ics
+/// ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=TENTATIVE;CN=Henry
+///  Cabot:mailto:hcabot@example.com
+/// ATTENDEE;ROLE=REQ-PARTICIPANT;DELEGATED-FROM="mailto:bob@
+///  example.com";PARTSTAT=ACCEPTED;CN=Jane Doe:mailto:jdoe@
+///  example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.4.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4.1)
+pub struct Attendee;
+
+impl Property for Attendee {
+    const NAME: &'static str = "ATTENDEE";
+
+    type CompositeValueType = CalAddress;
+}
+
+impl EventCProperty for Attendee {}
+impl TodoCProperty for Attendee {}
+impl JournalCProperty for Attendee {}
+impl FreeBusyCProperty for Attendee {}
+impl AlarmCProperty for Attendee {}
+
+impl AcceptsParam<parameters::CalendarUserType> for Attendee {}
+impl AcceptsParam<parameters::Membership> for Attendee {}
+impl AcceptsParam<parameters::ParticipationRole> for Attendee {}
+impl AcceptsParam<parameters::ParticipationStatus> for Attendee {}
+impl AcceptsParam<parameters::RsvpExpectation> for Attendee {}
+impl AcceptsParam<parameters::Delegatees> for Attendee {}
+impl AcceptsParam<parameters::Delegators> for Attendee {}
+impl AcceptsParam<parameters::SentBy> for Attendee {}
+impl AcceptsParam<parameters::CommonName> for Attendee {}
+impl AcceptsParam<parameters::DirectoryEntryReference> for Attendee {}
+impl AcceptsParam<parameters::Language> for Attendee {}
+impl AcceptsParam<parameters::Email> for Attendee {}
+
+/// Contact
+///
+/// Property Name:  CONTACT
+///
+/// Purpose:  This property is used to represent contact information or
+/// alternately a reference to contact information associated with the
+/// calendar component.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, alternate text representation,
+/// and language property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified in an "VEVENT", "VTODO",
+/// "VJOURNAL", or "VFREEBUSY" calendar component.
+///
+/// Description:  The property value consists of textual contact information.
+/// An alternative representation for the property value can also be
+/// specified that refers to a URI pointing to an alternate form, such as a
+/// vCard [RFC2426], for the contact information.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// contact    = "CONTACT" contparam ":" text CRLF
+///
+/// contparam  = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" altrepparam) / (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this property referencing
+/// textual contact information:
+///
+/// ```ics
+/// CONTACT:Jim Dolittle\, ABC Industries\, +1-919-555-1234
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.4.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4.2)
+///
+/// [RFC2426]: https://www.rfc-editor.org/rfc/rfc2426
+pub struct Contact;
+
+impl Property for Contact {
+    const NAME: &'static str = "CONTACT";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for Contact {}
+impl TodoCProperty for Contact {}
+impl JournalCProperty for Contact {}
+impl FreeBusyCProperty for Contact {}
+
+impl ParticipantCProperty for Contact {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Contact {}
+impl AcceptsParam<parameters::Language> for Contact {}
+
+/// Organizer
+///
+/// Property Name:  ORGANIZER
+///
+/// Purpose:  This property defines the organizer for a calendar component.
+///
+/// Value Type:  CAL-ADDRESS
+///
+/// Property Parameters:  IANA, non-standard, language, common name,
+/// directory entry reference, and sent-by property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MUST be specified in an iCalendar object that
+/// specifies a group-scheduled calendar entity.  This property MUST be
+/// specified in an iCalendar object that specifies the publication of a
+/// calendar user's busy time.  This property MUST NOT be specified in an
+/// iCalendar object that specifies only a time zone definition or that
+/// defines calendar components that are not group-scheduled components, but
+/// are components only on a single user's calendar.
+///
+/// Description:  This property is specified within the "VEVENT", "VTODO",
+/// and "VJOURNAL" calendar components to specify the organizer of a
+/// group-scheduled calendar entity.  The property is specified within the
+/// "VFREEBUSY" calendar component to specify the calendar user requesting
+/// the free or busy time.  When publishing a "VFREEBUSY" calendar component,
+/// the property is used to specify the calendar that the published busy
+/// time came from.
+///
+/// The property has the property parameters "CN", for specifying the common
+/// or display name associated with the "Organizer", "DIR", for specifying a
+/// pointer to the directory information associated with the "Organizer",
+/// "SENT-BY", for specifying another calendar user that is acting on behalf
+/// of the "Organizer".  The non-standard parameters may also be specified on
+/// this property.  If the "LANGUAGE" property parameter is specified, the
+/// identified language applies to the "CN" parameter value.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// organizer  = "ORGANIZER" orgparam ":"
+///              cal-address CRLF
+///
+/// orgparam   = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" cnparam) / (";" dirparam) / (";" sentbyparam) /
+///            (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// ORGANIZER;CN=John Smith:mailto:jsmith@example.com
+/// ```
+///
+/// The following is an example of this property with a pointer to the
+/// directory information associated with the organizer:
+///
+/// ```ics
+/// ORGANIZER;CN=JohnSmith;DIR="ldap://example.com:6666/o=DC%20Ass
+///  ociates,c=US???(cn=John%20Smith)":mailto:jsmith@example.com
+/// ```
+///
+/// The following is an example of this property used by another calendar
+/// user who is acting on behalf of the organizer, with responses intended to
+/// be sent back to the organizer, not the other calendar user:
+///
+/// ```ics
+/// ORGANIZER;SENT-BY="mailto:jane_doe@example.com":
+///  mailto:jsmith@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.4.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4.3)
+pub struct Organizer;
+
+impl Property for Organizer {
+    const NAME: &'static str = "ORGANIZER";
+
+    type CompositeValueType = CalAddress;
+}
+
+impl EventCProperty for Organizer {}
+impl TodoCProperty for Organizer {}
+impl JournalCProperty for Organizer {}
+impl FreeBusyCProperty for Organizer {}
+
+impl AcceptsParam<parameters::CommonName> for Organizer {}
+impl AcceptsParam<parameters::DirectoryEntryReference> for Organizer {}
+impl AcceptsParam<parameters::SentBy> for Organizer {}
+impl AcceptsParam<parameters::Language> for Organizer {}
+impl AcceptsParam<parameters::Email> for Organizer {}
+
+/// Recurrence ID
+///
+/// Property Name:  RECURRENCE-ID
+///
+/// Purpose:  This property is used in conjunction with the "UID" and
+/// "SEQUENCE" properties to identify a specific instance of a recurring
+/// "VEVENT", "VTODO", or "VJOURNAL" calendar component.  The property value
+/// is the original value of the "DTSTART" property of the recurrence
+/// instance.
+///
+/// Value Type:  The default value type is DATE-TIME.  The value type can be
+/// set to a DATE value type.  This property MUST have the same value type as
+/// the "DTSTART" property contained within the recurring component.
+/// Furthermore, this property MUST be specified as a date with local time if
+/// and only if the "DTSTART" property contained within the recurring
+/// component is specified as a date with local time.
+///
+/// Property Parameters:  IANA, non-standard, value data type, time zone
+/// identifier, and recurrence identifier range parameters can be specified
+/// on this property.
+///
+/// Conformance:  This property can be specified in an iCalendar object
+/// containing a recurring calendar component.
+///
+/// Description:  The full range of calendar components specified by a
+/// recurrence set is referenced by referring to just the "UID" property
+/// value corresponding to the calendar component.  The "RECURRENCE-ID"
+/// property allows the reference to an individual instance within the
+/// recurrence set.
+///
+/// If the value of the "DTSTART" property is a DATE type value, then the
+/// value MUST be the calendar date for the recurrence instance.
+///
+/// The DATE-TIME value is set to the time when the original recurrence
+/// instance would occur; meaning that if the intent is to change a Friday
+/// meeting to Thursday, the DATE-TIME is still set to the original Friday
+/// meeting.
+///
+/// The "RECURRENCE-ID" property is used in conjunction with the "UID" and
+/// "SEQUENCE" properties to identify a particular instance of a recurring
+/// event, to-do, or journal.  For a given pair of "UID" and "SEQUENCE"
+/// property values, the "RECURRENCE-ID" value for a recurrence instance is
+/// fixed.
+///
+/// The "RANGE" parameter is used to specify the effective range of
+/// recurrence instances from the instance specified by the "RECURRENCE-ID"
+/// property value.  The value for the range parameter can only be
+/// "THISANDFUTURE" to indicate a range defined by the given recurrence
+/// instance and all subsequent instances.  Subsequent instances are
+/// determined by their "RECURRENCE-ID" value and not their current scheduled
+/// start time.  Subsequent instances defined in separate components are not
+/// impacted by the given recurrence instance.  When the given recurrence
+/// instance is rescheduled, all subsequent instances are also rescheduled by
+/// the same time difference.  For instance, if the given recurrence instance
+/// is rescheduled to start 2 hours later, then all subsequent instances are
+/// also rescheduled 2 hours later.  Similarly, if the duration of the given
+/// recurrence instance is modified, then all subsequence instances are also
+/// modified to have this same duration.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// recurid    = "RECURRENCE-ID" ridparam ":" ridval CRLF
+///
+/// ridparam   = *(
+///            ;
+///            ; The following are OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" "VALUE" "=" ("DATE-TIME" / "DATE")) /
+///            (";" tzidparam) / (";" rangeparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+///
+/// ridval     = date-time / date
+/// ;Value MUST match value type
+/// ```
+///
+/// Example:  The following are examples of this property:
+///
+/// ```ics
+/// RECURRENCE-ID;VALUE=DATE:19960401
+///
+/// RECURRENCE-ID;RANGE=THISANDFUTURE:19960120T120000Z
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.4.4](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4.4)
+pub struct RecurrenceId;
+
+impl Property for RecurrenceId {
+    const NAME: &'static str = "RECURRENCE-ID";
+
+    type CompositeValueType = Any2<DateTime, Date>;
+}
+
+impl EventCProperty for RecurrenceId {}
+impl TodoCProperty for RecurrenceId {}
+impl JournalCProperty for RecurrenceId {}
+
+impl AcceptsParam<parameters::TimeZoneIdentifier> for RecurrenceId {}
+impl AcceptsParam<parameters::RecurrenceIdentifierRange> for RecurrenceId {}
+
+/// Related To
+///
+/// Property Name:  RELATED-TO
+///
+/// Purpose:  This property is used to represent a relationship or reference
+/// between one calendar component and another.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, and relationship type property
+/// parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified in the "VEVENT", "VTODO",
+/// and "VJOURNAL" calendar components.
+///
+/// Description:  The property value consists of the persistent, globally
+/// unique identifier of another calendar component.  This value would be
+/// represented in a calendar component by the "UID" property.
+///
+/// By default, the property value points to another calendar component that
+/// has a PARENT relationship to the referencing object.  The "RELTYPE"
+/// property parameter is used to either explicitly state the default PARENT
+/// relationship type to the referenced calendar component or to override
+/// the default PARENT relationship type and specify either a CHILD or
+/// SIBLING relationship.  The PARENT relationship indicates that the
+/// calendar component is a subordinate of the referenced calendar
+/// component.  The CHILD relationship indicates that the calendar component
+/// is a superior of the referenced calendar component.  The SIBLING
+/// relationship indicates that the calendar component is a peer of the
+/// referenced calendar component.
+///
+/// Changes to a calendar component referenced by this property can have an
+/// implicit impact on the related calendar component.  For example, if a
+/// group event changes its start or end date or time, then the related,
+/// dependent events will need to have their start and end dates changed in
+/// a corresponding way.  Similarly, if a PARENT calendar component is
+/// cancelled or deleted, then there is an implied impact to the related
+/// CHILD calendar components.  This property is intended only to provide
+/// information on the relationship of calendar components.  It is up to the
+/// target calendar system to maintain any property implications of this
+/// relationship.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// related    = "RELATED-TO" relparam ":" text CRLF
+///
+/// relparam   = *(
+///            ;
+///            ; The following is OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" reltypeparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// RELATED-TO:jsmith.part7.19960817T083000.xyzMail@example.com
+///
+/// RELATED-TO:19960401-080045-4000F192713-0052@example.com
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.4.5](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4.5)
+pub struct RelatedTo;
+
+impl Property for RelatedTo {
+    const NAME: &'static str = "RELATED-TO";
+
+    type CompositeValueType = Text;
+}
+
+impl EventCProperty for RelatedTo {}
+impl TodoCProperty for RelatedTo {}
+impl JournalCProperty for RelatedTo {}
+
+impl ParticipantCProperty for RelatedTo {}
+
+// Allowed in VALARM by RFC 9074 5, for RELTYPE=SNOOZE
+impl AlarmCProperty for RelatedTo {}
+
+impl AcceptsParam<parameters::RelationshipType> for RelatedTo {}
+
+/// Uniform Resource Locator
+///
+/// Property Name:  URL
+///
+/// Purpose:  This property defines a Uniform Resource Locator (URL)
+/// associated with the iCalendar object.
+///
+/// Value Type:  URI
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in the "VEVENT",
+/// "VTODO", "VJOURNAL", or "VFREEBUSY" calendar components.
+///
+/// Description:  This property may be used in a calendar component to convey
+/// a location where a more dynamic rendition of the calendar information
+/// associated with the calendar component can be found.  This memo does not
+/// attempt to standardize the form of the URI, nor the format of the
+/// resource pointed to by the property value.  If the URL property and
+/// Content-Location MIME header are both specified, they MUST point to the
+/// same resource.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// url        = "URL" urlparam ":" uri CRLF
+///
+/// urlparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```ics
+/// URL:http://example.com/pub/calendars/jsmith/mytime.ics
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.4.6](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.4.6)
+pub struct Url;
+
+impl Property for Url {
+    const NAME: &'static str = "URL";
+
+    type CompositeValueType = Uri;
+}
+
+impl EventCProperty for Url {}
+impl TodoCProperty for Url {}
+impl JournalCProperty for Url {}
+impl FreeBusyCProperty for Url {}
+
+// Allowed on the iCalendar object by RFC 7986 5.5
+impl ICalObjectProperty for Url {}
+
+impl ParticipantCProperty for Url {}
+
 /// Unique Identifier
 ///
 /// Property Name:  UID
@@ -87,3 +639,13 @@ impl EventCProperty for Uid {}
 impl TodoCProperty for Uid {}
 impl JournalCProperty for Uid {}
 impl FreeBusyCProperty for Uid {}
+
+// Allowed on the iCalendar object by RFC 7986 5.3
+impl ICalObjectProperty for Uid {}
+
+impl ParticipantCProperty for Uid {}
+impl LocationCProperty for Uid {}
+impl ResourceCProperty for Uid {}
+
+// Allowed in VALARM by RFC 9074 4
+impl AlarmCProperty for Uid {}
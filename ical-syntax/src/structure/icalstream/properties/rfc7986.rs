@@ -0,0 +1,446 @@
+//! [RFC 7986: New Properties for iCalendar](https://www.rfc-editor.org/rfc/rfc7986)
+//!
+//! RFC 7986 also allows some of the existing properties on the iCalendar
+//! object:
+//! [Description](super::descriptive::Description),
+//! [Uid](super::relationship::Uid), [Url](super::relationship::Url),
+//! [LastModified](super::change_management::LastModified) and
+//! [Categories](super::descriptive::Categories).
+
+use crate::structure::{
+    composite_value_types::Any2,
+    icalstream::{
+        components::{
+            EventCProperty, ICalObjectProperty, JournalCProperty, LocationCProperty,
+            ResourceCProperty, TodoCProperty,
+        },
+        parameters,
+    },
+    value_types::{Binary, Duration, Text, Uri},
+    AcceptsParam, Property,
+};
+
+/// Property Name:  NAME
+///
+/// Purpose:  This property specifies the name of the calendar.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, alternate text representation,
+/// and language property parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified multiple times in an
+/// iCalendar object.  However, each property MUST represent the name of the
+/// calendar in a different language.
+///
+/// Description:  This property is used to specify a name of the iCalendar
+/// object that can be used by calendar user agents when presenting the
+/// calendar data to a user.  Whilst a calendar only has a single name,
+/// multiple language variants can be specified by including this property
+/// multiple times with different "LANGUAGE" parameter values on each.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// name      = "NAME" nameparam ":" text CRLF
+///
+/// nameparam = *(
+///             ;
+///             ; The following are OPTIONAL,
+///             ; but MUST NOT occur more than once.
+///             ;
+///             (";" altrepparam) / (";" languageparam) /
+///             ;
+///             ; The following is OPTIONAL,
+///             ; and MAY occur more than once.
+///             ;
+///             (";" other-param)
+///             ;
+///             )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// NAME:Company Vacation Days
+/// ```
+///
+/// Reference: [RFC 7986
+/// 5.1](https://www.rfc-editor.org/rfc/rfc7986#section-5.1)
+pub struct Name;
+
+impl Property for Name {
+    const NAME: &'static str = "NAME";
+
+    type CompositeValueType = Text;
+}
+
+impl ICalObjectProperty for Name {}
+impl LocationCProperty for Name {}
+impl ResourceCProperty for Name {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Name {}
+impl AcceptsParam<parameters::Language> for Name {}
+
+/// Property Name:  REFRESH-INTERVAL
+///
+/// Purpose:  This property specifies a suggested minimum interval for
+/// polling for changes of the calendar data from the original source of that
+/// data.
+///
+/// Value Type:  DURATION -- no default
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in an iCalendar object,
+/// consisting of a positive duration of time.
+///
+/// Description:  This property specifies a positive duration that gives a
+/// suggested minimum polling interval for checking for updates to the
+/// calendar data.  The value of this property SHOULD be used by calendar user
+/// agents to limit the polling interval for calendar data updates to the
+/// minimum interval specified.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// refresh      = "REFRESH-INTERVAL" refreshparam
+///                ":" dur-value CRLF
+///                ; consisting of a positive duration of time.
+///
+/// refreshparam = *(
+///                ;
+///                ; The following is REQUIRED,
+///                ; but MUST NOT occur more than once.
+///                ;
+///                (";" "VALUE" "=" "DURATION") /
+///                ;
+///                ; The following is OPTIONAL,
+///                ; and MAY occur more than once.
+///                ;
+///                (";" other-param)
+///                ;
+///                )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// REFRESH-INTERVAL;VALUE=DURATION:P1W
+/// ```
+///
+/// Reference: [RFC 7986
+/// 5.7](https://www.rfc-editor.org/rfc/rfc7986#section-5.7)
+pub struct RefreshInterval;
+
+impl Property for RefreshInterval {
+    const NAME: &'static str = "REFRESH-INTERVAL";
+
+    type CompositeValueType = Duration;
+
+    const EXPLICIT_VALUE_TYPE: bool = true;
+}
+
+impl ICalObjectProperty for RefreshInterval {}
+
+/// Property Name:  SOURCE
+///
+/// Purpose:  This property identifies a URI where calendar data can be
+/// refreshed from.
+///
+/// Value Type:  URI -- no default
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in an iCalendar object.
+///
+/// Description:  This property identifies a location where a client can
+/// retrieve updated data for the calendar.  Clients SHOULD honor any
+/// specified "REFRESH-INTERVAL" value when periodically retrieving data.
+/// Note that this property differs from the "URL" property in that "URL" is
+/// meant to provide an alternative representation of the calendar data rather
+/// than the original location of the data.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// source = "SOURCE" sourceparam ":" uri CRLF
+///
+/// sourceparam = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// SOURCE;VALUE=URI:https://example.com/holidays.ics
+/// ```
+///
+/// Reference: [RFC 7986
+/// 5.8](https://www.rfc-editor.org/rfc/rfc7986#section-5.8)
+pub struct Source;
+
+impl Property for Source {
+    const NAME: &'static str = "SOURCE";
+
+    type CompositeValueType = Uri;
+
+    const EXPLICIT_VALUE_TYPE: bool = true;
+}
+
+impl ICalObjectProperty for Source {}
+
+/// Property Name:  COLOR
+///
+/// Purpose:  This property specifies a color used for displaying the
+/// calendar, event, todo, or journal data.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified once in an iCalendar object
+/// or in "VEVENT", "VTODO", or "VJOURNAL" calendar components.
+///
+/// Description:  This property specifies a color that clients MAY use when
+/// presenting the relevant data to a user.  Typically, this would appear as
+/// the "background" color of events or tasks.  The value is a
+/// case-insensitive color name taken from the CSS3 set of names, defined in
+/// Section 4.3 of [W3C.REC-css3-color-20110607].
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// color      = "COLOR" colorparam ":" text CRLF
+///              ; Value is CSS3 color name
+///
+/// colorparam = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// COLOR:turquoise
+/// ```
+///
+/// Reference: [RFC 7986
+/// 5.9](https://www.rfc-editor.org/rfc/rfc7986#section-5.9)
+pub struct Color;
+
+impl Property for Color {
+    const NAME: &'static str = "COLOR";
+
+    type CompositeValueType = Text;
+}
+
+impl ICalObjectProperty for Color {}
+impl EventCProperty for Color {}
+impl TodoCProperty for Color {}
+impl JournalCProperty for Color {}
+
+/// Property Name:  IMAGE
+///
+/// Purpose:  This property specifies an image associated with the calendar
+/// or a calendar component.
+///
+/// Value Type:  URI or BINARY -- no default.  The value MUST be data with a
+/// media type of "image" or refer to such data.
+///
+/// Property Parameters:  IANA, non-standard, display, inline encoding, and
+/// value data type property parameters can be specified on this property.
+/// The format type parameter can be specified on this property and is
+/// RECOMMENDED for inline binary encoded content information.
+///
+/// Conformance:  This property can be specified multiple times in an
+/// iCalendar object or in "VEVENT", "VTODO", or "VJOURNAL" calendar
+/// components.
+///
+/// Description:  This property specifies an image for an iCalendar object or
+/// a calendar component via a URI or directly with inline data that can be
+/// used by calendar user agents when presenting the calendar data to a user.
+/// Multiple properties MAY be used to specify alternative sets of images
+/// with, for example, varying media subtypes, resolutions, or sizes.  When
+/// multiple properties are present, calendar user agents SHOULD display only
+/// one of them, picking one that provides the most appropriate image quality,
+/// or display none.  The "DISPLAY" parameter is used to indicate the intended
+/// display mode for the image.  The "ALTREP" parameter, defined in [RFC5545],
+/// can be used to provide a "clickable" image where the URI in the parameter
+/// value can be "launched" by a click on the image in the calendar user
+/// agent.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// image      = "IMAGE" imageparam
+///              (
+///                (
+///                  ";" "VALUE" "=" "URI"
+///                  ":" uri
+///                ) /
+///                (
+///                  ";" "ENCODING" "=" "BASE64"
+///                  ";" "VALUE" "=" "BINARY"
+///                  ":" binary
+///                )
+///              )
+///              CRLF
+///
+/// imageparam = *(
+///              ;
+///              ; The following is OPTIONAL for a URI value,
+///              ; RECOMMENDED for a BINARY value,
+///              ; and MUST NOT occur more than once.
+///              ;
+///              (";" fmttypeparam) /
+///              ;
+///              ; The following are OPTIONAL,
+///              ; and MUST NOT occur more than once.
+///              ;
+///              (";" altrepparam) / (";" displayparam) /
+///              ;
+///              ; The following is OPTIONAL,
+///              ; and MAY occur more than once.
+///              ;
+///              (";" other-param)
+///              ;
+///              )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// IMAGE;VALUE=URI;DISPLAY=BADGE;FMTTYPE=image/png:http://exa
+///  mple.com/images/party.png
+/// ```
+///
+/// Reference: [RFC 7986
+/// 5.10](https://www.rfc-editor.org/rfc/rfc7986#section-5.10)
+///
+/// [RFC5545]: https://www.rfc-editor.org/rfc/rfc5545
+pub struct Image;
+
+impl Property for Image {
+    const NAME: &'static str = "IMAGE";
+
+    type CompositeValueType = Any2<Uri, Binary>;
+
+    const EXPLICIT_VALUE_TYPE: bool = true;
+}
+
+impl ICalObjectProperty for Image {}
+impl EventCProperty for Image {}
+impl TodoCProperty for Image {}
+impl JournalCProperty for Image {}
+
+impl AcceptsParam<parameters::FormatType> for Image {}
+impl AcceptsParam<parameters::AlternateTextRepresentation> for Image {}
+impl AcceptsParam<parameters::ImageDisplay> for Image {}
+impl AcceptsParam<parameters::InlineEncoding> for Image {}
+
+/// Property Name:  CONFERENCE
+///
+/// Purpose:  This property specifies information for accessing a
+/// conferencing system.
+///
+/// Value Type:  URI -- no default.
+///
+/// Property Parameters:  IANA, non-standard, feature, and label property
+/// parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified multiple times in a "VEVENT"
+/// or "VTODO" calendar component.
+///
+/// Description:  This property specifies information for accessing a
+/// conferencing system for attendees of a meeting or task.  This might be for
+/// a telephone-based conference number dial-in with access codes included
+/// (such as a tel: URI [RFC3966] or a sip: or sips: URI [RFC3261]), for a
+/// web-based video chat (such as an http: or https: URI [RFC7230]), or for an
+/// instant messaging group chat room (such as an xmpp: URI [RFC5122]).  If a
+/// specific URI for a conferencing system is not available, a data: URI
+/// [RFC2397] containing a text description can be used.
+///
+/// A conference system can be a bidirectional communication channel or a
+/// uni-directional "broadcast feed".
+///
+/// The "FEATURE" property parameter is used to describe the key capabilities
+/// of the conference system to allow a client to choose the ones that give
+/// the required level of interaction from a set of multiple properties.
+///
+/// The "LABEL" property parameter is used to convey additional details on the
+/// use of the URI.  For example, the URIs or access codes for the moderator
+/// and attendee of a teleconference system could be different, and the
+/// "LABEL" property parameter could be used to "tag" each "CONFERENCE"
+/// property to indicate which is which.
+///
+/// The "LANGUAGE" property parameter can be used to specify the language used
+/// for text values used with this property (as per Section 3.2.10 of
+/// [RFC5545]).
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// conference = "CONFERENCE" confparam ":" uri CRLF
+///
+/// confparam  = *(
+///              ;
+///              ; The following is REQUIRED,
+///              ; but MUST NOT occur more than once.
+///              ;
+///              (";" "VALUE" "=" "URI") /
+///              ;
+///              ; The following are OPTIONAL,
+///              ; and MUST NOT occur more than once.
+///              ;
+///              (";" featureparam) / (";" labelparam) /
+///              (";" languageparam ) /
+///              ;
+///              ; The following is OPTIONAL,
+///              ; and MAY occur more than once.
+///              ;
+///              (";" other-param)
+///              ;
+///              )
+/// ```
+///
+/// Example:  The following are examples of this property:
+///
+/// ```text
+/// CONFERENCE;VALUE=URI;FEATURE=PHONE,MODERATOR;
+///  LABEL=Moderator dial-in:tel:+1-412-555-0123,,,654321
+/// CONFERENCE;VALUE=URI;FEATURE=PHONE;
+///  LABEL=Attendee dial-in:tel:+1-412-555-0123,,,555123
+/// CONFERENCE;VALUE=URI;FEATURE=PHONE;
+///  LABEL=Attendee dial-in:tel:+1-888-555-0456,,,555123
+/// CONFERENCE;VALUE=URI;FEATURE=CHAT;
+///  LABEL=Chat room:xmpp:chat-123@conference.example.com
+/// CONFERENCE;VALUE=URI;FEATURE=AUDIO,VIDEO;
+///  LABEL=Attendee dial-in:https://chat.example.com/audio?id=123456
+/// ```
+///
+/// Reference: [RFC 7986
+/// 5.11](https://www.rfc-editor.org/rfc/rfc7986#section-5.11)
+///
+/// [RFC3966]: https://www.rfc-editor.org/rfc/rfc3966
+/// [RFC3261]: https://www.rfc-editor.org/rfc/rfc3261
+/// [RFC7230]: https://www.rfc-editor.org/rfc/rfc7230
+/// [RFC5122]: https://www.rfc-editor.org/rfc/rfc5122
+/// [RFC2397]: https://www.rfc-editor.org/rfc/rfc2397
+/// [RFC5545]: https://www.rfc-editor.org/rfc/rfc5545
+pub struct Conference;
+
+impl Property for Conference {
+    const NAME: &'static str = "CONFERENCE";
+
+    type CompositeValueType = Uri;
+
+    const EXPLICIT_VALUE_TYPE: bool = true;
+}
+
+impl EventCProperty for Conference {}
+impl TodoCProperty for Conference {}
+
+impl AcceptsParam<parameters::ConferenceFeature> for Conference {}
+impl AcceptsParam<parameters::Label> for Conference {}
+impl AcceptsParam<parameters::Language> for Conference {}
@@ -0,0 +1,390 @@
+//! [RFC 9073: Event Publishing Extensions to iCalendar](https://www.rfc-editor.org/rfc/rfc9073)
+//!
+//! Existing properties are also allowed in the
+//! [ParticipantC](crate::structure::icalstream::components::ParticipantC),
+//! [LocationC](crate::structure::icalstream::components::LocationC) and
+//! [ResourceC](crate::structure::icalstream::components::ResourceC)
+//! components. Those are marked in their own modules.
+
+use crate::structure::{
+    composite_value_types::{Any2, Any3, List},
+    icalstream::{
+        components::{
+            EventCProperty, JournalCProperty, LocationCProperty, ParticipantCProperty,
+            ResourceCProperty, TodoCProperty,
+        },
+        parameters,
+    },
+    value_types::{Binary, CalAddress, Text, Uri},
+    AcceptsParam, Property,
+};
+
+/// Property Name:  LOCATION-TYPE
+///
+/// Purpose:  This property specifies the type(s) of a location.
+///
+/// Value Type:  The value type for this property is TEXT.  The allowable
+/// values are defined by the Location Types Registry [RFC4589].  This
+/// registry is extensible and MAY be extended by the IANA.
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MAY be specified once in a "VLOCATION"
+/// component.
+///
+/// Description:  This property MAY be specified in "VLOCATION" components
+/// and provides a way to differentiate multiple locations.  For example, it
+/// allows event producers to provide location information for the venue
+/// and the parking.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// loctype      = "LOCATION-TYPE" loctypeparam ":"
+///                text *("," text)
+///                CRLF
+///
+/// loctypeparam = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// LOCATION-TYPE:parking
+/// ```
+///
+/// Reference: [RFC 9073
+/// 6.1](https://www.rfc-editor.org/rfc/rfc9073#section-6.1)
+///
+/// [RFC4589]: https://www.rfc-editor.org/rfc/rfc4589
+pub struct LocationType;
+
+impl Property for LocationType {
+    const NAME: &'static str = "LOCATION-TYPE";
+
+    type CompositeValueType = List<Text>;
+}
+
+impl LocationCProperty for LocationType {}
+
+/// Property Name:  PARTICIPANT-TYPE
+///
+/// Purpose:  To specify the type of participant.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MUST be specified once within a "PARTICIPANT"
+/// calendar component.
+///
+/// Description:  This property defines the type of participation in events
+/// or tasks.  Participants can be individuals or organizations, for example,
+/// a soccer team, the spectators, or the musicians.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// participanttype = "PARTICIPANT-TYPE" partvalueparam ":"
+///                    partvalue CRLF
+///
+/// partvalue       = ("ACTIVE" / "INACTIVE"
+///                 / "SPONSOR" / "CONTACT"
+///                 / "BOOKING-CONTACT"
+///                 / "EMERGENCY-CONTACT"
+///                 / "PUBLICITY-CONTACT"
+///                 / "PLANNER-CONTACT"
+///                 / "PERFORMER"
+///                 / "SPEAKER"
+///                 / iana-token)    ; Other IANA-registered
+///                                  ; values
+///
+/// partvalueparam  = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// PARTICIPANT-TYPE:SPEAKER
+/// ```
+///
+/// Reference: [RFC 9073
+/// 6.2](https://www.rfc-editor.org/rfc/rfc9073#section-6.2)
+pub struct ParticipantType;
+
+impl Property for ParticipantType {
+    const NAME: &'static str = "PARTICIPANT-TYPE";
+
+    type CompositeValueType = Text;
+}
+
+impl ParticipantCProperty for ParticipantType {}
+
+/// Property Name:  RESOURCE-TYPE
+///
+/// Purpose:  To specify the type of resource.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MAY be specified once within a "VRESOURCE"
+/// calendar component.
+///
+/// Description:  This property defines the type of resource.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// restype      = "RESOURCE-TYPE" restypeparam ":"
+///                restypevalue CRLF
+///
+/// restypevalue = ("ROOM"
+///                / "PROJECTOR"
+///                / "REMOTE-CONFERENCE-AUDIO"
+///                / "REMOTE-CONFERENCE-VIDEO"
+///                / iana-token)  ; Other IANA-registered
+///                               ; values
+///
+/// restypeparam = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// RESOURCE-TYPE:REMOTE-CONFERENCE-VIDEO
+/// ```
+///
+/// Reference: [RFC 9073
+/// 6.3](https://www.rfc-editor.org/rfc/rfc9073#section-6.3)
+pub struct ResourceType;
+
+impl Property for ResourceType {
+    const NAME: &'static str = "RESOURCE-TYPE";
+
+    type CompositeValueType = Text;
+}
+
+impl ResourceCProperty for ResourceType {}
+
+/// Property Name:  CALENDAR-ADDRESS
+///
+/// Purpose:  To specify the calendar address for a participant.
+///
+/// Value Type:  CAL-ADDRESS
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MAY be specified once within a "PARTICIPANT"
+/// calendar component.
+///
+/// Description:  This property provides a calendar user address for the
+/// participant.  If there is an "ATTENDEE" property with the same value,
+/// then the participant is schedulable.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// calendaraddress  = "CALENDAR-ADDRESS" caladdrparam ":"
+///                    cal-address CRLF
+///
+/// caladdrparam     = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// CALENDAR-ADDRESS:mailto:john@example.com
+/// ```
+///
+/// Reference: [RFC 9073
+/// 6.4](https://www.rfc-editor.org/rfc/rfc9073#section-6.4)
+pub struct CalendarAddress;
+
+impl Property for CalendarAddress {
+    const NAME: &'static str = "CALENDAR-ADDRESS";
+
+    type CompositeValueType = CalAddress;
+}
+
+impl ParticipantCProperty for CalendarAddress {}
+
+/// Property Name:  STYLED-DESCRIPTION
+///
+/// Purpose:  This property provides for one or more rich-text descriptions
+/// to replace that provided by the "DESCRIPTION" property.
+///
+/// Value Type:  There is no default value type for this property.  The value
+/// type can be set to URI or TEXT.  Other text-based value types can be used
+/// when defined in the future.  Clients MUST ignore any properties with
+/// value types they do not understand.
+///
+/// Property Parameters:  IANA, non-standard, id, alternate text
+/// representation, format type, value type, and language property
+/// parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified multiple times in an
+/// iCalendar object.
+///
+/// Description:  This property supports rich-text descriptions, for
+/// example, HTML.  Event publishers typically wish to provide more and
+/// better-formatted information about the event.
+///
+/// If the "DERIVED" parameter is set to "TRUE", then this property is
+/// derived from the plain text "DESCRIPTION" property, and clients SHOULD
+/// NOT update it.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// styleddescription = "STYLED-DESCRIPTION" styleddescparam ":"
+///                     styleddescval CRLF
+///
+/// styleddescparam   = *(
+///                   ;
+///                   ; The following is REQUIRED
+///                   ; but MUST NOT occur more than once.
+///                   ;
+///                   (";" "VALUE" "=" ("URI" / "TEXT")) /
+///                   ;
+///                   ; The following are OPTIONAL
+///                   ; but MUST NOT occur more than once.
+///                   ;
+///                   (";" altrepparam) / (";" languageparam) /
+///                   (";" fmttypeparam) / (";" derivedparam) /
+///                   ;
+///                   ; The following is OPTIONAL
+///                   ; and MAY occur more than once.
+///                   ;
+///                   (";" other-param)
+///                   ;
+///                   )
+///
+/// styleddescval     = ( text / uri / iana-token )
+///                   ; Value must match value type
+/// ```
+///
+/// Example:  The following is an example of this property.  It points to an
+/// HTML description.
+///
+/// ```text
+/// STYLED-DESCRIPTION;VALUE=URI:http://example.org/desc001.html
+/// ```
+///
+/// Reference: [RFC 9073
+/// 6.5](https://www.rfc-editor.org/rfc/rfc9073#section-6.5)
+pub struct StyledDescription;
+
+impl Property for StyledDescription {
+    const NAME: &'static str = "STYLED-DESCRIPTION";
+
+    type CompositeValueType = Any2<Text, Uri>;
+
+    const EXPLICIT_VALUE_TYPE: bool = true;
+}
+
+impl EventCProperty for StyledDescription {}
+impl TodoCProperty for StyledDescription {}
+impl JournalCProperty for StyledDescription {}
+impl ParticipantCProperty for StyledDescription {}
+
+impl AcceptsParam<parameters::AlternateTextRepresentation> for StyledDescription {}
+impl AcceptsParam<parameters::Language> for StyledDescription {}
+impl AcceptsParam<parameters::FormatType> for StyledDescription {}
+impl AcceptsParam<parameters::Derived> for StyledDescription {}
+impl AcceptsParam<parameters::Order> for StyledDescription {}
+
+/// Property Name:  STRUCTURED-DATA
+///
+/// Purpose:  This property specifies ancillary data associated with the
+/// calendar component.
+///
+/// Value Type:  TEXT, BINARY, or URI
+///
+/// Property Parameters:  IANA, non-standard, inline encoding, and value
+/// data type property parameters can be specified on this property.  The
+/// format type and schema parameters can be specified on this property and
+/// MUST be present if the value type is TEXT or BINARY.
+///
+/// Conformance:  This property can be specified multiple times in an
+/// iCalendar object.  Typically, it would be used in "VEVENT", "VTODO", or
+/// "VJOURNAL" calendar components.
+///
+/// Description:  This property is used to specify ancillary data in some
+/// structured format either directly (inline) as a "TEXT" or "BINARY" value
+/// or as a link via a "URI" value.
+///
+/// For a "BINARY" value, the "ENCODING" parameter MUST be set to "BASE64".
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// sdataprop   = "STRUCTURED-DATA" sdataparam
+///               (":" text) /
+///               (
+///                 ";" "ENCODING" "=" "BASE64"
+///                 ";" "VALUE" "=" "BINARY"
+///                 ":" binary
+///               ) /
+///               (
+///                 ";" "VALUE" "=" "URI"
+///                 ":" uri
+///               )
+///               CRLF
+///
+/// sdataparam  = *(
+///               ;
+///               ; The following is REQUIRED for a TEXT or BINARY
+///               ; value and MUST NOT occur more than once.
+///               ;
+///               ((";" fmttypeparam) / (";" schemaparam)) /
+///               ;
+///               ; The following is OPTIONAL
+///               ; and MAY occur more than once.
+///               ;
+///               (";" other-param)
+///               ;
+///               )
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// STRUCTURED-DATA;FMTTYPE=application/ld+json;
+///  SCHEMA="https://schema.org/SportsEvent";
+///  VALUE=TEXT:{\n
+///   "@context": "http://schema.org"\,\n
+///   "@type": "SportsEvent"\,\n
+///   "homeTeam": "Pittsburgh Pirates"\,\n
+///   "awayTeam": "San Francisco Giants"\n
+///  }\n
+/// STRUCTURED-DATA;VALUE=URI:http://example.com/event-details.json
+/// ```
+///
+/// Reference: [RFC 9073
+/// 6.6](https://www.rfc-editor.org/rfc/rfc9073#section-6.6)
+pub struct StructuredData;
+
+impl Property for StructuredData {
+    const NAME: &'static str = "STRUCTURED-DATA";
+
+    type CompositeValueType = Any3<Text, Binary, Uri>;
+
+    const EXPLICIT_VALUE_TYPE: bool = true;
+}
+
+impl EventCProperty for StructuredData {}
+impl TodoCProperty for StructuredData {}
+impl JournalCProperty for StructuredData {}
+impl ParticipantCProperty for StructuredData {}
+impl LocationCProperty for StructuredData {}
+impl ResourceCProperty for StructuredData {}
+
+impl AcceptsParam<parameters::FormatType> for StructuredData {}
+impl AcceptsParam<parameters::Schema> for StructuredData {}
+impl AcceptsParam<parameters::InlineEncoding> for StructuredData {}
+impl AcceptsParam<parameters::Order> for StructuredData {}
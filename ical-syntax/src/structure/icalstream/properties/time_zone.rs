@@ -1 +1,297 @@
 //! [RFC 5545 3.8.3: Time Zone Component Properties](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.3)
+
+use crate::structure::{
+    icalstream::{
+        components::{DaylightCProperty, StandardCProperty, TimeZoneCProperty},
+        parameters,
+    },
+    value_types::{Text, Uri, UtcOffset},
+    AcceptsParam, Property,
+};
+
+/// Time Zone Identifier
+///
+/// Property Name:  TZID
+///
+/// Purpose:  This property specifies the text value that uniquely identifies
+/// the "VTIMEZONE" calendar component in the scope of an iCalendar object.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MUST be specified in a "VTIMEZONE" calendar
+/// component.
+///
+/// Description:  This is the label by which a time zone calendar component
+/// is referenced by any iCalendar properties whose value type is either
+/// DATE-TIME or TIME and not intended to specify a UTC or a "floating" time.
+/// The presence of the SOLIDUS character as a prefix, indicates that this
+/// "TZID" represents an unique ID in a globally defined time zone registry
+/// (when such registry is defined).
+///
+/// Note: This document does not define a naming convention for time zone
+/// identifiers.  Implementers may want to use the naming conventions defined
+/// in existing time zone specifications such as the public-domain TZ
+/// database [TZDB].  The specification of globally unique time zone
+/// identifiers is not addressed by this document and is left for future
+/// study.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// tzid       = "TZID" tzidpropparam ":" [tzidprefix] text CRLF
+///
+/// tzidpropparam      = *(";" other-param)
+///
+/// ;tzidprefix        = "/"
+/// ; Defined previously. Just listed here for reader convenience.
+/// ```
+///
+/// Example:  The following are examples of non-globally unique time zone
+/// identifiers:
+///
+/// ```text
+/// TZID:America/New_York
+///
+/// TZID:America/Los_Angeles
+/// ```
+///
+/// The following is an example of a fictitious globally unique time zone
+/// identifier:
+///
+/// ```text
+/// TZID:/example.org/America/New_York
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.3.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.3.1)
+///
+/// [TZDB]: https://www.rfc-editor.org/rfc/rfc5545#ref-TZDB
+pub struct TimeZoneIdentifier;
+
+impl Property for TimeZoneIdentifier {
+    const NAME: &'static str = "TZID";
+
+    type CompositeValueType = Text;
+}
+
+impl TimeZoneCProperty for TimeZoneIdentifier {}
+
+/// Time Zone Name
+///
+/// Property Name:  TZNAME
+///
+/// Purpose:  This property specifies the customary designation for a time
+/// zone description.
+///
+/// Value Type:  TEXT
+///
+/// Property Parameters:  IANA, non-standard, and language property
+/// parameters can be specified on this property.
+///
+/// Conformance:  This property can be specified in "STANDARD" and "DAYLIGHT"
+/// sub-components.
+///
+/// Description:  This property specifies a customary name that can be used
+/// when displaying dates that occur during the observance defined by the
+/// time zone sub-component.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// tzname     = "TZNAME" tznparam ":" text CRLF
+///
+/// tznparam   = *(
+///            ;
+///            ; The following is OPTIONAL,
+///            ; but MUST NOT occur more than once.
+///            ;
+///            (";" languageparam) /
+///            ;
+///            ; The following is OPTIONAL,
+///            ; and MAY occur more than once.
+///            ;
+///            (";" other-param)
+///            ;
+///            )
+/// ```
+///
+/// Example:  The following are examples of this property:
+///
+/// ```text
+/// TZNAME:EST
+///
+/// TZNAME;LANGUAGE=fr-CA:HNE
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.3.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.3.2)
+pub struct TimeZoneName;
+
+impl Property for TimeZoneName {
+    const NAME: &'static str = "TZNAME";
+
+    type CompositeValueType = Text;
+}
+
+impl StandardCProperty for TimeZoneName {}
+impl DaylightCProperty for TimeZoneName {}
+
+impl AcceptsParam<parameters::Language> for TimeZoneName {}
+
+/// Time Zone Offset From
+///
+/// Property Name:  TZOFFSETFROM
+///
+/// Purpose:  This property specifies the offset that is in use prior to this
+/// time zone observance.
+///
+/// Value Type:  UTC-OFFSET
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MUST be specified in "STANDARD" and "DAYLIGHT"
+/// sub-components.
+///
+/// Description:  This property specifies the offset that is in use prior to
+/// this time observance.  It is used to calculate the absolute time at which
+/// the transition to a given observance takes place. This property MUST only
+/// be specified in a "VTIMEZONE" calendar component.  A "VTIMEZONE" calendar
+/// component MUST include this property.  The property value is a signed
+/// numeric indicating the number of hours and possibly minutes from UTC.
+/// Positive numbers represent time zones east of the prime meridian, or ahead
+/// of UTC.  Negative numbers represent time zones west of the prime meridian,
+/// or behind UTC.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// tzoffsetfrom       = "TZOFFSETFROM" frmparam ":" utc-offset
+///                      CRLF
+///
+/// frmparam   = *(";" other-param)
+/// ```
+///
+/// Example:  The following are examples of this property:
+///
+/// ```text
+/// TZOFFSETFROM:-0500
+///
+/// TZOFFSETFROM:+1345
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.3.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.3.3)
+pub struct TimeZoneOffsetFrom;
+
+impl Property for TimeZoneOffsetFrom {
+    const NAME: &'static str = "TZOFFSETFROM";
+
+    type CompositeValueType = UtcOffset;
+}
+
+impl StandardCProperty for TimeZoneOffsetFrom {}
+impl DaylightCProperty for TimeZoneOffsetFrom {}
+
+/// Time Zone Offset To
+///
+/// Property Name:  TZOFFSETTO
+///
+/// Purpose:  This property specifies the offset that is in use in this time
+/// zone observance.
+///
+/// Value Type:  UTC-OFFSET
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property MUST be specified in "STANDARD" and "DAYLIGHT"
+/// sub-components.
+///
+/// Description:  This property specifies the offset that is in use in this
+/// time zone observance.  It is used to calculate the absolute time for the
+/// new observance.  The property value is a signed numeric indicating the
+/// number of hours and possibly minutes from UTC.  Positive numbers represent
+/// time zones east of the prime meridian, or ahead of UTC.  Negative numbers
+/// represent time zones west of the prime meridian, or behind UTC.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// tzoffsetto = "TZOFFSETTO" toparam ":" utc-offset CRLF
+///
+/// toparam    = *(";" other-param)
+/// ```
+///
+/// Example:  The following are examples of this property:
+///
+/// ```text
+/// TZOFFSETTO:-0400
+///
+/// TZOFFSETTO:+1245
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.3.4](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.3.4)
+pub struct TimeZoneOffsetTo;
+
+impl Property for TimeZoneOffsetTo {
+    const NAME: &'static str = "TZOFFSETTO";
+
+    type CompositeValueType = UtcOffset;
+}
+
+impl StandardCProperty for TimeZoneOffsetTo {}
+impl DaylightCProperty for TimeZoneOffsetTo {}
+
+/// Time Zone URL
+///
+/// Property Name:  TZURL
+///
+/// Purpose:  This property provides a means for a "VTIMEZONE" component to
+/// point to a network location that can be used to retrieve an up-to- date
+/// version of itself.
+///
+/// Value Type:  URI
+///
+/// Property Parameters:  IANA and non-standard property parameters can be
+/// specified on this property.
+///
+/// Conformance:  This property can be specified in a "VTIMEZONE" calendar
+/// component.
+///
+/// Description:  This property provides a means for a "VTIMEZONE" component
+/// to point to a network location that can be used to retrieve an up-to-date
+/// version of itself.  This provides a hook to handle changes government
+/// bodies impose upon time zone definitions.  Retrieval of this resource
+/// results in an iCalendar object containing a single "VTIMEZONE" component
+/// and a "METHOD" property set to PUBLISH.
+///
+/// Format Definition:  This property is defined by the following notation:
+///
+/// ```abnf
+/// tzurl      = "TZURL" tzurlparam ":" uri CRLF
+///
+/// tzurlparam = *(";" other-param)
+/// ```
+///
+/// Example:  The following is an example of this property:
+///
+/// ```text
+/// TZURL:http://timezones.example.org/tz/America-Los_Angeles.ics
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.8.3.5](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.3.5)
+pub struct TimeZoneUrl;
+
+impl Property for TimeZoneUrl {
+    const NAME: &'static str = "TZURL";
+
+    type CompositeValueType = Uri;
+}
+
+impl TimeZoneCProperty for TimeZoneUrl {}
@@ -9,21 +9,36 @@ pub trait Component {
     const NAME: &'static str;
 }
 
-// TODO: Modelling of choice of value type, via VALUE= parameter
-// TODO: Modelling of comma-separated lists of values (eg RDATE)
-// TODO: Modelling of semicolon-separated parts for a value (eg VERSION, GEO)
+// Choice of value type, via the VALUE= parameter, comma-separated lists of
+// values (eg RDATE) and semicolon-separated parts for a value (eg VERSION,
+// GEO) are modelled in the composite_value_types module.
 // --> see https://www.rfc-editor.org/rfc/rfc5545#section-3.1.1
 
 pub trait Property {
     const NAME: &'static str;
 
     type CompositeValueType: CompositeValueType;
+
+    /// Whether the VALUE parameter must be written even for the default value
+    /// type, as for some of the properties of RFC 7986.
+    const EXPLICIT_VALUE_TYPE: bool = false;
 }
 
-pub trait CompositeValueType {}
+pub trait CompositeValueType {
+    /// The name of the value type that applies when the VALUE parameter is
+    /// absent
+    const DEFAULT_VALUE_TYPE: &'static str;
+}
 
 pub trait ValueType {
     const NAME: &'static str;
+
+    /// Whether the BACKSLASH character encoding for TEXT values applies.
+    ///
+    /// This is harmless for value types that never contain `\`, `;`, `,` or
+    /// newlines, but it must be disabled for value types such as RECUR where
+    /// these characters have syntactic significance.
+    const ESCAPED: bool = true;
 }
 
 pub trait Param {
@@ -32,6 +47,56 @@ pub trait Param {
     type ParamValueType: ParamValue;
 }
 
+/// Marker-trait for the parameters that are allowed on a property, other than
+/// the generic IANA and non-standard parameters. The VALUE parameter is
+/// implied by the value that is written, so it is not included.
+///
+/// See `dynamic_param` on the property writers for writing IANA and
+/// non-standard parameters.
+///
+/// ```
+/// # use ical_syntax::{structure::icalstream::{parameters, properties::descriptive::Summary}, write::{LineStream, PropertyWriter, value_types::LanguageTag}};
+/// # let mut buf = String::new();
+/// # let mut line_stream = LineStream::new(&mut buf);
+/// let mut p = PropertyWriter::<_, Summary>::new(&mut line_stream)?;
+/// p.param(parameters::Language, LanguageTag::new("en-US").unwrap())?;
+/// p.value("Company Holiday Party")?;
+/// p.end()?;
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+///
+/// RSVP is only allowed on ATTENDEE, so this does not compile:
+///
+/// ```compile_fail
+/// # use ical_syntax::{structure::icalstream::{parameters, properties::descriptive::Summary}, write::{LineStream, PropertyWriter}};
+/// # let mut buf = String::new();
+/// # let mut line_stream = LineStream::new(&mut buf);
+/// let mut p = PropertyWriter::<_, Summary>::new(&mut line_stream)?;
+/// p.param(parameters::RsvpExpectation, true)?;
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+pub trait AcceptsParam<PP: Param>: Property {}
+
+/// The type of a single parameter value. Enumerated parameters have their
+/// own item type, so they only accept the matching enumeration:
+///
+/// ```compile_fail
+/// # use ical_syntax::{structure::icalstream::{parameters, properties::relationship::Attendee}, write::{LineStream, PropertyWriter}};
+/// # let mut buf = String::new();
+/// # let mut line_stream = LineStream::new(&mut buf);
+/// let mut p = PropertyWriter::<_, Attendee>::new(&mut line_stream)?;
+/// p.param(parameters::ParticipationStatus, "ACCEPTED")?;
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+///
+/// ```compile_fail
+/// # use ical_syntax::{structure::icalstream::{parameters, properties::relationship::Attendee}, write::{value_types::ParticipationRole, LineStream, PropertyWriter}};
+/// # let mut buf = String::new();
+/// # let mut line_stream = LineStream::new(&mut buf);
+/// let mut p = PropertyWriter::<_, Attendee>::new(&mut line_stream)?;
+/// p.param(parameters::ParticipationStatus, ParticipationRole::Chair)?;
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
 pub trait ParamValueItem {
     const QUOTED: bool;
 }
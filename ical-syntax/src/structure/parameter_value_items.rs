@@ -21,3 +21,212 @@ pub struct Name;
 impl ParamValueItem for Name {
     const QUOTED: bool = false;
 }
+
+/// `paramtext` as defined by the following notation:
+///
+/// ```abnf
+/// paramtext     = *SAFE-CHAR
+///
+/// SAFE-CHAR     = WSP / %x21 / %x23-2B / %x2D-39 / %x3C-7E
+///               / NON-US-ASCII
+/// ; Any character except CONTROL, DQUOTE, ";", ":", ","
+/// ```
+///
+/// Reference: [RFC 5545 3.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.1)
+pub struct ParamText;
+
+impl ParamValueItem for ParamText {
+    const QUOTED: bool = false;
+}
+
+/// `quoted-string`, for free text that may contain characters that are not
+/// allowed in `paramtext`, such as the COMMA character in a common name:
+///
+/// ```abnf
+/// quoted-string = DQUOTE *QSAFE-CHAR DQUOTE
+///
+/// QSAFE-CHAR    = WSP / %x21 / %x23-7E / NON-US-ASCII
+/// ; Any character except CONTROL and DQUOTE
+/// ```
+///
+/// Reference: [RFC 5545 3.1](https://www.rfc-editor.org/rfc/rfc5545#section-3.1)
+pub struct QuotedString;
+
+impl ParamValueItem for QuotedString {
+    const QUOTED: bool = true;
+}
+
+/// A URI, which must be quoted when used as a parameter value:
+///
+/// ```abnf
+/// altrepparam = "ALTREP" "=" DQUOTE uri DQUOTE
+/// ```
+///
+/// Reference: [RFC 5545 3.3.13](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.13)
+pub struct Uri;
+
+impl ParamValueItem for Uri {
+    const QUOTED: bool = true;
+}
+
+/// A calendar user address, which must be quoted when used as a parameter
+/// value:
+///
+/// ```abnf
+/// sentbyparam = "SENT-BY" "=" DQUOTE cal-address DQUOTE
+/// ```
+///
+/// Reference: [RFC 5545 3.3.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.3)
+pub struct CalAddress;
+
+impl ParamValueItem for CalAddress {
+    const QUOTED: bool = true;
+}
+
+/// A media type, as defined by the following notation:
+///
+/// ```abnf
+/// fmttypeparam = "FMTTYPE" "=" type-name "/" subtype-name
+///                ; Where "type-name" and "subtype-name" are
+///                ; defined in Section 4.2 of [RFC4288].
+/// ```
+///
+/// Reference: [RFC 5545 3.2.8](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.8)
+pub struct MediaType;
+
+impl ParamValueItem for MediaType {
+    const QUOTED: bool = false;
+}
+
+/// A language tag, as defined in [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646):
+///
+/// ```abnf
+/// language = Language-Tag
+///            ; As defined in [RFC5646].
+/// ```
+///
+/// Reference: [RFC 5545 3.2.10](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.10)
+pub struct LanguageTag;
+
+impl ParamValueItem for LanguageTag {
+    const QUOTED: bool = false;
+}
+
+/// A BOOLEAN value, as used by parameters that are either on or off:
+///
+/// ```abnf
+/// rsvpparam = "RSVP" "=" ("TRUE" / "FALSE")
+/// ; Default is FALSE
+/// ```
+///
+/// Reference: [RFC 5545 3.2.17](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.17),
+/// [RFC 9073 5.3](https://www.rfc-editor.org/rfc/rfc9073#section-5.3)
+pub struct Boolean;
+
+impl ParamValueItem for Boolean {
+    const QUOTED: bool = false;
+}
+
+/// An INTEGER that is greater than or equal to 1:
+///
+/// ```abnf
+/// orderparam    = "ORDER" "=" integer ;
+///                 Must be greater than or equal to 1
+/// ```
+///
+/// Reference: [RFC 9073 5.1](https://www.rfc-editor.org/rfc/rfc9073#section-5.1)
+pub struct PositiveInteger;
+
+impl ParamValueItem for PositiveInteger {
+    const QUOTED: bool = false;
+}
+
+/// One of the free/busy time types enumerated for FBTYPE
+///
+/// Reference: [RFC 5545 3.2.9](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.9)
+pub struct FreeBusyType;
+
+impl ParamValueItem for FreeBusyType {
+    const QUOTED: bool = false;
+}
+
+/// One of the inline encodings enumerated for ENCODING
+///
+/// Reference: [RFC 5545 3.2.7](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.7)
+pub struct InlineEncoding;
+
+impl ParamValueItem for InlineEncoding {
+    const QUOTED: bool = false;
+}
+
+/// One of the alarm trigger relationships enumerated for RELATED
+///
+/// Reference: [RFC 5545 3.2.14](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.14)
+pub struct AlarmTriggerRelationship;
+
+impl ParamValueItem for AlarmTriggerRelationship {
+    const QUOTED: bool = false;
+}
+
+/// One of the calendar user types enumerated for CUTYPE
+///
+/// Reference: [RFC 5545 3.2.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.3)
+pub struct CalendarUserType;
+
+impl ParamValueItem for CalendarUserType {
+    const QUOTED: bool = false;
+}
+
+/// One of the participation statuses enumerated for PARTSTAT
+///
+/// Reference: [RFC 5545 3.2.12](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.12)
+pub struct ParticipationStatus;
+
+impl ParamValueItem for ParticipationStatus {
+    const QUOTED: bool = false;
+}
+
+/// One of the recurrence identifier ranges enumerated for RANGE
+///
+/// Reference: [RFC 5545 3.2.13](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.13)
+pub struct RecurrenceIdentifierRange;
+
+impl ParamValueItem for RecurrenceIdentifierRange {
+    const QUOTED: bool = false;
+}
+
+/// One of the relationship types enumerated for RELTYPE
+///
+/// Reference: [RFC 5545 3.2.15](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.15)
+pub struct RelationshipType;
+
+impl ParamValueItem for RelationshipType {
+    const QUOTED: bool = false;
+}
+
+/// One of the participation roles enumerated for ROLE
+///
+/// Reference: [RFC 5545 3.2.16](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.16)
+pub struct ParticipationRole;
+
+impl ParamValueItem for ParticipationRole {
+    const QUOTED: bool = false;
+}
+
+/// One of the display modes enumerated for DISPLAY
+///
+/// Reference: [RFC 7986 6.1](https://www.rfc-editor.org/rfc/rfc7986#section-6.1)
+pub struct ImageDisplay;
+
+impl ParamValueItem for ImageDisplay {
+    const QUOTED: bool = false;
+}
+
+/// One of the conference features enumerated for FEATURE
+///
+/// Reference: [RFC 7986 6.3](https://www.rfc-editor.org/rfc/rfc7986#section-6.3)
+pub struct ConferenceFeature;
+
+impl ParamValueItem for ConferenceFeature {
+    const QUOTED: bool = false;
+}
@@ -58,6 +58,12 @@ impl ValueType for Binary {
     const NAME: &'static str = "BINARY";
 }
 
+// ATTACH
+impl IsA<Any2<Uri, Binary>> for Binary {}
+
+// STRUCTURED-DATA
+impl IsA<Any3<Text, Binary, Uri>> for Binary {}
+
 /// Boolean
 ///
 /// Value Name:  BOOLEAN
@@ -123,6 +129,9 @@ pub struct CalAddress;
 
 impl ValueType for CalAddress {
     const NAME: &'static str = "CAL-ADDRESS";
+
+    // A CAL-ADDRESS is a URI, so it is not escaped either
+    const ESCAPED: bool = false;
 }
 
 /// Date
@@ -322,6 +331,8 @@ impl ValueType for DateTimeUtc {
     const NAME: &'static str = "DATE-TIME";
 }
 
+impl IsA<Any2<Duration, DateTimeUtc>> for DateTimeUtc {}
+
 /// Duration
 ///
 /// Value Name:  DURATION
@@ -389,6 +400,8 @@ impl ValueType for Duration {
     const NAME: &'static str = "DURATION";
 }
 
+impl IsA<Any2<Duration, DateTimeUtc>> for Duration {}
+
 /// Float
 ///
 /// Value Name:  FLOAT
@@ -527,6 +540,18 @@ impl ValueType for PeriodOfTime {
 
 impl IsA<Any3<DateTime, Date, PeriodOfTime>> for PeriodOfTime {}
 
+/// Period of Time in UTC format
+///
+/// This is a special case of the [PeriodOfTime] value type, where the start
+/// and end MUST be given in the "date with UTC time" form of [DateTime].
+///
+/// This type is appropriate for the FREEBUSY property.
+pub struct PeriodOfTimeUtc;
+
+impl ValueType for PeriodOfTimeUtc {
+    const NAME: &'static str = "PERIOD";
+}
+
 /// Recurrence Rule
 ///
 /// Value Name:  RECUR
@@ -849,14 +874,47 @@ impl IsA<Any3<DateTime, Date, PeriodOfTime>> for PeriodOfTime {}
 ///
 /// There are other examples specified in Section 3.8.5.3.
 ///
+/// Non-Gregorian recurrence rules are supported by the extension of RFC
+/// 7529, which adds the RSCALE and SKIP rule parts and leap months in
+/// BYMONTH:
+///
+/// ```abnf
+/// recur-rule-part =/ ("RSCALE" "=" rscale)
+///                  / ("SKIP" "=" skip)
+///
+/// rscale          = (iana-token  ; A CLDR-registered calendar system
+///                                ; name.
+///                  / x-name)     ; A non-standard, experimental
+///                                ; calendar system name.
+///                                ; Names are case insensitive,
+///                                ; but uppercase values are preferred.
+///
+/// skip            = ("OMIT" / "BACKWARD" / "FORWARD")
+///                 ; Optional, with default value "OMIT", and
+///                 ; MUST NOT be present unless "RSCALE" is present.
+///
+/// monthnum        = 1*2DIGIT ["L"]
+///                 ; Existing rule modified to include leap months
+///                 ; with the "L" suffix. The leap month suffix
+///                 ; MUST NOT be used unless "RSCALE" is present.
+/// ```
+///
+/// The SKIP rule part determines what happens to an instance that falls on
+/// a date that does not exist in the calendar system, such as February 30,
+/// or a leap month in a year without one. "OMIT" leaves out the instance,
+/// while "BACKWARD" and "FORWARD" move it to the previous or next valid day
+/// or month.
+///
 /// Reference: [RFC 5545
-/// 3.3.10](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.10)
+/// 3.3.10](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.10), [RFC 7529
+/// 4.1](https://www.rfc-editor.org/rfc/rfc7529#section-4.1)
 ///
 /// [ISO.8601.2004]: https://www.rfc-editor.org/rfc/rfc5545#ref-ISO.8601.2004
 pub struct RecurrenceRule;
 
 impl ValueType for RecurrenceRule {
     const NAME: &'static str = "RECUR";
+    const ESCAPED: bool = false;
 }
 
 /// Text
@@ -926,6 +984,20 @@ impl ValueType for Text {
     const NAME: &'static str = "TEXT";
 }
 
+// VERSION
+impl IsA<Any2<Text, (Text, Text)>> for Text {}
+impl IsA<Any2<Text, (Text, Text)>> for (Text, Text) {}
+
+// REQUEST-STATUS
+impl IsA<Any2<(Text, Text), (Text, Text, Text)>> for (Text, Text) {}
+impl IsA<Any2<(Text, Text), (Text, Text, Text)>> for (Text, Text, Text) {}
+
+// STYLED-DESCRIPTION
+impl IsA<Any2<Text, Uri>> for Text {}
+
+// STRUCTURED-DATA
+impl IsA<Any3<Text, Binary, Uri>> for Text {}
+
 /// Time
 ///
 /// Value Name:  TIME
@@ -1075,8 +1147,20 @@ pub struct Uri;
 
 impl ValueType for Uri {
     const NAME: &'static str = "URI";
+
+    // URIs are written as-is, without the TEXT escaping
+    const ESCAPED: bool = false;
 }
 
+// ATTACH
+impl IsA<Any2<Uri, Binary>> for Uri {}
+
+// STYLED-DESCRIPTION
+impl IsA<Any2<Text, Uri>> for Uri {}
+
+// STRUCTURED-DATA
+impl IsA<Any3<Text, Binary, Uri>> for Uri {}
+
 /// UTC Offset
 ///
 /// Value Name:  UTC-OFFSET
@@ -1,12 +1,13 @@
 use std::fmt::Write;
 
 use crate::structure::{
-    composite_value_types::{Any2, Any3, List, ValueTypeChoice},
+    composite_value_types::{Any2, Any3, Any4, Any5, Any6, Any7, Any8, List, ValueTypeChoice},
     CompositeValueType, ValueType,
 };
 
 use super::{
-    value_types::{AsValueType, AsValueTypeChoice},
+    text_writer::TextWriter,
+    value_types::{AsValueType, AsValueTypeChoice, ToValueType},
     PropertyValueWriter,
 };
 
@@ -14,15 +15,40 @@ pub trait AsCompositeValueType<To: CompositeValueType> {
     fn write_into<W: Write>(self, prop_value_writer: PropertyValueWriter<W>) -> std::fmt::Result;
 }
 
+/// Write a single value, with escaping if the value type calls for it.
+fn write_value<V: ValueType, W: Write>(value: &impl AsValueType<V>, w: &mut W) -> std::fmt::Result {
+    if V::ESCAPED {
+        value.fmt(&mut TextWriter::new(w))
+    } else {
+        value.fmt(w)
+    }
+}
+
+/// Record the time zone referenced by a value, if any.
+fn reference_time_zone<V: ValueType, W: Write>(
+    value: &impl AsValueType<V>,
+    prop_value_writer: &mut PropertyValueWriter<W>,
+) {
+    if let Some((tzid, timestamp)) = value.time_zone_reference() {
+        prop_value_writer.reference_time_zone(tzid, timestamp);
+    }
+}
+
 // All the value types can be used directly as a CompositeValueType:
 impl<T: AsValueType<V>, V: ValueType> AsCompositeValueType<V> for T {
     fn write_into<W: Write>(
         self,
         mut prop_value_writer: PropertyValueWriter<W>,
     ) -> std::fmt::Result {
+        if prop_value_writer.is_value_type_explicit() {
+            prop_value_writer.param(crate::structure::icalstream::parameters::Value, V::NAME)?;
+        }
+
+        reference_time_zone(&self, &mut prop_value_writer);
+        self.write_params(&mut prop_value_writer)?;
+
         let mut tw = prop_value_writer.value_tuple_writer()?;
-        let mut w = tw.next_value_writer()?;
-        self.fmt(&mut w)
+        write_value(&self, tw.next_raw_value_writer()?)
     }
 }
 
@@ -30,83 +56,87 @@ impl<T: AsValueType<V>, V: ValueType> AsValueTypeChoice<V> for T {
     type Type = V;
 }
 
-// Tuples of value types can be used as CompositeValueType:
+// Tuples of value types are value types in their own right. All the parts
+// have the same value type, and each part is escaped according to it:
 macro_rules! tuple_value_type {
-    ($( $t:ident ),+ ; $( $v:ident ),+ ) => {
-        impl<$( $t: AsValueType<$v> ),+, $( $v: ValueType ),+> AsCompositeValueType<($( $v, )+)> for ($( $t, )+) {
+    ($t0:ident $(, $t:ident )* ; $( $v:ident ),+) => {
+        impl<V: ValueType, $t0: AsValueType<V>, $( $t: AsValueType<V>, )*>
+            AsValueType<($( $v, )+)> for ($t0, $( $t, )*)
+        {
             #[allow(non_snake_case)]
-            fn write_into<W: Write>(self, mut prop_value_writer: PropertyValueWriter<W>) -> std::fmt::Result {
-                let mut tw = prop_value_writer.value_tuple_writer()?;
-
-                let ($( $v, )+) = self;
-                $( $v.fmt(&mut tw.next_value_writer()?)?; )+
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                let ($t0, $( $t, )*) = self;
+                write_value::<V, _>($t0, w)?;
+                $(
+                    w.write_char(';')?;
+                    write_value::<V, _>($t, w)?;
+                )*
 
                 Ok(())
             }
         }
 
-    };
-}
-
-tuple_value_type!(T0 ; V0);
-tuple_value_type!(T0, T1 ; V0, V1);
-tuple_value_type!(T0, T1, T2 ; V0, V1, V2);
-tuple_value_type!(T0, T1, T2, T3 ; V0, V1, V2, V3);
-
-// Choice of value types
-impl<RustType, T0, T1> AsCompositeValueType<Any2<T0, T1>> for RustType
-where
-    T0: ValueType,
-    T1: ValueType,
-    RustType: AsValueTypeChoice<Any2<T0, T1>>
-        + AsValueType<<RustType as AsValueTypeChoice<Any2<T0, T1>>>::Type>,
-{
-    fn write_into<W: Write>(
-        self,
-        mut prop_value_writer: PropertyValueWriter<W>,
-    ) -> std::fmt::Result {
-        if <RustType as AsValueTypeChoice<Any2<T0, T1>>>::Type::NAME
-            != <Any2<T0, T1> as ValueTypeChoice>::DefaultType::NAME
+        impl<V, $t0: ToValueType<ValueType = V>, $( $t: ToValueType<ValueType = V>, )*> ToValueType
+            for ($t0, $( $t, )*)
         {
-            prop_value_writer.param(
-                crate::structure::icalstream::parameters::Value,
-                <RustType as AsValueTypeChoice<Any2<T0, T1>>>::Type::NAME,
-            )?;
+            type ValueType = ($( $v, )+);
         }
-
-        let mut tw = prop_value_writer.value_tuple_writer()?;
-        let mut w = tw.next_value_writer()?;
-        self.fmt(&mut w)
-    }
+    };
 }
 
-impl<RustType, T0, T1, T2> AsCompositeValueType<Any3<T0, T1, T2>> for RustType
-where
-    T0: ValueType,
-    T1: ValueType,
-    T2: ValueType,
-    RustType: AsValueTypeChoice<Any3<T0, T1, T2>>
-        + AsValueType<<RustType as AsValueTypeChoice<Any3<T0, T1, T2>>>::Type>,
-{
-    fn write_into<W: Write>(
-        self,
-        mut prop_value_writer: PropertyValueWriter<W>,
-    ) -> std::fmt::Result {
-        if <RustType as AsValueTypeChoice<Any3<T0, T1, T2>>>::Type::NAME
-            != <Any2<T0, T1> as ValueTypeChoice>::DefaultType::NAME
+tuple_value_type!(T0 ; V);
+tuple_value_type!(T0, T1 ; V, V);
+tuple_value_type!(T0, T1, T2 ; V, V, V);
+tuple_value_type!(T0, T1, T2, T3 ; V, V, V, V);
+
+// Choice of value types. The VALUE parameter is set when the chosen type is
+// not the default type of the choice:
+macro_rules! any_value_type {
+    ($name:ident, $( $t:ident ),+) => {
+        impl<RustType, $( $t ),+> AsCompositeValueType<$name<$( $t ),+>> for RustType
+        where
+            $( $t: ValueType, )+
+            RustType: AsValueTypeChoice<$name<$( $t ),+>>
+                + AsValueType<<RustType as AsValueTypeChoice<$name<$( $t ),+>>>::Type>,
         {
-            prop_value_writer.param(
-                crate::structure::icalstream::parameters::Value,
-                <RustType as AsValueTypeChoice<Any3<T0, T1, T2>>>::Type::NAME,
-            )?;
-        }
+            fn write_into<W: Write>(
+                self,
+                mut prop_value_writer: PropertyValueWriter<W>,
+            ) -> std::fmt::Result {
+                type Chosen<RustType, $( $t ),+> =
+                    <RustType as AsValueTypeChoice<$name<$( $t ),+>>>::Type;
+
+                if prop_value_writer.is_value_type_explicit()
+                    || Chosen::<RustType, $( $t ),+>::NAME
+                        != <$name<$( $t ),+> as ValueTypeChoice>::DefaultType::NAME
+                {
+                    prop_value_writer.param(
+                        crate::structure::icalstream::parameters::Value,
+                        Chosen::<RustType, $( $t ),+>::NAME,
+                    )?;
+                }
+
+                reference_time_zone(&self, &mut prop_value_writer);
+                AsValueType::<Chosen<RustType, $( $t ),+>>::write_params(
+                    &self,
+                    &mut prop_value_writer,
+                )?;
 
-        let mut tw = prop_value_writer.value_tuple_writer()?;
-        let mut w = tw.next_value_writer()?;
-        self.fmt(&mut w)
-    }
+                let mut tw = prop_value_writer.value_tuple_writer()?;
+                write_value(&self, tw.next_raw_value_writer()?)
+            }
+        }
+    };
 }
 
+any_value_type!(Any2, T0, T1);
+any_value_type!(Any3, T0, T1, T2);
+any_value_type!(Any4, T0, T1, T2, T3);
+any_value_type!(Any5, T0, T1, T2, T3, T4);
+any_value_type!(Any6, T0, T1, T2, T3, T4, T5);
+any_value_type!(Any7, T0, T1, T2, T3, T4, T5, T6);
+any_value_type!(Any8, T0, T1, T2, T3, T4, T5, T6, T7);
+
 // Lists of value types:
 impl<VT, RustType, I> AsCompositeValueType<List<VT>> for I
 where
@@ -118,17 +148,51 @@ where
         self,
         mut prop_value_writer: PropertyValueWriter<W>,
     ) -> std::fmt::Result {
-        if <RustType as AsValueTypeChoice<VT>>::Type::NAME != VT::DefaultType::NAME {
+        // All the values in the list share the same parameters, which is
+        // checked before anything is written
+        let items: Vec<RustType> = self.into_iter().collect();
+        if let Some((first, rest)) = items.split_first() {
+            if rest.iter().any(|item| !item.has_same_params(first)) {
+                return Err(std::fmt::Error);
+            }
+        }
+
+        if prop_value_writer.is_value_type_explicit()
+            || <RustType as AsValueTypeChoice<VT>>::Type::NAME != VT::DefaultType::NAME
+        {
             prop_value_writer.param(
                 crate::structure::icalstream::parameters::Value,
                 <RustType as AsValueTypeChoice<VT>>::Type::NAME,
             )?;
         }
 
+        let mut items = items.into_iter();
+        let Some(first) = items.next() else {
+            prop_value_writer.value_list_writer()?;
+            return Ok(());
+        };
+
+        first.write_params(&mut prop_value_writer)?;
+
         let mut lw = prop_value_writer.value_list_writer()?;
+        write_value(&first, lw.next_raw_value_writer()?)?;
+
+        // ... and hence the same time zone, if any
+        let mut time_range = first.time_zone_reference().map(|(_, t)| (t, t));
+
+        for item in items {
+            write_value(&item, lw.next_raw_value_writer()?)?;
+
+            if let (Some((min, max)), Some((_, t))) = (&mut time_range, item.time_zone_reference())
+            {
+                *min = t.min(*min);
+                *max = t.max(*max);
+            }
+        }
 
-        for item in self {
-            item.fmt(&mut lw.next_value_writer()?)?;
+        if let (Some((tzid, _)), Some((min, max))) = (first.time_zone_reference(), time_range) {
+            prop_value_writer.reference_time_zone(tzid, min);
+            prop_value_writer.reference_time_zone(tzid, max);
         }
 
         Ok(())
@@ -39,8 +39,9 @@ pub trait ParamValueWriter<W: Write> {
 
     fn param_value_quoted(&mut self, fmt: impl Display) -> std::fmt::Result {
         let mut pv = self.param_value_quoted_writer()?;
-        write!(&mut pv, "{}", fmt)?;
-        pv.close()
+        // Close the quoted string even when the value is invalid
+        let result = write!(&mut pv, "{}", fmt);
+        pv.close().and(result)
     }
 }
 
@@ -134,6 +135,13 @@ impl<W: Write> ContentLine<W> {
         assert_eq!(self.state, State::Value);
         self.inner.eol()
     }
+
+    /// Give up on a content line that could not be completed, without ending
+    /// it. Only for lines written to a scratch buffer.
+    #[cfg(any(feature = "jcal", test))]
+    pub(crate) fn discard(self) {
+        self.inner.discard()
+    }
 }
 
 impl<W: Write> ParamValueWriter<W> for ContentLine<W> {
@@ -176,6 +184,17 @@ impl<'a, W: Write> ValueTupleWriter<'a, W> {
 
         Ok(TextWriter::new(&mut self.inner))
     }
+
+    /// Like `next_value_writer`, but without the escaping for TEXT values.
+    pub fn next_raw_value_writer(&mut self) -> Result<&mut FoldingWriter<W>, std::fmt::Error> {
+        if self.first_value {
+            self.first_value = false;
+        } else {
+            self.inner.write_char(';')?;
+        }
+
+        Ok(self.inner)
+    }
 }
 
 pub struct ValueListWriter<'a, W> {
@@ -202,6 +221,17 @@ impl<'a, W: Write> ValueListWriter<'a, W> {
 
         Ok(TextWriter::new(&mut self.inner))
     }
+
+    /// Like `next_value_writer`, but without the escaping for TEXT values.
+    pub fn next_raw_value_writer(&mut self) -> Result<&mut FoldingWriter<W>, std::fmt::Error> {
+        if self.first_value {
+            self.first_value = false;
+        } else {
+            self.inner.write_char(',')?;
+        }
+
+        Ok(self.inner)
+    }
 }
 
 #[cfg(test)]
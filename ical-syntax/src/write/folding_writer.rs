@@ -28,6 +28,13 @@ impl<W: Write> FoldingWriter<W> {
         self.passed_eol = true;
         self.inner.write_str(CRLF)
     }
+
+    /// Drop the writer without ending the line. Only for lines written to a
+    /// scratch buffer.
+    #[cfg(any(feature = "jcal", test))]
+    pub(crate) fn discard(mut self) {
+        self.passed_eol = true;
+    }
 }
 
 impl<W> Drop for FoldingWriter<W> {
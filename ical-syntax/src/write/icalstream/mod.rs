@@ -1,34 +1,920 @@
 pub mod typed_writers;
+pub mod vtimezone;
 
 #[cfg(test)]
 mod test {
+    use crate::{
+        structure::value_types::Date,
+        write::value_types::{AsValueType, ToValueType},
+    };
+
+    /// A DATE for DTSTART, which is required in VEVENT, in the tests that do
+    /// not depend on a date and time crate
+    struct Day;
+
+    impl AsValueType<Date> for Day {
+        fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+            write!(w, "20240626")
+        }
+    }
+
+    impl ToValueType for Day {
+        type ValueType = Date;
+    }
+
+    #[test]
+    fn icalstream() -> std::fmt::Result {
+        use super::typed_writers::ICalStreamWriter;
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let ico = ics.icalendar_object("-//test//")?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//test//\r\nEND:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn calendar_properties() -> std::fmt::Result {
+        use crate::write::{
+            value_types::{CalendarScale, Method},
+            DynamicName,
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        ico.calendar_scale(CalendarScale::Gregorian)?;
+        assert!(ico.calendar_scale(CalendarScale::Gregorian).is_err());
+        let mut ev = ico.event()?;
+        assert!(ev.missing_dtstart());
+        ev.dtstart(Day)?;
+        assert!(!ev.missing_dtstart());
+        ev.end()?;
+
+        // The properties must come before the components
+        assert!(ico.calendar_scale(CalendarScale::Gregorian).is_err());
+        assert!(ico.method(Method::Publish).is_err());
+        assert!(ico.name("Late").is_err());
+        ico.end()?;
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let chinese = DynamicName::new("X-CHINESE").unwrap();
+        ico.calendar_scale(CalendarScale::Other(chinese))?;
+        ico.method(Method::DeclineCounter)?;
+        assert!(ico.method(Method::Reply).is_err());
+        assert!(ico.has_method());
+        let ev = ico.event()?;
+        assert!(!ev.missing_dtstart());
+        ev.end()?;
+        ico.end()?;
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        ico.method(Method::Other(DynamicName::new("X-POLL").unwrap()))?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n\
+            BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            CALSCALE:X-CHINESE\r\n\
+            METHOD:DECLINECOUNTER\r\n\
+            BEGIN:VEVENT\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n\
+            BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            METHOD:X-POLL\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parameters() -> std::fmt::Result {
+        use crate::{
+            structure::icalstream::{parameters, properties::descriptive},
+            write::value_types::{LanguageTag, MediaType, Uri},
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+
+        let mut p = ev.property(descriptive::Description)?;
+        let altrep = Uri::new("CID:part3.msg.970415T083000@example.com").unwrap();
+        p.param(parameters::AlternateTextRepresentation, &altrep)?;
+        p.param(parameters::Language, LanguageTag::new("en-US").unwrap())?;
+        p.value("Project XYZ Review Meeting")?;
+        p.end()?;
+
+        let mut p = ev.property(descriptive::Attach)?;
+        p.param(
+            parameters::FormatType,
+            MediaType::new("application/msword").unwrap(),
+        )?;
+        p.value(Uri::new("ftp://example.com/pub/docs/agenda.doc").unwrap())?;
+        p.end()?;
+
+        ev.end()?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            DESCRIPTION;ALTREP=\"CID:part3.msg.970415T083000@example.com\";LANGUAGE=en-US\r\n \
+            :Project XYZ Review Meeting\r\n\
+            ATTACH;FMTTYPE=application/msword:ftp://example.com/pub/docs/agenda.doc\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rfc7986_properties() -> std::fmt::Result {
+        use crate::write::value_types::{
+            ConferenceFeature, ICalDuration, ImageDisplay, LanguageTag, Uri,
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        ico.name("Company Vacation Days")?;
+        let mut p = ico.property(crate::structure::icalstream::properties::rfc7986::Name)?;
+        p.param(
+            crate::structure::icalstream::parameters::Language,
+            LanguageTag::new("no").unwrap(),
+        )?;
+        p.value("Ferie")?;
+        p.end()?;
+        ico.refresh_interval(ICalDuration::weeks(1))?;
+        ico.source(Uri::new("https://example.com/holidays.ics").unwrap())?;
+        ico.color("turquoise")?;
+        ico.image(
+            Uri::new("http://example.com/images/logo.png").unwrap(),
+            ImageDisplay::Badge,
+        )?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+        ev.image(
+            Uri::new("http://example.com/images/party.png").unwrap(),
+            ImageDisplay::Thumbnail,
+        )?;
+        ev.conference(
+            Uri::new("tel:+1-412-555-0123%2C%2C%2C654321").unwrap(),
+            [ConferenceFeature::Phone, ConferenceFeature::Moderator],
+            Some("Moderator dial-in"),
+        )?;
+        ev.conference(
+            Uri::new("xmpp:chat-123@conference.example.com").unwrap(),
+            [],
+            None,
+        )?;
+        ev.end()?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            NAME:Company Vacation Days\r\n\
+            NAME;LANGUAGE=no:Ferie\r\n\
+            REFRESH-INTERVAL;VALUE=DURATION:P1W\r\n\
+            SOURCE;VALUE=URI:https://example.com/holidays.ics\r\n\
+            COLOR:turquoise\r\n\
+            IMAGE;VALUE=URI:http://example.com/images/logo.png\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            IMAGE;DISPLAY=THUMBNAIL;VALUE=URI:http://example.com/images/party.png\r\n\
+            CONFERENCE;FEATURE=PHONE,MODERATOR;LABEL=\"Moderator dial-in\";VALUE=URI:tel:\r\n \
+            +1-412-555-0123%2C%2C%2C654321\r\n\
+            CONFERENCE;VALUE=URI:xmpp:chat-123@conference.example.com\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn event_publishing() -> std::fmt::Result {
+        use std::num::NonZeroU32;
+
+        use crate::{
+            structure::icalstream::{parameters, properties::rfc9073},
+            write::value_types::{CalAddress, MediaType, ParticipantType, ResourceType, Uri},
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+
+        let mut p = ev.property(rfc9073::StyledDescription)?;
+        p.param(parameters::FormatType, MediaType::new("text/html").unwrap())?;
+        p.param(parameters::Derived, true)?;
+        p.param(parameters::Order, NonZeroU32::MIN)?;
+        p.value("<p>Hi</p>")?;
+        p.end()?;
+
+        let mut p = ev.property(rfc9073::StructuredData)?;
+        p.param(
+            parameters::Schema,
+            Uri::new("https://schema.org/SportsEvent").unwrap(),
+        )?;
+        p.value(Uri::new("http://example.com/event-details.json").unwrap())?;
+        p.end()?;
+
+        let mut loc = ev.structured_location("loc-1")?;
+        loc.name("The venue")?;
+        loc.location_type(["parking", "venue"])?;
+        loc.end()?;
+
+        let mut res = ev.structured_resource("res-1")?;
+        res.name("The projector")?;
+        res.resource_type(ResourceType::Projector)?;
+        res.end()?;
+
+        let mut part = ev.participant("part-1", ParticipantType::Speaker)?;
+        part.calendar_address(CalAddress::new("mailto:speaker@example.com").unwrap())?;
+        let loc = part.structured_location("loc-2")?;
+        loc.end()?;
+        part.end()?;
+
+        ev.end()?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            STYLED-DESCRIPTION;FMTTYPE=text/html;DERIVED=TRUE;ORDER=1;VALUE=TEXT:<p>Hi<\r\n /p>\r\n\
+            STRUCTURED-DATA;SCHEMA=\"https://schema.org/SportsEvent\";VALUE=URI:http://ex\r\n \
+            ample.com/event-details.json\r\n\
+            BEGIN:VLOCATION\r\n\
+            UID:loc-1\r\n\
+            NAME:The venue\r\n\
+            LOCATION-TYPE:parking,venue\r\n\
+            END:VLOCATION\r\n\
+            BEGIN:VRESOURCE\r\n\
+            UID:res-1\r\n\
+            NAME:The projector\r\n\
+            RESOURCE-TYPE:PROJECTOR\r\n\
+            END:VRESOURCE\r\n\
+            BEGIN:PARTICIPANT\r\n\
+            UID:part-1\r\n\
+            PARTICIPANT-TYPE:SPEAKER\r\n\
+            CALENDAR-ADDRESS:mailto:speaker@example.com\r\n\
+            BEGIN:VLOCATION\r\n\
+            UID:loc-2\r\n\
+            END:VLOCATION\r\n\
+            END:PARTICIPANT\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dynamic_names() -> std::fmt::Result {
+        use crate::{
+            structure::value_types::{Boolean, Text, Uri},
+            write::{value_types::Uri as UriValue, DynamicName},
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let name = |x| DynamicName::new(x).unwrap();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut p = ico.dynamic_property(&name("X-WR-CALNAME"))?;
+        p.dynamic_param(&name("X-LANG"), "no")?;
+        p.value::<Text>("Ferie; sommer")?;
+        p.end()?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+        let mut p = ev.dynamic_property(&name("X-APPLE-STRUCTURED-LOCATION"))?;
+        p.dynamic_param(&name("X-TITLE"), "Oslo, Norway")?;
+        p.dynamic_param(&name("X-APPLE-RADIUS"), 100)?;
+        p.value::<Uri>(UriValue::new("https://example.com/oslo").unwrap())?;
+        p.end()?;
+
+        let mut p =
+            ev.dynamic_property(&DynamicName::x_vendor("MICROSOFT", "CDO-BUSYSTATUS").unwrap())?;
+        p.value::<Text>("BUSY")?;
+        p.end()?;
+
+        let mut c = ev.dynamic_component(&name("X-VENDOR-THING"))?;
+        let mut p = c.dynamic_property(&name("X-FLAGS"))?;
+        p.list_value::<Boolean>([true, false])?;
+        p.end()?;
+        c.end()?;
+        ev.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            X-WR-CALNAME;X-LANG=no:Ferie\\; sommer\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            X-APPLE-STRUCTURED-LOCATION;X-TITLE=\"Oslo, Norway\";X-APPLE-RADIUS=100;VALUE\r\n \
+            =URI:https://example.com/oslo\r\n\
+            X-MICROSOFT-CDO-BUSYSTATUS:BUSY\r\n\
+            BEGIN:X-VENDOR-THING\r\n\
+            X-FLAGS;VALUE=BOOLEAN:TRUE,FALSE\r\n\
+            END:X-VENDOR-THING\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn writer_event() -> std::fmt::Result {
+        use crate::write::value_types::TimeTransparency;
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let dtstamp = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.dtstamp(dtstamp)?;
+        ev.uid("unique identifier")?;
+        ev.dtstart(dtstamp.date_naive())?;
+        ev.summary("summary text")?;
+        ev.time_transparency(TimeTransparency::Transparent)?;
+        ev.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTAMP:20240626T120000Z\r\n\
+            UID:unique identifier\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            SUMMARY:summary text\r\n\
+            TRANSP:TRANSPARENT\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn descriptive_properties() -> std::fmt::Result {
+        use crate::write::value_types::{Base64Binary, Classification, EventStatus, Uri};
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+        ev.attach(Uri::new("https://example.com/agenda.pdf").unwrap())?;
+        ev.attach(Base64Binary(b"foobar"))?;
+        ev.categories(["APPOINTMENT", "EDUCATION"])?;
+        ev.classification(Classification::Public)?;
+        ev.classification(Classification::Confidential)?;
+        ev.comment("Bring snacks, please")?;
+        ev.description("Line one\nLine two")?;
+        ev.geo((37.386013, -122.082932))?;
+        ev.location("Conference Room - F123, Bldg. 002")?;
+        ev.priority(1)?;
+        ev.resources(["EASEL", "PROJECTOR"])?;
+        ev.status(EventStatus::Tentative)?;
+        ev.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            ATTACH:https://example.com/agenda.pdf\r\n\
+            ATTACH;VALUE=BINARY;ENCODING=BASE64:Zm9vYmFy\r\n\
+            CATEGORIES:APPOINTMENT,EDUCATION\r\n\
+            CLASS:CONFIDENTIAL\r\n\
+            COMMENT:Bring snacks\\, please\r\n\
+            DESCRIPTION:Line one\\nLine two\r\n\
+            GEO:37.386013;-122.082932\r\n\
+            LOCATION:Conference Room - F123\\, Bldg. 002\r\n\
+            PRIORITY:1\r\n\
+            RESOURCES:EASEL,PROJECTOR\r\n\
+            STATUS:TENTATIVE\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn relationship_properties() -> std::fmt::Result {
+        use crate::write::value_types::{
+            CalAddress, CalendarUserType, ParticipationRole, ParticipationStatus, RelationshipType,
+            Uri,
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let instance = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+        let address = |email| CalAddress::from_email(email).unwrap();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.organizer(address("jsmith@example.com"))?
+            .cn("Smith, John")?
+            .sent_by(&address("jane_doe@example.com"))?
+            .end()?;
+        ev.attendee(address("hcabot@example.com"))?
+            .role(ParticipationRole::ReqParticipant)?
+            .partstat(ParticipationStatus::Tentative)?
+            .rsvp(true)?
+            .cn("Henry Cabot")?
+            .end()?;
+        ev.attendee(address("room@example.com"))?
+            .cutype(CalendarUserType::Room)?
+            .delegated_from([&address("bob@example.com")])?
+            .end()?;
+        ev.contact("Jim Dolittle, ABC Industries")?;
+        ev.related_to("parent@example.com", RelationshipType::Parent)?;
+        ev.related_to("sibling@example.com", RelationshipType::Sibling)?;
+        ev.url(Uri::new("https://example.com/meeting").unwrap())?;
+        ev.recurrence_id_this_and_future(instance)?;
+        assert!(ev.dtstart(instance.date_naive()).is_err());
+        ev.dtstart(instance)?;
+        ev.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            ORGANIZER;CN=\"Smith, John\";SENT-BY=\"mailto:jane_doe@example.com\":mailto:jsm\r\n \
+            ith@example.com\r\n\
+            ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=TENTATIVE;RSVP=TRUE;CN=\"Henry Cabot\"\r\n \
+            :mailto:hcabot@example.com\r\n\
+            ATTENDEE;CUTYPE=ROOM;DELEGATED-FROM=\"mailto:bob@example.com\":mailto:room@ex\r\n \
+            ample.com\r\n\
+            CONTACT:Jim Dolittle\\, ABC Industries\r\n\
+            RELATED-TO:parent@example.com\r\n\
+            RELATED-TO;RELTYPE=SIBLING:sibling@example.com\r\n\
+            URL:https://example.com/meeting\r\n\
+            RECURRENCE-ID;RANGE=THISANDFUTURE:20240626T120000Z\r\n\
+            DTSTART:20240626T120000Z\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejected_date_time_form() -> std::fmt::Result {
+        use crate::structure::value_types::DateTime;
+
+        use super::typed_writers::ICalStreamWriter;
+
+        /// A UTC DATE-TIME that is not a valid point in time
+        struct Invalid;
+
+        impl AsValueType<DateTime> for Invalid {
+            fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "20241399T000000Z")
+            }
+
+            fn is_utc(&self) -> bool {
+                true
+            }
+        }
+
+        impl ToValueType for Invalid {
+            type ValueType = DateTime;
+        }
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        // The form of a rejected value does not restrict the next one
+        let mut ev = ico.event()?;
+        assert!(ev.dtstart(Invalid).is_err());
+        ev.dtstart(Day)?;
+        ev.end()?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn date_and_time_properties() -> std::fmt::Result {
+        use crate::{
+            structure::icalstream::{components::FreeBusyC, parameters, properties},
+            write::value_types::{FreeBusyType, ICalDuration, PeriodUtc},
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let start = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+        let end = start + chrono::Duration::hours(2);
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(start.date_naive())?;
+        assert!(ev.dtend(end).is_err());
+        ev.dtend(start.date_naive().succ_opt().unwrap())?;
+        assert!(ev.duration(ICalDuration::days(1)).is_err());
+        ev.end()?;
+
+        let mut ev = ico.event()?;
+        ev.dtend(end)?;
+        assert!(ev.dtstart(start.naive_utc()).is_err());
+        ev.dtstart(start)?;
+        ev.end()?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(start.naive_utc())?;
+        ev.duration(ICalDuration::hours(2))?;
+        assert!(ev.dtend(end.naive_utc()).is_err());
+        ev.end()?;
+
+        // DTEND must be after DTSTART
+        let mut ev = ico.event()?;
+        ev.dtstart(end)?;
+        assert!(ev.dtend(start).is_err());
+        assert!(ev.dtend(end).is_err());
+        assert!(ev.dtend(end.date_naive()).is_err());
+        ev.end()?;
+
+        let mut fb = ico.component(FreeBusyC)?;
+        let mut prop = fb.property(properties::date_and_time::FreeBusyTime)?;
+        prop.param(parameters::FreeBusyTimeType, FreeBusyType::BusyTentative)?;
+        prop.value(PeriodUtc::merge([
+            PeriodUtc::new(start, end).unwrap(),
+            PeriodUtc::with_duration(end, ICalDuration::hours(1)).unwrap(),
+        ]))?;
+        prop.end()?;
+        fb.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            DTEND;VALUE=DATE:20240627\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTEND:20240626T140000Z\r\n\
+            DTSTART:20240626T120000Z\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART:20240626T120000\r\n\
+            DURATION:PT2H\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART:20240626T140000Z\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VFREEBUSY\r\n\
+            FREEBUSY;FBTYPE=BUSY-TENTATIVE:20240626T120000Z/20240626T150000Z\r\n\
+            END:VFREEBUSY\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono-tz010")]
     #[test]
-    fn icalstream() -> std::fmt::Result {
+    fn mixed_date_time_forms() -> std::fmt::Result {
+        use chrono::TimeZone;
+
         use super::typed_writers::ICalStreamWriter;
 
+        let utc = |s| chrono::DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+        let start = chrono_tz::Europe::Oslo
+            .with_ymd_and_hms(2024, 6, 26, 14, 0, 0)
+            .unwrap();
+
         let mut buf = String::new();
         let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        // A DTSTART with a time zone reference goes with a DTEND in UTC, but
+        // not with a floating one
+        let mut ev = ico.event()?;
+        ev.dtstart(start)?;
+        assert!(ev.dtend(utc("2024-06-26T13:00:00Z").naive_utc()).is_err());
+        assert!(ev.dtend(utc("2024-06-26T12:00:00Z")).is_err());
+        ev.dtend(utc("2024-06-26T13:00:00Z"))?;
+        ev.end()?;
+
+        // Without jiff, the VTIMEZONE is not generated, which is an error
+        let _ = ico.end();
+
+        assert!(buf.contains(
+            "BEGIN:VEVENT\r\n\
+            DTSTART;TZID=Europe/Oslo:20240626T140000\r\n\
+            DTEND:20240626T130000Z\r\n\
+            END:VEVENT\r\n"
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono-tz010")]
+    #[test]
+    fn recurrence_id_forms() -> std::fmt::Result {
+        use chrono::TimeZone;
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let start = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+        let instance = chrono_tz::Europe::Oslo
+            .with_ymd_and_hms(2024, 7, 3, 14, 0, 0)
+            .unwrap();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        // RECURRENCE-ID may have a time zone reference when DTSTART is in
+        // UTC, but it must not be floating or a DATE
+        let mut ev = ico.event()?;
+        ev.dtstart(start)?;
+        assert!(ev.recurrence_id(instance.naive_local()).is_err());
+        assert!(ev.recurrence_id(instance.date_naive()).is_err());
+        ev.recurrence_id(instance)?;
+        ev.end()?;
+
+        // Without jiff, the VTIMEZONE is not generated, which is an error
+        let _ = ico.end();
+
+        assert!(buf.contains(
+            "BEGIN:VEVENT\r\n\
+            DTSTART:20240626T120000Z\r\n\
+            RECURRENCE-ID;TZID=Europe/Oslo:20240703T140000\r\n\
+            END:VEVENT\r\n"
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn time_zone_writer() -> std::fmt::Result {
+        use crate::write::value_types::{Frequency, Recur, Uri, Weekday};
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let dtstart = |s| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let offset = |hours| chrono::FixedOffset::east_opt(hours * 3600).unwrap();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut tz = ico.time_zone("Europe/Oslo")?;
+        tz.tz_url(Uri::new("https://example.com/tz/Europe-Oslo.ics").unwrap())?;
+        assert!(tz
+            .standard(dtstart("1970-10-25 03:00").and_utc(), offset(2), offset(1))
+            .is_err());
+
+        let mut std = tz.standard(dtstart("1970-10-25 03:00"), offset(2), offset(1))?;
+        std.recurrence_rule(
+            Recur::new(Frequency::Yearly)
+                .by_month([10])
+                .by_day([Weekday::Sunday.nth(-1)]),
+        )?;
+        std.tz_name("CET")?;
+        std.end()?;
+
+        let mut dst = tz.daylight(dtstart("1970-03-29 02:00"), offset(1), offset(2))?;
+        dst.tz_name("CEST")?;
+        dst.end()?;
+        tz.end()?;
+
+        let tz = ico.time_zone("Nowhere")?;
+        assert!(tz.end().is_err());
 
-        let ico = ics.icalendar_object("-//test//")?;
         ico.end()?;
 
         assert_eq!(
             &buf,
-            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//test//\r\nEND:VCALENDAR\r\n"
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VTIMEZONE\r\n\
+            TZID:Europe/Oslo\r\n\
+            TZURL:https://example.com/tz/Europe-Oslo.ics\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:19701025T030000\r\n\
+            TZOFFSETFROM:+0200\r\n\
+            TZOFFSETTO:+0100\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10\r\n\
+            TZNAME:CET\r\n\
+            END:STANDARD\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:19700329T020000\r\n\
+            TZOFFSETFROM:+0100\r\n\
+            TZOFFSETTO:+0200\r\n\
+            TZNAME:CEST\r\n\
+            END:DAYLIGHT\r\n\
+            END:VTIMEZONE\r\n\
+            BEGIN:VTIMEZONE\r\n\
+            TZID:Nowhere\r\n\
+            END:VTIMEZONE\r\n\
+            END:VCALENDAR\r\n"
         );
         Ok(())
     }
 
     #[cfg(feature = "chrono04")]
     #[test]
-    fn writer_event() -> std::fmt::Result {
-        use crate::write::value_types::TimeTransparency;
+    fn change_management_properties() -> std::fmt::Result {
+        use super::typed_writers::ICalStreamWriter;
+
+        let created = chrono::DateTime::parse_from_rfc3339("1996-03-29T13:30:00Z")
+            .unwrap()
+            .to_utc();
+        let modified = chrono::DateTime::parse_from_rfc3339("1996-08-17T15:30:00+02:00")
+            .unwrap()
+            .to_utc();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+        ev.created(created)?;
+        ev.last_modified(modified)?;
+        ev.sequence(0)?;
+        ev.sequence(2)?;
+        ev.end()?;
+
+        let mut todo = ico.todo()?;
+        todo.created(created)?;
+        todo.last_modified(modified)?;
+        todo.sequence(1)?;
+        todo.end()?;
+
+        let mut journal = ico.journal()?;
+        journal.created(created)?;
+        journal.sequence(0)?;
+        journal.end()?;
+
+        let mut tz = ico.time_zone("Europe/Oslo")?;
+        tz.last_modified(modified)?;
+        tz.standard(
+            created.naive_utc(),
+            chrono::FixedOffset::east_opt(3600).unwrap(),
+            chrono::FixedOffset::east_opt(3600).unwrap(),
+        )?
+        .end()?;
+        tz.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            CREATED:19960329T133000Z\r\n\
+            LAST-MODIFIED:19960817T133000Z\r\n\
+            SEQUENCE:2\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VTODO\r\n\
+            CREATED:19960329T133000Z\r\n\
+            LAST-MODIFIED:19960817T133000Z\r\n\
+            SEQUENCE:1\r\n\
+            END:VTODO\r\n\
+            BEGIN:VJOURNAL\r\n\
+            CREATED:19960329T133000Z\r\n\
+            END:VJOURNAL\r\n\
+            BEGIN:VTIMEZONE\r\n\
+            TZID:Europe/Oslo\r\n\
+            LAST-MODIFIED:19960817T133000Z\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:19960329T133000\r\n\
+            TZOFFSETFROM:+0100\r\n\
+            TZOFFSETTO:+0100\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn alarms() -> std::fmt::Result {
+        use crate::write::value_types::{CalAddress, ICalDuration, Trigger, Uri};
 
         use super::typed_writers::ICalStreamWriter;
 
-        let dtstamp = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+        let absolute = chrono::DateTime::parse_from_rfc3339("1997-03-17T13:30:00Z")
             .unwrap()
             .to_utc();
+        let address = CalAddress::from_email("john_doe@example.com").unwrap();
+        let sound = || Uri::new("ftp://example.com/pub/sounds/bell-01.aud").unwrap();
 
         let mut buf = String::new();
         let mut ics = ICalStreamWriter::with_fmt(&mut buf);
@@ -36,11 +922,39 @@ mod test {
         let mut ico = ics.icalendar_object("-//test//")?;
 
         let mut ev = ico.event()?;
-        ev.dtstamp(dtstamp)?;
-        ev.uid("unique identifier")?;
-        ev.dtstart(dtstamp.date_naive())?;
-        ev.summary("summary text")?;
-        ev.time_transparency(TimeTransparency::Transparent)?;
+        ev.dtstart(Day)?;
+
+        let mut alarm = ev.audio_alarm(Trigger::at(absolute))?;
+        alarm.repeat(4, ICalDuration::minutes(15))?;
+        assert!(alarm.repeat(1, ICalDuration::minutes(15)).is_err());
+        alarm.attach(sound())?;
+        assert!(alarm.attach(sound()).is_err());
+        alarm.end()?;
+
+        let mut alarm = ev.display_alarm(
+            Trigger::Start(-ICalDuration::minutes(30)),
+            "Breakfast meeting",
+        )?;
+        assert!(alarm.attach(sound()).is_err());
+        alarm.end()?;
+
+        assert!(ev
+            .email_alarm(
+                Trigger::End(-ICalDuration::days(2)),
+                "Reminder",
+                "Agenda",
+                []
+            )
+            .is_err());
+        let mut alarm = ev.email_alarm(
+            Trigger::End(-ICalDuration::days(2)),
+            "Reminder",
+            "Agenda",
+            [&address],
+        )?;
+        alarm.attach(Uri::new("http://example.com/templates/agenda.doc").unwrap())?;
+        alarm.end()?;
+
         ev.end()?;
 
         ico.end()?;
@@ -51,11 +965,151 @@ mod test {
             VERSION:2.0\r\n\
             PRODID:-//test//\r\n\
             BEGIN:VEVENT\r\n\
-            DTSTAMP:20240626T120000Z\r\n\
-            UID:unique identifier\r\n\
             DTSTART;VALUE=DATE:20240626\r\n\
-            SUMMARY:summary text\r\n\
-            TRANSP:TRANSPARENT\r\n\
+            BEGIN:VALARM\r\n\
+            ACTION:AUDIO\r\n\
+            TRIGGER;VALUE=DATE-TIME:19970317T133000Z\r\n\
+            REPEAT:4\r\n\
+            DURATION:PT15M\r\n\
+            ATTACH:ftp://example.com/pub/sounds/bell-01.aud\r\n\
+            END:VALARM\r\n\
+            BEGIN:VALARM\r\n\
+            ACTION:DISPLAY\r\n\
+            TRIGGER:-PT30M\r\n\
+            DESCRIPTION:Breakfast meeting\r\n\
+            END:VALARM\r\n\
+            BEGIN:VALARM\r\n\
+            ACTION:EMAIL\r\n\
+            TRIGGER;RELATED=END:-P2D\r\n\
+            DESCRIPTION:Agenda\r\n\
+            SUMMARY:Reminder\r\n\
+            ATTENDEE:mailto:john_doe@example.com\r\n\
+            ATTACH:http://example.com/templates/agenda.doc\r\n\
+            END:VALARM\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn alarm_extensions() -> std::fmt::Result {
+        use crate::write::value_types::{ICalDuration, Proximity, Trigger};
+
+        use super::typed_writers::ICalStreamWriter;
+
+        let acknowledged = chrono::DateTime::parse_from_rfc3339("2009-06-04T08:45:00Z")
+            .unwrap()
+            .to_utc();
+        let snoozed_until = acknowledged + chrono::TimeDelta::minutes(10);
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
+
+        let mut alarm = ev.display_alarm(Trigger::Start(-ICalDuration::minutes(15)), "Event")?;
+        alarm.uid("alarm-1")?;
+        alarm.acknowledged(acknowledged)?;
+        alarm.end()?;
+
+        let mut alarm = ev.display_alarm(Trigger::at(snoozed_until), "Event")?;
+        alarm.uid("alarm-2")?;
+        alarm.snooze_of("alarm-1")?;
+        alarm.end()?;
+
+        let mut alarm = ev.display_alarm(Trigger::Start(ICalDuration::default()), "Home")?;
+        alarm.proximity(Proximity::Arrive)?;
+        let mut location = alarm.structured_location("home")?;
+        location.geo((40.443, -79.945))?;
+        location.end()?;
+        alarm.end()?;
+
+        ev.end()?;
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
+            BEGIN:VALARM\r\n\
+            ACTION:DISPLAY\r\n\
+            TRIGGER:-PT15M\r\n\
+            DESCRIPTION:Event\r\n\
+            UID:alarm-1\r\n\
+            ACKNOWLEDGED:20090604T084500Z\r\n\
+            END:VALARM\r\n\
+            BEGIN:VALARM\r\n\
+            ACTION:DISPLAY\r\n\
+            TRIGGER;VALUE=DATE-TIME:20090604T085500Z\r\n\
+            DESCRIPTION:Event\r\n\
+            UID:alarm-2\r\n\
+            RELATED-TO;RELTYPE=SNOOZE:alarm-1\r\n\
+            END:VALARM\r\n\
+            BEGIN:VALARM\r\n\
+            ACTION:DISPLAY\r\n\
+            TRIGGER:PT0S\r\n\
+            DESCRIPTION:Home\r\n\
+            PROXIMITY:ARRIVE\r\n\
+            BEGIN:VLOCATION\r\n\
+            UID:home\r\n\
+            GEO:40.443;-79.945\r\n\
+            END:VLOCATION\r\n\
+            END:VALARM\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn recurrence_properties() -> std::fmt::Result {
+        use crate::write::value_types::{Frequency, Recur};
+
+        use super::typed_writers::{ICalStreamWriter, Warning};
+
+        let start = chrono::DateTime::parse_from_rfc3339("2024-06-26T12:00:00Z")
+            .unwrap()
+            .to_utc();
+        let day = chrono::Duration::days(1);
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(start)?;
+        ev.recurrence_rule(Recur::new(Frequency::Daily).count(10))?;
+        assert_eq!(ev.warnings(), []);
+        ev.exception_datetimes([start + day, start + day * 2])?;
+        assert!(ev
+            .exception_datetimes([(start + day).date_naive()])
+            .is_err());
+        assert!(ev.exception_datetimes([(start + day).naive_utc()]).is_err());
+        ev.recurrence_rule(Recur::new(Frequency::Weekly))?;
+        assert_eq!(ev.warnings(), [Warning::RepeatedRecurrenceRule]);
+        ev.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART:20240626T120000Z\r\n\
+            RRULE:FREQ=DAILY;COUNT=10\r\n\
+            EXDATE:20240627T120000Z,20240628T120000Z\r\n\
+            RRULE:FREQ=WEEKLY\r\n\
             END:VEVENT\r\n\
             END:VCALENDAR\r\n"
         );
@@ -82,6 +1136,7 @@ mod test {
         let mut ico = ics.icalendar_object("-//test//")?;
 
         let mut ev = ico.event()?;
+        ev.dtstart(Day)?;
         ev.recurrence_datetimes(datetimes.iter().map(|dt| dt.date_naive()))?;
         ev.recurrence_datetimes(datetimes.iter())?;
         ev.recurrence_datetimes(datetimes)?;
@@ -99,6 +1154,7 @@ mod test {
             VERSION:2.0\r\n\
             PRODID:-//test//\r\n\
             BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20240626\r\n\
             RDATE;VALUE=DATE:20240626,20240627\r\n\
             RDATE:20240626T120000Z,20240627T120000Z\r\n\
             RDATE:20240626T120000Z,20240627T120000Z\r\n\
@@ -108,4 +1164,204 @@ mod test {
         );
         Ok(())
     }
+
+    #[cfg(feature = "jiff02")]
+    #[test]
+    fn time_zone_definitions() -> std::fmt::Result {
+        use super::{typed_writers::ICalStreamWriter, vtimezone::TimeZoneDefinition};
+
+        let summer: jiff::Zoned = "2024-06-26T09:00[Europe/Oslo]".parse().unwrap();
+        let new_york: jiff::Zoned = "2024-06-26T09:00[America/New_York]".parse().unwrap();
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(&summer)?;
+        ev.end()?;
+
+        let mut ev = ico.event()?;
+        ev.dtstart(&new_york)?;
+        ev.end()?;
+
+        // Time zones that are defined explicitly are not generated again
+        let definition = TimeZoneDefinition::from_tzdb(
+            "America/New_York",
+            new_york.timestamp(),
+            new_york.timestamp(),
+        )
+        .unwrap();
+        ico.time_zone_definition(&definition)?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;TZID=Europe/Oslo:20240626T090000\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;TZID=America/New_York:20240626T090000\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VTIMEZONE\r\n\
+            TZID:America/New_York\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20240310T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=2SU;BYMONTH=3\r\n\
+            TZOFFSETFROM:-0500\r\n\
+            TZOFFSETTO:-0400\r\n\
+            TZNAME:EDT\r\n\
+            END:DAYLIGHT\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20241103T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=1SU;BYMONTH=11\r\n\
+            TZOFFSETFROM:-0400\r\n\
+            TZOFFSETTO:-0500\r\n\
+            TZNAME:EST\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n\
+            BEGIN:VTIMEZONE\r\n\
+            TZID:Europe/Oslo\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20240331T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3\r\n\
+            TZOFFSETFROM:+0100\r\n\
+            TZOFFSETTO:+0200\r\n\
+            TZNAME:CEST\r\n\
+            END:DAYLIGHT\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20241027T030000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10\r\n\
+            TZOFFSETFROM:+0200\r\n\
+            TZOFFSETTO:+0100\r\n\
+            TZNAME:CET\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "jiff02")]
+    #[test]
+    fn unknown_time_zone() -> std::fmt::Result {
+        use crate::{
+            structure::value_types::DateTime,
+            write::value_types::{AsValueType, ToValueType},
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        struct MadeUp;
+
+        impl AsValueType<DateTime> for MadeUp {
+            fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "20240101T000000")
+            }
+
+            fn time_zone_reference(&self) -> Option<(&str, i64)> {
+                Some(("Made/Up", 0))
+            }
+        }
+
+        impl ToValueType for MadeUp {
+            type ValueType = DateTime;
+        }
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(MadeUp)?;
+        ev.end()?;
+
+        assert!(ico.end().is_err());
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "jiff02"))]
+    #[test]
+    fn undefined_time_zone() -> std::fmt::Result {
+        use crate::{
+            structure::value_types::{DateTime, UtcOffset},
+            write::value_types::{AsValueType, ToValueType},
+        };
+
+        use super::typed_writers::ICalStreamWriter;
+
+        struct Zoned;
+        struct Local;
+        struct Offset;
+
+        impl AsValueType<DateTime> for Zoned {
+            fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "20240101T000000")
+            }
+
+            fn time_zone_reference(&self) -> Option<(&str, i64)> {
+                Some(("Europe/Oslo", 1704063600))
+            }
+        }
+
+        impl ToValueType for Zoned {
+            type ValueType = DateTime;
+        }
+
+        impl AsValueType<DateTime> for Local {
+            fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "19701025T030000")
+            }
+        }
+
+        impl ToValueType for Local {
+            type ValueType = DateTime;
+        }
+
+        impl AsValueType<UtcOffset> for Offset {
+            fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "+0100")
+            }
+        }
+
+        // Without jiff, VTIMEZONE components are not generated, so a
+        // referenced time zone must be written explicitly
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(Zoned)?;
+        ev.end()?;
+        assert!(ico.end().is_err());
+
+        // A VTIMEZONE that failed to end does not define its TZID
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(Zoned)?;
+        ev.end()?;
+        let tz = ico.time_zone("Europe/Oslo")?;
+        assert!(tz.end().is_err());
+        assert!(ico.end().is_err());
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(Zoned)?;
+        ev.end()?;
+        let mut tz = ico.time_zone("Europe/Oslo")?;
+        tz.standard(Local, Offset, Offset)?.end()?;
+        tz.end()?;
+        ico.end()?;
+
+        Ok(())
+    }
 }
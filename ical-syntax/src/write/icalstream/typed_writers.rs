@@ -1,18 +1,32 @@
+use std::collections::BTreeSet;
 use std::fmt::{Error, Write};
 
 use crate::{
     structure::{
+        composite_value_types::Any2,
         icalstream::{
             components::{
-                self, EventC, EventCProperty, ICalObject, ICalObjectProperty, ICalStreamComponent,
+                self, AlarmC, AlarmCComponent, AlarmCProperty, DaylightC, EventC, EventCComponent,
+                EventCProperty, ICalObject, ICalObjectProperty, ICalStreamComponent, JournalC,
+                JournalCProperty, LocationC, LocationCProperty, ObservanceProperty, ParticipantC,
+                ParticipantCComponent, ParticipantCProperty, ResourceC, ResourceCProperty,
+                StandardC, TimeZoneC, TimeZoneCComponent, TimeZoneCProperty, TodoC, TodoCComponent,
+                TodoCProperty,
             },
-            properties,
+            parameters, properties,
         },
-        value_types, Property,
+        parameter_value_items, value_types, AcceptsParam, Property,
     },
     write::{
-        composite_value_types::AsCompositeValueType, value_types::AsValueType, ComponentWriter,
-        PropertyWriter, Writer,
+        composite_value_types::AsCompositeValueType,
+        value_types::{
+            AlarmAction, AsValueType, AsValueTypeChoice, CalAddress, CalendarScale,
+            CalendarUserType, ConferenceFeature, DateTimeForm, DateTimeKey, ImageDisplay, Method,
+            ParticipantType, ParticipationRole, ParticipationStatus, Proximity, RelationshipType,
+            ResourceType, Trigger,
+        },
+        AsParamValueItem, ComponentWriter, DynamicComponentWriter, DynamicName,
+        DynamicPropertyWriter, PropertyWriter, Writer,
     },
 };
 
@@ -51,8 +65,25 @@ impl<W: std::io::Write> ICalStreamWriter<crate::write::io_adapters::FmtToIo<W>>
     }
 }
 
+macro_rules! simple_property {
+    ($name:ident, $prop:path) => {
+        pub fn $name(
+            &mut self,
+            value: impl AsCompositeValueType<<$prop as Property>::CompositeValueType>,
+        ) -> std::fmt::Result {
+            self.simple_property($prop, value)
+        }
+    };
+}
+
 pub struct ICalObjectWriter<'a, W> {
     inner: ComponentWriter<'a, W, ICalObject>,
+    has_calendar_scale: bool,
+    has_method: bool,
+
+    /// The properties of the iCalendar object must come before its components
+    has_component: bool,
+    defined_time_zones: BTreeSet<String>,
 }
 
 impl<'a, W: Write> ICalObjectWriter<'a, W> {
@@ -60,62 +91,293 @@ impl<'a, W: Write> ICalObjectWriter<'a, W> {
         inner: ComponentWriter<'a, W, ICalObject>,
         prod_id: impl AsValueType<value_types::Text>,
     ) -> Result<Self, Error> {
-        let mut new = Self { inner };
+        let mut new = Self {
+            inner,
+            has_calendar_scale: false,
+            has_method: false,
+            has_component: false,
+            defined_time_zones: BTreeSet::new(),
+        };
         new.simple_property(properties::calendar::Version, "2.0")?;
         new.simple_property(properties::calendar::ProdId, prod_id)?;
 
         Ok(new)
     }
 
+    /// Check that no component has begun, since the properties of the
+    /// iCalendar object must come before its components
+    fn check_no_component(&self) -> std::fmt::Result {
+        if self.has_component {
+            Err(Error)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Begin a property. Fails if a component has begun.
     pub fn property<'x, 'y: 'x, P: ICalObjectProperty>(
         &'y mut self,
         property: P,
     ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.check_no_component()?;
         self.inner.property(property)
     }
 
+    /// Write a property. Fails if a component has begun.
     pub fn simple_property<P: ICalObjectProperty>(
         &mut self,
         property: P,
         value: impl AsCompositeValueType<P::CompositeValueType>,
     ) -> std::fmt::Result {
+        self.check_no_component()?;
         self.inner.simple_property(property, value)
     }
 
+    /// Write the CALSCALE property. It is skipped for the default calendar
+    /// scale, [CalendarScale::Gregorian]. Fails if it has already been
+    /// written, or if a component has begun.
+    pub fn calendar_scale(&mut self, value: CalendarScale) -> std::fmt::Result {
+        if self.has_calendar_scale || self.has_component {
+            return Err(Error);
+        }
+
+        if value != Default::default() {
+            self.simple_property(properties::calendar::CalendarScale, value)?;
+        }
+        self.has_calendar_scale = true;
+        Ok(())
+    }
+
+    /// Write the METHOD property, which marks the iCalendar object as part of
+    /// a scheduling transaction. Fails if it has already been written, or if
+    /// a component has begun.
+    ///
+    /// METHOD must be written before the components, since it affects which
+    /// properties they require. See [EventWriter::missing_dtstart].
+    pub fn method(&mut self, value: Method) -> std::fmt::Result {
+        if self.has_method || self.has_component {
+            return Err(Error);
+        }
+
+        self.simple_property(properties::calendar::Method, value)?;
+        self.has_method = true;
+        Ok(())
+    }
+
+    /// Check if METHOD has been written with [ICalObjectWriter::method]
+    pub fn has_method(&self) -> bool {
+        self.has_method
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(name, properties::rfc7986::Name);
+    simple_property!(description, properties::descriptive::Description);
+    simple_property!(uid, properties::relationship::Uid);
+    simple_property!(url, properties::relationship::Url);
+    simple_property!(last_modified, properties::change_management::LastModified);
+    simple_property!(categories, properties::descriptive::Categories);
+    simple_property!(refresh_interval, properties::rfc7986::RefreshInterval);
+    simple_property!(source, properties::rfc7986::Source);
+    simple_property!(color, properties::rfc7986::Color);
+
+    /* == Custom simple property functions == */
+
+    /// Write the IMAGE property. The DISPLAY parameter is skipped for the
+    /// default display mode, [ImageDisplay::Badge].
+    pub fn image(
+        &mut self,
+        value: impl AsCompositeValueType<<properties::rfc7986::Image as Property>::CompositeValueType>,
+        display: ImageDisplay,
+    ) -> std::fmt::Result {
+        write_image(self.property(properties::rfc7986::Image)?, value, display)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property. Fails if a component has begun.
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.check_no_component()?;
+        self.inner.dynamic_property(name)
+    }
+
+    /// A component with a name that is only known at runtime, such as a
+    /// non-standard `X-` component
+    pub fn dynamic_component<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicComponentWriter<'x, W>, Error> {
+        self.has_component = true;
+        self.inner.dynamic_component(name)
+    }
+
     pub fn component<'x, 'y: 'x, NC: components::ICalObjectComponent>(
         &'y mut self,
         component: NC,
     ) -> Result<ComponentWriter<'x, W, NC>, Error> {
+        self.has_component = true;
         ComponentWriter::new(&mut self.inner, component)
     }
 
     pub fn event<'x, 'y: 'x>(&'y mut self) -> Result<EventWriter<'x, W>, Error> {
-        Ok(EventWriter::new(self.component(EventC)?))
+        let dtstart_required = !self.has_method;
+        let mut event = EventWriter::new(self.component(EventC)?);
+        event.dtstart_required = dtstart_required;
+        Ok(event)
     }
 
-    pub fn end(self) -> Result<(), Error> {
-        self.inner.end()
+    pub fn todo<'x, 'y: 'x>(&'y mut self) -> Result<TodoWriter<'x, W>, Error> {
+        Ok(TodoWriter::new(self.component(TodoC)?))
+    }
+
+    pub fn journal<'x, 'y: 'x>(&'y mut self) -> Result<JournalWriter<'x, W>, Error> {
+        Ok(JournalWriter::new(self.component(JournalC)?))
+    }
+
+    /// Begin a VTIMEZONE component with the given TZID. VTIMEZONE components
+    /// that are written this way will not be generated by
+    /// [ICalObjectWriter::end], once they have been successfully ended.
+    pub fn time_zone<'x, 'y: 'x>(&'y mut self, tzid: &str) -> Result<TimeZoneWriter<'x, W>, Error> {
+        let component = ComponentWriter::new(&mut self.inner, TimeZoneC)?;
+        self.has_component = true;
+
+        let mut tz = TimeZoneWriter::new(component, tzid)?;
+        tz.defined_time_zone = Some((&mut self.defined_time_zones, tzid.to_owned()));
+        Ok(tz)
+    }
+
+    /// Write a VTIMEZONE component for the given definition. VTIMEZONE
+    /// components that are written this way will not be generated by
+    /// [ICalObjectWriter::end].
+    #[cfg(feature = "jiff02")]
+    pub fn time_zone_definition(
+        &mut self,
+        definition: &super::vtimezone::TimeZoneDefinition,
+    ) -> std::fmt::Result {
+        let mut c = self.component(TimeZoneC)?;
+        definition.write_into(&mut c)?;
+        c.end()?;
+
+        self.defined_time_zones.insert(definition.tzid().to_owned());
+        Ok(())
+    }
+
+    /// End the iCalendar object.
+    ///
+    /// With the `jiff02` feature, this first writes a VTIMEZONE component for
+    /// each time zone that has been referenced by a `TZID` parameter, and
+    /// which has not been given with [ICalObjectWriter::time_zone_definition].
+    /// The definitions are generated from the time zone database of jiff,
+    /// covering the range of time that has been referenced. An unknown TZID
+    /// yields `Result::Err`, but the iCalendar object is still ended.
+    ///
+    /// Without the `jiff02` feature, every referenced time zone must have been
+    /// written with [ICalObjectWriter::time_zone]. Otherwise, this yields
+    /// `Result::Err`, since a TZID parameter without a matching VTIMEZONE
+    /// component is invalid. The iCalendar object is still ended.
+    pub fn end(mut self) -> Result<(), Error> {
+        let references = self.inner.take_time_zone_references();
+
+        #[cfg(feature = "jiff02")]
+        let result = self.write_time_zone_definitions(&references);
+
+        #[cfg(not(feature = "jiff02"))]
+        let result = if references
+            .iter()
+            .all(|(tzid, _, _)| self.defined_time_zones.contains(tzid))
+        {
+            Ok(())
+        } else {
+            Err(Error)
+        };
+
+        self.inner.end()?;
+        result
+    }
+
+    #[cfg(feature = "jiff02")]
+    fn write_time_zone_definitions(
+        &mut self,
+        references: &crate::write::TimeZoneReferences,
+    ) -> std::fmt::Result {
+        use super::vtimezone::TimeZoneDefinition;
+
+        let timestamp = |t| jiff::Timestamp::from_second(t).map_err(|_| Error);
+
+        for (tzid, start, end) in references.iter() {
+            if self.defined_time_zones.contains(tzid) {
+                continue;
+            }
+
+            let definition =
+                TimeZoneDefinition::from_tzdb(tzid, timestamp(start)?, timestamp(end)?)
+                    .map_err(|_| Error)?;
+            self.time_zone_definition(&definition)?;
+        }
+
+        Ok(())
     }
 }
 
-pub struct EventWriter<'a, W> {
-    inner: ComponentWriter<'a, W, EventC>,
+fn write_image<W: Write, P: AcceptsParam<parameters::ImageDisplay>>(
+    mut p: PropertyWriter<W, P>,
+    value: impl AsCompositeValueType<P::CompositeValueType>,
+    display: ImageDisplay,
+) -> std::fmt::Result {
+    if display != Default::default() {
+        p.param(parameters::ImageDisplay, [display])?;
+    }
+    p.value(value)?;
+    p.end()
 }
 
-macro_rules! simple_property {
-    ($name:ident, $prop:path) => {
-        pub fn $name(
-            &mut self,
-            value: impl AsCompositeValueType<<$prop as Property>::CompositeValueType>,
-        ) -> std::fmt::Result {
-            self.simple_property($prop, value)
+/// A violation of a SHOULD or SHOULD NOT of RFC 5545. Unlike a violation of
+/// a MUST, this does not make the written iCalendar object invalid, so it is
+/// collected by the typed writers rather than returned as an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// RRULE SHOULD NOT be specified more than once, as the recurrence set
+    /// generated with multiple RRULE properties is undefined
+    RepeatedRecurrenceRule,
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::RepeatedRecurrenceRule => {
+                write!(f, "RRULE should not be specified more than once")
+            }
         }
-    };
+    }
+}
+
+pub struct EventWriter<'a, W> {
+    inner: ComponentWriter<'a, W, EventC>,
+
+    /// The form of DTSTART, DTEND and RECURRENCE-ID, which must all agree
+    form: Option<DateTimeForm>,
+    dtstart_required: bool,
+    start: Option<DateTimeKey>,
+    end: Option<DateTimeKey>,
+    has_duration: bool,
+    has_recurrence_rule: bool,
+    warnings: Vec<Warning>,
 }
 
 impl<'a, W: Write> EventWriter<'a, W> {
     pub fn new(inner: ComponentWriter<'a, W, EventC>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            form: None,
+            dtstart_required: true,
+            start: None,
+            end: None,
+            has_duration: false,
+            has_recurrence_rule: false,
+            warnings: Vec::new(),
+        }
     }
 
     pub fn property<'x, 'y: 'x, P: EventCProperty>(
@@ -133,22 +395,421 @@ impl<'a, W: Write> EventWriter<'a, W> {
         self.inner.simple_property(property, value)
     }
 
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    /// A component with a name that is only known at runtime, such as a
+    /// non-standard `X-` component
+    pub fn dynamic_component<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicComponentWriter<'x, W>, Error> {
+        self.inner.dynamic_component(name)
+    }
+
+    /// The warnings for the properties written so far
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Check if DTSTART is required, but has not been written with
+    /// [EventWriter::dtstart]. DTSTART is required unless the iCalendar
+    /// object has a METHOD property, see [ICalObjectWriter::method].
+    pub fn missing_dtstart(&self) -> bool {
+        self.dtstart_required && self.start.is_none()
+    }
+
+    /// End the VEVENT component. Fails if DTSTART is required, but has not
+    /// been written, see [EventWriter::missing_dtstart]. The component is
+    /// still ended.
     pub fn end(self) -> Result<(), Error> {
-        self.inner.end()
+        let result = if self.missing_dtstart() {
+            Err(Error)
+        } else {
+            Ok(())
+        };
+
+        self.inner.end()?;
+        result
     }
 
     /* == Standard simple property functions == */
     simple_property!(dtstamp, properties::change_management::DateTimeStamp);
+    simple_property!(created, properties::change_management::DateTimeCreated);
+    simple_property!(last_modified, properties::change_management::LastModified);
     simple_property!(uid, properties::relationship::Uid);
     simple_property!(summary, properties::descriptive::Summary);
     simple_property!(
         recurrence_datetimes,
         properties::date_and_time::RecurrenceDateTimes
     );
-    simple_property!(dtstart, properties::date_and_time::DateTimeStart);
+    simple_property!(attach, properties::descriptive::Attach);
+    simple_property!(categories, properties::descriptive::Categories);
+    simple_property!(comment, properties::descriptive::Comment);
+    simple_property!(description, properties::descriptive::Description);
+    simple_property!(geo, properties::descriptive::Geo);
+    simple_property!(location, properties::descriptive::Location);
+    simple_property!(priority, properties::descriptive::Priority);
+    simple_property!(resources, properties::descriptive::Resources);
+    simple_property!(contact, properties::relationship::Contact);
+    simple_property!(url, properties::relationship::Url);
+    simple_property!(request_status, properties::misc::RequestStatus);
+    simple_property!(color, properties::rfc7986::Color);
+    simple_property!(styled_description, properties::rfc9073::StyledDescription);
+    simple_property!(structured_data, properties::rfc9073::StructuredData);
 
     /* == Custom simple property functions == */
 
+    /// Check that a DATE or DATE-TIME value has a form that is compatible with
+    /// the other DATE or DATE-TIME values that must agree with DTSTART, see
+    /// [DateTimeForm::is_compatible]. The form is only recorded by the caller
+    /// once the value has been written.
+    fn check_form<T>(&self, value: &T) -> Result<DateTimeForm, Error>
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        let form = DateTimeForm::of(value)?;
+        match self.form {
+            Some(expected) if !expected.is_compatible(form) => Err(Error),
+            _ => Ok(form),
+        }
+    }
+
+    /// Write the DTSTART property. Fails if DTEND or RECURRENCE-ID has already
+    /// been written in an incompatible form, see [DateTimeForm], or if DTEND
+    /// is not after it.
+    pub fn dtstart<T>(&mut self, value: T) -> std::fmt::Result
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        let form = self.check_form(&value)?;
+        let start = DateTimeKey::of(&value, form)?;
+        if self.end.as_ref().is_some_and(|end| *end <= start) {
+            return Err(Error);
+        }
+
+        self.simple_property(properties::date_and_time::DateTimeStart, value)?;
+        self.form = Some(form);
+        self.start = Some(start);
+        Ok(())
+    }
+
+    /// Write the DTEND property. Fails if DTSTART has already been written in
+    /// an incompatible form, see [DateTimeForm], if DTEND is not after
+    /// DTSTART, or if DURATION has been written.
+    pub fn dtend<T>(&mut self, value: T) -> std::fmt::Result
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        if self.has_duration {
+            return Err(Error);
+        }
+        let form = self.check_form(&value)?;
+        let end = DateTimeKey::of(&value, form)?;
+        if self.start.as_ref().is_some_and(|start| end <= *start) {
+            return Err(Error);
+        }
+
+        self.simple_property(properties::date_and_time::DateTimeEnd, value)?;
+        self.form = Some(form);
+        self.end = Some(end);
+        Ok(())
+    }
+
+    /// Write the DURATION property. Fails if DTEND has been written, since
+    /// only one of them may occur.
+    pub fn duration(&mut self, value: impl AsValueType<value_types::Duration>) -> std::fmt::Result {
+        if self.end.is_some() {
+            return Err(Error);
+        }
+
+        self.simple_property(properties::date_and_time::DurationProperty, value)?;
+        self.has_duration = true;
+        Ok(())
+    }
+
+    /// Write the RRULE property. Writing it more than once yields
+    /// [Warning::RepeatedRecurrenceRule].
+    pub fn recurrence_rule(
+        &mut self,
+        value: impl AsValueType<value_types::RecurrenceRule>,
+    ) -> std::fmt::Result {
+        if std::mem::replace(&mut self.has_recurrence_rule, true) {
+            self.warnings.push(Warning::RepeatedRecurrenceRule);
+        }
+        self.simple_property(properties::recurrence::RecurrenceRule, value)
+    }
+
+    /// Write the EXDATE property. Fails if any of the values has a form that
+    /// is incompatible with DTSTART, see [DateTimeForm].
+    pub fn exception_datetimes<T>(
+        &mut self,
+        values: impl IntoIterator<Item = T>,
+    ) -> std::fmt::Result
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        let values: Vec<T> = values.into_iter().collect();
+        let mut form = None;
+        for value in &values {
+            form = Some(self.check_form(value)?);
+        }
+        self.simple_property(properties::recurrence::ExceptionDateTimes, values)?;
+        if form.is_some() {
+            self.form = form;
+        }
+        Ok(())
+    }
+
+    /// Write the RECURRENCE-ID property, identifying a single instance of the
+    /// recurring event with the same UID. Fails if DTSTART has already been
+    /// written in an incompatible form, see [DateTimeForm]. As in RFC 5545
+    /// 3.8.4.4, it may be in the UTC form or have a time zone reference,
+    /// whichever of these DTSTART has.
+    pub fn recurrence_id<T>(&mut self, value: T) -> std::fmt::Result
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        let form = self.check_form(&value)?;
+        self.simple_property(properties::relationship::RecurrenceId, value)?;
+        self.form = Some(form);
+        Ok(())
+    }
+
+    /// Write the RECURRENCE-ID property with `RANGE=THISANDFUTURE`,
+    /// identifying the given instance and all subsequent instances. See
+    /// [EventWriter::recurrence_id].
+    pub fn recurrence_id_this_and_future<T>(&mut self, value: T) -> std::fmt::Result
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        let form = self.check_form(&value)?;
+
+        let mut p = self.property(properties::relationship::RecurrenceId)?;
+        p.param(
+            parameters::RecurrenceIdentifierRange,
+            crate::write::value_types::RecurrenceIdentifierRange::ThisAndFuture,
+        )?;
+        p.value(value)?;
+        p.end()?;
+        self.form = Some(form);
+        Ok(())
+    }
+
+    /// Begin the ATTENDEE property. Parameters are given with the methods of
+    /// [CalendarUserWriter], and the property is written by
+    /// [CalendarUserWriter::end].
+    pub fn attendee<'x, 'y: 'x>(
+        &'y mut self,
+        address: CalAddress,
+    ) -> Result<CalendarUserWriter<'x, W, properties::relationship::Attendee>, Error> {
+        Ok(CalendarUserWriter::new(
+            self.property(properties::relationship::Attendee)?,
+            address,
+        ))
+    }
+
+    /// Begin the ORGANIZER property. See [EventWriter::attendee].
+    pub fn organizer<'x, 'y: 'x>(
+        &'y mut self,
+        address: CalAddress,
+    ) -> Result<CalendarUserWriter<'x, W, properties::relationship::Organizer>, Error> {
+        Ok(CalendarUserWriter::new(
+            self.property(properties::relationship::Organizer)?,
+            address,
+        ))
+    }
+
+    pub fn component<'x, 'y: 'x, NC: EventCComponent>(
+        &'y mut self,
+        component: NC,
+    ) -> Result<ComponentWriter<'x, W, NC>, Error> {
+        self.inner.component(component)
+    }
+
+    /// Begin a PARTICIPANT component with its required properties
+    pub fn participant<'x, 'y: 'x>(
+        &'y mut self,
+        uid: impl AsValueType<value_types::Text>,
+        participant_type: ParticipantType,
+    ) -> Result<ParticipantWriter<'x, W>, Error> {
+        ParticipantWriter::new(self.component(ParticipantC)?, uid, participant_type)
+    }
+
+    /// Begin a VLOCATION component. Unlike the LOCATION property, this can
+    /// describe the location in detail, and there can be several, such as the
+    /// venue and the parking.
+    pub fn structured_location<'x, 'y: 'x>(
+        &'y mut self,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<LocationWriter<'x, W>, Error> {
+        LocationWriter::new(self.component(LocationC)?, uid)
+    }
+
+    /// Begin a VRESOURCE component. Unlike the RESOURCES property, this can
+    /// describe the resource in detail.
+    pub fn structured_resource<'x, 'y: 'x>(
+        &'y mut self,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<ResourceWriter<'x, W>, Error> {
+        ResourceWriter::new(self.component(ResourceC)?, uid)
+    }
+
+    /// Begin a VALARM component that plays a sound. The sound can be given
+    /// with [AlarmWriter::attach].
+    pub fn audio_alarm<'x, 'y: 'x>(
+        &'y mut self,
+        trigger: Trigger,
+    ) -> Result<AlarmWriter<'x, W>, Error> {
+        AlarmWriter::new(self.inner.component(AlarmC)?, AlarmAction::Audio, trigger)
+    }
+
+    /// Begin a VALARM component that displays the given text
+    pub fn display_alarm<'x, 'y: 'x>(
+        &'y mut self,
+        trigger: Trigger,
+        description: impl AsValueType<value_types::Text>,
+    ) -> Result<AlarmWriter<'x, W>, Error> {
+        let mut alarm =
+            AlarmWriter::new(self.inner.component(AlarmC)?, AlarmAction::Display, trigger)?;
+        alarm.simple_property(properties::descriptive::Description, description)?;
+        Ok(alarm)
+    }
+
+    /// Begin a VALARM component that sends an email to the given attendees,
+    /// with `summary` as the subject and `description` as the body.
+    /// Attachments can be given with [AlarmWriter::attach].
+    ///
+    /// At least one attendee is required. Otherwise, this yields
+    /// `Result::Err`, and nothing is written.
+    pub fn email_alarm<'x, 'y: 'x, 'z>(
+        &'y mut self,
+        trigger: Trigger,
+        summary: impl AsValueType<value_types::Text>,
+        description: impl AsValueType<value_types::Text>,
+        attendees: impl IntoIterator<Item = &'z CalAddress>,
+    ) -> Result<AlarmWriter<'x, W>, Error> {
+        let attendees: Vec<_> = attendees.into_iter().collect();
+        if attendees.is_empty() {
+            return Err(Error);
+        }
+
+        let mut alarm =
+            AlarmWriter::new(self.inner.component(AlarmC)?, AlarmAction::Email, trigger)?;
+        alarm.simple_property(properties::descriptive::Description, description)?;
+        alarm.simple_property(properties::descriptive::Summary, summary)?;
+        for attendee in attendees {
+            alarm.simple_property(properties::relationship::Attendee, attendee)?;
+        }
+        Ok(alarm)
+    }
+
+    /// Begin a VALARM component with an IANA-registered or non-standard
+    /// action
+    pub fn other_alarm<'x, 'y: 'x>(
+        &'y mut self,
+        action: DynamicName,
+        trigger: Trigger,
+    ) -> Result<AlarmWriter<'x, W>, Error> {
+        AlarmWriter::new(
+            self.inner.component(AlarmC)?,
+            AlarmAction::Other(action),
+            trigger,
+        )
+    }
+
+    pub fn related_to(
+        &mut self,
+        uid: impl AsValueType<value_types::Text>,
+        relationship: RelationshipType,
+    ) -> std::fmt::Result {
+        let mut p = self.property(properties::relationship::RelatedTo)?;
+        if relationship != Default::default() {
+            p.param(parameters::RelationshipType, relationship)?;
+        }
+        p.value(uid)?;
+        p.end()
+    }
+
+    /// Write the IMAGE property. See [ICalObjectWriter::image].
+    pub fn image(
+        &mut self,
+        value: impl AsCompositeValueType<<properties::rfc7986::Image as Property>::CompositeValueType>,
+        display: ImageDisplay,
+    ) -> std::fmt::Result {
+        write_image(self.property(properties::rfc7986::Image)?, value, display)
+    }
+
+    /// Write the CONFERENCE property. The FEATURE parameter is skipped when
+    /// there are no features, and the LABEL parameter when there is no label.
+    pub fn conference(
+        &mut self,
+        uri: impl AsValueType<value_types::Uri>,
+        features: impl IntoIterator<Item = ConferenceFeature>,
+        label: Option<&str>,
+    ) -> std::fmt::Result {
+        let features: Vec<_> = features.into_iter().collect();
+
+        let mut p = self.property(properties::rfc7986::Conference)?;
+        if !features.is_empty() {
+            p.param(parameters::ConferenceFeature, features)?;
+        }
+        if let Some(label) = label {
+            p.param(parameters::Label, label)?;
+        }
+        p.value(uri)?;
+        p.end()
+    }
+
+    /// Write the SEQUENCE property. Revision numbers start at 0, which is
+    /// the default, so 0 is not written.
+    pub fn sequence(&mut self, value: u32) -> std::fmt::Result {
+        if value != 0 {
+            self.simple_property(properties::change_management::SequenceNumber, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn classification(
+        &mut self,
+        value: crate::write::value_types::Classification,
+    ) -> std::fmt::Result {
+        if value != Default::default() {
+            self.simple_property(properties::descriptive::Classification, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn status(&mut self, value: crate::write::value_types::EventStatus) -> std::fmt::Result {
+        self.simple_property(properties::descriptive::Status, value)
+    }
+
     pub fn time_transparency(
         &mut self,
         value: crate::write::value_types::TimeTransparency,
@@ -160,3 +821,833 @@ impl<'a, W: Write> EventWriter<'a, W> {
         }
     }
 }
+
+/// Reference: [RFC5545 3.6.2](https://www.rfc-editor.org/rfc/rfc5545.html#section-3.6.2)
+pub struct TodoWriter<'a, W> {
+    inner: ComponentWriter<'a, W, TodoC>,
+}
+
+impl<'a, W: Write> TodoWriter<'a, W> {
+    pub fn new(inner: ComponentWriter<'a, W, TodoC>) -> Self {
+        Self { inner }
+    }
+
+    pub fn property<'x, 'y: 'x, P: TodoCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: TodoCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    pub fn component<'x, 'y: 'x, NC: TodoCComponent>(
+        &'y mut self,
+        component: NC,
+    ) -> Result<ComponentWriter<'x, W, NC>, Error> {
+        self.inner.component(component)
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(dtstamp, properties::change_management::DateTimeStamp);
+    simple_property!(created, properties::change_management::DateTimeCreated);
+    simple_property!(last_modified, properties::change_management::LastModified);
+    simple_property!(uid, properties::relationship::Uid);
+    simple_property!(summary, properties::descriptive::Summary);
+    simple_property!(description, properties::descriptive::Description);
+
+    /// Write the SEQUENCE property. Revision numbers start at 0, which is
+    /// the default, so 0 is not written.
+    pub fn sequence(&mut self, value: u32) -> std::fmt::Result {
+        if value != 0 {
+            self.simple_property(properties::change_management::SequenceNumber, value)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reference: [RFC5545 3.6.3](https://www.rfc-editor.org/rfc/rfc5545.html#section-3.6.3)
+pub struct JournalWriter<'a, W> {
+    inner: ComponentWriter<'a, W, JournalC>,
+}
+
+impl<'a, W: Write> JournalWriter<'a, W> {
+    pub fn new(inner: ComponentWriter<'a, W, JournalC>) -> Self {
+        Self { inner }
+    }
+
+    pub fn property<'x, 'y: 'x, P: JournalCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: JournalCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(dtstamp, properties::change_management::DateTimeStamp);
+    simple_property!(created, properties::change_management::DateTimeCreated);
+    simple_property!(last_modified, properties::change_management::LastModified);
+    simple_property!(uid, properties::relationship::Uid);
+    simple_property!(summary, properties::descriptive::Summary);
+    simple_property!(description, properties::descriptive::Description);
+
+    /// Write the SEQUENCE property. Revision numbers start at 0, which is
+    /// the default, so 0 is not written.
+    pub fn sequence(&mut self, value: u32) -> std::fmt::Result {
+        if value != 0 {
+            self.simple_property(properties::change_management::SequenceNumber, value)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Writer for a VTIMEZONE component
+///
+/// The TZID property is required, so it is written when the component is
+/// begun. At least one STANDARD or DAYLIGHT sub-component is required, so
+/// [TimeZoneWriter::end] fails if none have been written.
+pub struct TimeZoneWriter<'a, W> {
+    inner: ComponentWriter<'a, W, TimeZoneC>,
+    has_observance: bool,
+    /// Where the TZID is recorded as defined when the component is ended
+    /// with an observance
+    defined_time_zone: Option<(&'a mut BTreeSet<String>, String)>,
+}
+
+impl<'a, W: Write> TimeZoneWriter<'a, W> {
+    pub fn new(
+        inner: ComponentWriter<'a, W, TimeZoneC>,
+        tzid: impl AsValueType<value_types::Text>,
+    ) -> Result<Self, Error> {
+        let mut new = Self {
+            inner,
+            has_observance: false,
+            defined_time_zone: None,
+        };
+        new.simple_property(properties::time_zone::TimeZoneIdentifier, tzid)?;
+        Ok(new)
+    }
+
+    pub fn property<'x, 'y: 'x, P: TimeZoneCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: TimeZoneCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    /// Begin a STANDARD sub-component with its required properties, see
+    /// [TimeZoneWriter::observance]
+    pub fn standard<'x, 'y: 'x, T>(
+        &'y mut self,
+        dtstart: T,
+        offset_from: impl AsValueType<value_types::UtcOffset>,
+        offset_to: impl AsValueType<value_types::UtcOffset>,
+    ) -> Result<ObservanceWriter<'x, W, StandardC>, Error>
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        self.observance(StandardC, dtstart, offset_from, offset_to)
+    }
+
+    /// Begin a DAYLIGHT sub-component with its required properties, see
+    /// [TimeZoneWriter::observance]
+    pub fn daylight<'x, 'y: 'x, T>(
+        &'y mut self,
+        dtstart: T,
+        offset_from: impl AsValueType<value_types::UtcOffset>,
+        offset_to: impl AsValueType<value_types::UtcOffset>,
+    ) -> Result<ObservanceWriter<'x, W, DaylightC>, Error>
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        self.observance(DaylightC, dtstart, offset_from, offset_to)
+    }
+
+    /// Begin a STANDARD or DAYLIGHT sub-component with its required
+    /// properties. `dtstart` must be in the floating form, that is a date with
+    /// local time, as it is interpreted in the `offset_from` offset. Any other
+    /// form yields `Result::Err`, and nothing is written.
+    pub fn observance<'x, 'y: 'x, C: TimeZoneCComponent, T>(
+        &'y mut self,
+        component: C,
+        dtstart: T,
+        offset_from: impl AsValueType<value_types::UtcOffset>,
+        offset_to: impl AsValueType<value_types::UtcOffset>,
+    ) -> Result<ObservanceWriter<'x, W, C>, Error>
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        if DateTimeForm::of(&dtstart)? != DateTimeForm::Floating {
+            return Err(Error);
+        }
+
+        let mut c = self.inner.component(component)?;
+        c.simple_property(properties::date_and_time::DateTimeStart, dtstart)?;
+        c.simple_property(properties::time_zone::TimeZoneOffsetFrom, offset_from)?;
+        c.simple_property(properties::time_zone::TimeZoneOffsetTo, offset_to)?;
+
+        self.has_observance = true;
+        Ok(ObservanceWriter::new(c))
+    }
+
+    /// End the VTIMEZONE component. Fails if no STANDARD or DAYLIGHT
+    /// sub-component has been written, but the component is still ended.
+    ///
+    /// Only a successfully ended VTIMEZONE defines its TZID for
+    /// [ICalObjectWriter::end].
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()?;
+        if !self.has_observance {
+            return Err(Error);
+        }
+
+        if let Some((defined_time_zones, tzid)) = self.defined_time_zone {
+            defined_time_zones.insert(tzid);
+        }
+        Ok(())
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(last_modified, properties::change_management::LastModified);
+    simple_property!(tz_url, properties::time_zone::TimeZoneUrl);
+}
+
+/// Writer for a STANDARD or DAYLIGHT sub-component of a VTIMEZONE component
+///
+/// The DTSTART, TZOFFSETFROM and TZOFFSETTO properties are required, so they
+/// are written by [TimeZoneWriter::observance] when the sub-component is
+/// begun.
+pub struct ObservanceWriter<'a, W, C> {
+    inner: ComponentWriter<'a, W, C>,
+    has_recurrence_rule: bool,
+    warnings: Vec<Warning>,
+}
+
+impl<'a, W: Write, C: TimeZoneCComponent> ObservanceWriter<'a, W, C> {
+    pub fn new(inner: ComponentWriter<'a, W, C>) -> Self {
+        Self {
+            inner,
+            has_recurrence_rule: false,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn property<'x, 'y: 'x, P: ObservanceProperty<C>>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: ObservanceProperty<C>>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    /// The warnings for the properties written so far
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+
+    // The properties are shared between STANDARD and DAYLIGHT, so these are
+    // written directly rather than with the simple_property! macro
+
+    pub fn tz_name(&mut self, value: impl AsValueType<value_types::Text>) -> std::fmt::Result {
+        self.inner
+            .simple_property(properties::time_zone::TimeZoneName, value)
+    }
+
+    pub fn comment(&mut self, value: impl AsValueType<value_types::Text>) -> std::fmt::Result {
+        self.inner
+            .simple_property(properties::descriptive::Comment, value)
+    }
+
+    /// Write the RRULE property. Writing it more than once yields
+    /// [Warning::RepeatedRecurrenceRule].
+    pub fn recurrence_rule(
+        &mut self,
+        value: impl AsValueType<value_types::RecurrenceRule>,
+    ) -> std::fmt::Result {
+        if std::mem::replace(&mut self.has_recurrence_rule, true) {
+            self.warnings.push(Warning::RepeatedRecurrenceRule);
+        }
+        self.inner
+            .simple_property(properties::recurrence::RecurrenceRule, value)
+    }
+
+    /// Write the EXDATE property. The values must be in the floating form,
+    /// like DTSTART, see [DateTimeForm].
+    pub fn exception_datetimes<T>(
+        &mut self,
+        values: impl IntoIterator<Item = T>,
+    ) -> std::fmt::Result
+    where
+        T: AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>
+            + AsValueType<
+                <T as AsValueTypeChoice<Any2<value_types::DateTime, value_types::Date>>>::Type,
+            >,
+    {
+        let values: Vec<T> = values.into_iter().collect();
+        for value in &values {
+            if DateTimeForm::of(value)? != DateTimeForm::Floating {
+                return Err(Error);
+            }
+        }
+        self.inner
+            .simple_property(properties::recurrence::ExceptionDateTimes, values)
+    }
+
+    pub fn recurrence_datetimes(
+        &mut self,
+        value: impl AsCompositeValueType<
+            <properties::date_and_time::RecurrenceDateTimes as Property>::CompositeValueType,
+        >,
+    ) -> std::fmt::Result {
+        self.inner
+            .simple_property(properties::date_and_time::RecurrenceDateTimes, value)
+    }
+}
+
+/// Writer for a property with a calendar user address as its value, such as
+/// ATTENDEE or ORGANIZER
+///
+/// The parameters are written as they are given, and the address is written
+/// by [CalendarUserWriter::end]:
+///
+/// ```
+/// # use ical_syntax::write::{icalstream::typed_writers::ICalStreamWriter, value_types::*};
+/// # fn main() -> std::fmt::Result {
+/// # let mut buf = String::new();
+/// # let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+/// # let mut ico = ics.icalendar_object("-//test//")?;
+/// # ico.method(Method::Publish)?;
+/// # let mut ev = ico.event()?;
+/// let address = CalAddress::from_email("jsmith@example.com").unwrap();
+/// ev.attendee(address)?
+///     .cn("John Smith")?
+///     .role(ParticipationRole::Chair)?
+///     .end()?;
+/// # ev.end()?;
+/// # ico.end()
+/// # }
+/// ```
+pub struct CalendarUserWriter<'a, W, P> {
+    inner: PropertyWriter<'a, W, P>,
+    address: CalAddress,
+}
+
+impl<'a, W: Write, P> CalendarUserWriter<'a, W, P>
+where
+    P: Property<CompositeValueType = value_types::CalAddress>
+        + AcceptsParam<parameters::CommonName>
+        + AcceptsParam<parameters::DirectoryEntryReference>
+        + AcceptsParam<parameters::SentBy>,
+{
+    pub fn new(inner: PropertyWriter<'a, W, P>, address: CalAddress) -> Self {
+        Self { inner, address }
+    }
+
+    /// The common or display name of the calendar user
+    pub fn cn(mut self, value: impl std::fmt::Display) -> Result<Self, Error> {
+        self.inner.param(parameters::CommonName, value)?;
+        Ok(self)
+    }
+
+    /// A reference to the directory entry of the calendar user
+    pub fn dir(
+        mut self,
+        value: impl AsParamValueItem<parameter_value_items::Uri>,
+    ) -> Result<Self, Error> {
+        self.inner
+            .param(parameters::DirectoryEntryReference, value)?;
+        Ok(self)
+    }
+
+    /// The calendar user that is acting on behalf of this calendar user
+    pub fn sent_by(mut self, value: &CalAddress) -> Result<Self, Error> {
+        self.inner.param(parameters::SentBy, value)?;
+        Ok(self)
+    }
+
+    pub fn end(mut self) -> std::fmt::Result {
+        self.inner.value(&self.address)?;
+        self.inner.end()
+    }
+}
+
+impl<W: Write> CalendarUserWriter<'_, W, properties::relationship::Attendee> {
+    pub fn cutype(mut self, value: CalendarUserType) -> Result<Self, Error> {
+        self.inner.param(parameters::CalendarUserType, value)?;
+        Ok(self)
+    }
+
+    /// The groups or lists that the attendee is a member of
+    pub fn member<'x>(
+        mut self,
+        value: impl IntoIterator<Item = &'x CalAddress>,
+    ) -> Result<Self, Error> {
+        self.inner.param(parameters::Membership, value)?;
+        Ok(self)
+    }
+
+    pub fn role(mut self, value: ParticipationRole) -> Result<Self, Error> {
+        self.inner.param(parameters::ParticipationRole, value)?;
+        Ok(self)
+    }
+
+    pub fn partstat(mut self, value: ParticipationStatus) -> Result<Self, Error> {
+        self.inner.param(parameters::ParticipationStatus, value)?;
+        Ok(self)
+    }
+
+    /// Request a reply from the attendee
+    pub fn rsvp(mut self, value: bool) -> Result<Self, Error> {
+        self.inner.param(parameters::RsvpExpectation, value)?;
+        Ok(self)
+    }
+
+    /// The calendar users that the attendee has delegated participation to
+    pub fn delegated_to<'x>(
+        mut self,
+        value: impl IntoIterator<Item = &'x CalAddress>,
+    ) -> Result<Self, Error> {
+        self.inner.param(parameters::Delegatees, value)?;
+        Ok(self)
+    }
+
+    /// The calendar users that have delegated their participation to the
+    /// attendee
+    pub fn delegated_from<'x>(
+        mut self,
+        value: impl IntoIterator<Item = &'x CalAddress>,
+    ) -> Result<Self, Error> {
+        self.inner.param(parameters::Delegators, value)?;
+        Ok(self)
+    }
+}
+
+/// Writer for a VALARM component
+///
+/// The ACTION and TRIGGER properties, along with the properties that are
+/// required for the action, are written when the component is begun, see
+/// [EventWriter::audio_alarm], [EventWriter::display_alarm] and
+/// [EventWriter::email_alarm].
+pub struct AlarmWriter<'a, W> {
+    inner: ComponentWriter<'a, W, AlarmC>,
+    action: AlarmAction,
+    has_attachment: bool,
+    has_repeat: bool,
+}
+
+impl<'a, W: Write> AlarmWriter<'a, W> {
+    fn new(
+        mut inner: ComponentWriter<'a, W, AlarmC>,
+        action: AlarmAction,
+        trigger: Trigger,
+    ) -> Result<Self, Error> {
+        inner.simple_property(properties::alarm::Action, &action)?;
+
+        let mut p = inner.property(properties::alarm::Trigger)?;
+        trigger.write(&mut p)?;
+        p.end()?;
+
+        Ok(Self {
+            inner,
+            action,
+            has_attachment: false,
+            has_repeat: false,
+        })
+    }
+
+    pub fn property<'x, 'y: 'x, P: AlarmCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: AlarmCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    /// Repeat the alarm `count` more times after it has been triggered, with
+    /// the given delay in between. This writes both the REPEAT and the
+    /// DURATION property, which must occur together, and can only be called
+    /// once.
+    pub fn repeat(
+        &mut self,
+        count: u32,
+        delay: impl AsValueType<value_types::Duration>,
+    ) -> std::fmt::Result {
+        if std::mem::replace(&mut self.has_repeat, true) {
+            return Err(Error);
+        }
+
+        self.simple_property(properties::alarm::RepeatCount, count)?;
+        self.simple_property(properties::date_and_time::DurationProperty, delay)
+    }
+
+    /// Write the ATTACH property. An AUDIO alarm can have at most one, the
+    /// sound to play, while an EMAIL alarm can have any number of
+    /// attachments. A DISPLAY alarm cannot have any.
+    pub fn attach(
+        &mut self,
+        value: impl AsCompositeValueType<
+            <properties::descriptive::Attach as Property>::CompositeValueType,
+        >,
+    ) -> std::fmt::Result {
+        let allowed = match self.action {
+            AlarmAction::Audio => !self.has_attachment,
+            AlarmAction::Display => false,
+            AlarmAction::Email | AlarmAction::Other(_) => true,
+        };
+        if !allowed {
+            return Err(Error);
+        }
+
+        self.simple_property(properties::descriptive::Attach, value)?;
+        self.has_attachment = true;
+        Ok(())
+    }
+
+    pub fn component<'x, 'y: 'x, NC: AlarmCComponent>(
+        &'y mut self,
+        component: NC,
+    ) -> Result<ComponentWriter<'x, W, NC>, Error> {
+        self.inner.component(component)
+    }
+
+    /// Begin a VLOCATION component, giving the location for the PROXIMITY
+    /// trigger. See [AlarmWriter::proximity].
+    pub fn structured_location<'x, 'y: 'x>(
+        &'y mut self,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<LocationWriter<'x, W>, Error> {
+        LocationWriter::new(self.component(LocationC)?, uid)
+    }
+
+    /// Mark the alarm as a snoozed repetition of the alarm with the given
+    /// UID, by writing RELATED-TO with `RELTYPE=SNOOZE`. The snoozed alarm
+    /// typically has an absolute trigger, see [Trigger::at], and the original
+    /// alarm is marked with [AlarmWriter::acknowledged].
+    pub fn snooze_of(&mut self, uid: impl AsValueType<value_types::Text>) -> std::fmt::Result {
+        let mut p = self.property(properties::relationship::RelatedTo)?;
+        p.param(parameters::RelationshipType, RelationshipType::Snooze)?;
+        p.value(uid)?;
+        p.end()
+    }
+
+    pub fn proximity(&mut self, value: Proximity) -> std::fmt::Result {
+        self.simple_property(properties::alarm::Proximity, value)
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(uid, properties::relationship::Uid);
+    simple_property!(acknowledged, properties::alarm::Acknowledged);
+}
+
+/// Reference: [RFC 9073 7.1](https://www.rfc-editor.org/rfc/rfc9073#section-7.1)
+pub struct ParticipantWriter<'a, W> {
+    inner: ComponentWriter<'a, W, ParticipantC>,
+}
+
+impl<'a, W: Write> ParticipantWriter<'a, W> {
+    pub fn new(
+        inner: ComponentWriter<'a, W, ParticipantC>,
+        uid: impl AsValueType<value_types::Text>,
+        participant_type: ParticipantType,
+    ) -> Result<Self, Error> {
+        let mut new = Self { inner };
+        new.simple_property(properties::relationship::Uid, uid)?;
+        new.simple_property(properties::rfc9073::ParticipantType, participant_type)?;
+        Ok(new)
+    }
+
+    pub fn property<'x, 'y: 'x, P: ParticipantCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: ParticipantCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    pub fn component<'x, 'y: 'x, NC: ParticipantCComponent>(
+        &'y mut self,
+        component: NC,
+    ) -> Result<ComponentWriter<'x, W, NC>, Error> {
+        self.inner.component(component)
+    }
+
+    /// Begin a VLOCATION component, where the participant is located
+    pub fn structured_location<'x, 'y: 'x>(
+        &'y mut self,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<LocationWriter<'x, W>, Error> {
+        LocationWriter::new(self.component(LocationC)?, uid)
+    }
+
+    /// Begin a VRESOURCE component, for a resource used by the participant
+    pub fn structured_resource<'x, 'y: 'x>(
+        &'y mut self,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<ResourceWriter<'x, W>, Error> {
+        ResourceWriter::new(self.component(ResourceC)?, uid)
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(calendar_address, properties::rfc9073::CalendarAddress);
+    simple_property!(dtstamp, properties::change_management::DateTimeStamp);
+    simple_property!(created, properties::change_management::DateTimeCreated);
+    simple_property!(last_modified, properties::change_management::LastModified);
+    simple_property!(summary, properties::descriptive::Summary);
+    simple_property!(description, properties::descriptive::Description);
+    simple_property!(styled_description, properties::rfc9073::StyledDescription);
+    simple_property!(structured_data, properties::rfc9073::StructuredData);
+    simple_property!(attach, properties::descriptive::Attach);
+    simple_property!(categories, properties::descriptive::Categories);
+    simple_property!(comment, properties::descriptive::Comment);
+    simple_property!(contact, properties::relationship::Contact);
+    simple_property!(geo, properties::descriptive::Geo);
+    simple_property!(location, properties::descriptive::Location);
+    simple_property!(url, properties::relationship::Url);
+
+    /// Write the SEQUENCE property. Revision numbers start at 0, which is
+    /// the default, so 0 is not written.
+    pub fn sequence(&mut self, value: u32) -> std::fmt::Result {
+        if value != 0 {
+            self.simple_property(properties::change_management::SequenceNumber, value)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reference: [RFC 9073 7.2](https://www.rfc-editor.org/rfc/rfc9073#section-7.2)
+pub struct LocationWriter<'a, W> {
+    inner: ComponentWriter<'a, W, LocationC>,
+}
+
+impl<'a, W: Write> LocationWriter<'a, W> {
+    pub fn new(
+        inner: ComponentWriter<'a, W, LocationC>,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<Self, Error> {
+        let mut new = Self { inner };
+        new.simple_property(properties::relationship::Uid, uid)?;
+        Ok(new)
+    }
+
+    pub fn property<'x, 'y: 'x, P: LocationCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: LocationCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(name, properties::rfc7986::Name);
+    simple_property!(description, properties::descriptive::Description);
+    simple_property!(geo, properties::descriptive::Geo);
+    simple_property!(location_type, properties::rfc9073::LocationType);
+    simple_property!(structured_data, properties::rfc9073::StructuredData);
+}
+
+/// Reference: [RFC 9073 7.3](https://www.rfc-editor.org/rfc/rfc9073#section-7.3)
+pub struct ResourceWriter<'a, W> {
+    inner: ComponentWriter<'a, W, ResourceC>,
+}
+
+impl<'a, W: Write> ResourceWriter<'a, W> {
+    pub fn new(
+        inner: ComponentWriter<'a, W, ResourceC>,
+        uid: impl AsValueType<value_types::Text>,
+    ) -> Result<Self, Error> {
+        let mut new = Self { inner };
+        new.simple_property(properties::relationship::Uid, uid)?;
+        Ok(new)
+    }
+
+    pub fn property<'x, 'y: 'x, P: ResourceCProperty>(
+        &'y mut self,
+        property: P,
+    ) -> Result<PropertyWriter<'x, W, P>, Error> {
+        self.inner.property(property)
+    }
+
+    pub fn simple_property<P: ResourceCProperty>(
+        &mut self,
+        property: P,
+        value: impl AsCompositeValueType<P::CompositeValueType>,
+    ) -> std::fmt::Result {
+        self.inner.simple_property(property, value)
+    }
+
+    /// A property with a name that is only known at runtime, such as a
+    /// non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, Error> {
+        self.inner.dynamic_property(name)
+    }
+
+    pub fn end(self) -> Result<(), Error> {
+        self.inner.end()
+    }
+
+    /* == Standard simple property functions == */
+    simple_property!(name, properties::rfc7986::Name);
+    simple_property!(description, properties::descriptive::Description);
+    simple_property!(geo, properties::descriptive::Geo);
+    simple_property!(structured_data, properties::rfc9073::StructuredData);
+
+    /* == Custom simple property functions == */
+
+    pub fn resource_type(&mut self, value: ResourceType) -> std::fmt::Result {
+        self.simple_property(properties::rfc9073::ResourceType, value)
+    }
+}
@@ -0,0 +1,643 @@
+#![cfg(feature = "jiff02")]
+
+//! Generate VTIMEZONE components from the time zone database.
+//!
+//! The generated definitions cover the range of time that is actually used,
+//! as given by [TimeZoneReferences][crate::write::TimeZoneReferences], and the
+//! rules that are in effect at the end of it. Observances that follow a
+//! regular yearly pattern, such as "the last Sunday of March at 02:00", are
+//! compressed into a single observance with an RRULE. When the pattern is
+//! still in effect at the end of the range, the RRULE is left open-ended, so
+//! the definition also covers recurring events that extend past the range.
+//! Other observances are given explicitly with RDATE.
+//!
+//! The time zone database is looked up with `jiff::tz::db()`, which depends on
+//! the features enabled for jiff. Enable `jiff02-tzdb-bundle` to bundle the
+//! database, or enable the `std` or `tzdb-*` features of jiff directly.
+//! TZIDs written by other time zone libraries, such as `chrono-tz`, are looked
+//! up in the same database.
+
+use std::fmt::Write;
+
+use jiff::{
+    civil,
+    tz::{Offset, TimeZone, TimeZoneTransition},
+    Timestamp,
+};
+
+use crate::{
+    structure::icalstream::{
+        components::{DaylightC, StandardC, TimeZoneC},
+        properties::{
+            date_and_time::{DateTimeStart, RecurrenceDateTimes},
+            recurrence::RecurrenceRule,
+            time_zone::{TimeZoneIdentifier, TimeZoneName, TimeZoneOffsetFrom, TimeZoneOffsetTo},
+        },
+    },
+    write::{
+        value_types::{jiff02::UtcForm, Frequency, Recur, Weekday},
+        ComponentWriter, Writer,
+    },
+};
+
+const LOOKAHEAD_DAYS: i64 = 800;
+
+/// A STANDARD or DAYLIGHT sub-component of a [TimeZoneDefinition]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Observance {
+    /// `true` for DAYLIGHT, `false` for STANDARD
+    pub daylight: bool,
+
+    /// The onset of the observance, as local time in the `offset_from` offset
+    pub dtstart: civil::DateTime,
+
+    pub offset_from: Offset,
+    pub offset_to: Offset,
+    pub name: String,
+    pub rrule: Option<Recur>,
+
+    /// Further onsets of the observance, as local time in the `offset_from`
+    /// offset
+    pub rdates: Vec<civil::DateTime>,
+}
+
+impl Observance {
+    fn write<W: Write>(&self, w: &mut Writer<W>) -> std::fmt::Result {
+        w.simple_property(DateTimeStart, self.dtstart)?;
+        if let Some(rrule) = &self.rrule {
+            w.simple_property(RecurrenceRule, rrule)?;
+        }
+        if !self.rdates.is_empty() {
+            w.simple_property(RecurrenceDateTimes, self.rdates.iter().copied())?;
+        }
+        w.simple_property(TimeZoneOffsetFrom, self.offset_from)?;
+        w.simple_property(TimeZoneOffsetTo, self.offset_to)?;
+        if !self.name.is_empty() {
+            w.simple_property(TimeZoneName, &self.name)?;
+        }
+        Ok(())
+    }
+}
+
+/// The contents of a VTIMEZONE component, covering a limited range of time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZoneDefinition {
+    tzid: String,
+    observances: Vec<Observance>,
+}
+
+impl TimeZoneDefinition {
+    /// Look up `tzid` in the time zone database of jiff, and generate a
+    /// definition that is valid between `start` and `end`
+    pub fn from_tzdb(tzid: &str, start: Timestamp, end: Timestamp) -> Result<Self, jiff::Error> {
+        let tz = jiff::tz::db().get(tzid)?;
+        Ok(Self::new(tzid, &tz, start, end))
+    }
+
+    /// Generate a definition of `tz`, identified by `tzid`, that is valid
+    /// between `start` and `end`
+    pub fn new(tzid: impl Into<String>, tz: &TimeZone, start: Timestamp, end: Timestamp) -> Self {
+        // Look a little more than two years past the end, so every yearly
+        // transition is seen at least twice, and the rules that are in effect
+        // at the end can be given as open-ended RRULEs
+        let end = end
+            .saturating_add(jiff::SignedDuration::from_hours(LOOKAHEAD_DAYS * 24))
+            .unwrap();
+
+        let transitions: Vec<Transition> = tz
+            .preceding(
+                start
+                    .saturating_add(jiff::SignedDuration::from_secs(1))
+                    .unwrap(),
+            )
+            .next()
+            .into_iter()
+            .chain(tz.following(start).take_while(|t| t.timestamp() <= end))
+            .map(|t| Transition::new(tz, &t))
+            .collect();
+
+        // Group the transitions by what they transition into
+        let mut groups: Vec<Vec<Transition>> = vec![];
+        for transition in transitions {
+            match groups.iter_mut().find(|g| g[0].same_kind(&transition)) {
+                Some(group) => group.push(transition),
+                None => groups.push(vec![transition]),
+            }
+        }
+
+        let mut observances: Vec<Observance> = groups
+            .into_iter()
+            .flat_map(|group| Self::observances_for_group(tz, group))
+            .collect();
+
+        if observances.is_empty() {
+            // There are no transitions at all, so describe the offset that
+            // has always been in effect
+            let info = tz.to_offset_info(start);
+            let epoch = civil::date(1970, 1, 1).at(0, 0, 0, 0);
+            let dtstart = epoch.min(info.offset().to_datetime(start).start_of_day());
+            observances.push(Observance {
+                daylight: info.dst().is_dst(),
+                dtstart,
+                offset_from: info.offset(),
+                offset_to: info.offset(),
+                name: info.abbreviation().to_owned(),
+                rrule: None,
+                rdates: vec![],
+            });
+        }
+
+        observances.sort_by_key(|x| x.dtstart);
+
+        Self {
+            tzid: tzid.into(),
+            observances,
+        }
+    }
+
+    /// Split transitions of the same kind into yearly recurring runs and
+    /// remaining one-off transitions
+    fn observances_for_group(tz: &TimeZone, group: Vec<Transition>) -> Vec<Observance> {
+        let mut observances = vec![];
+        let mut one_offs: Vec<&Transition> = vec![];
+
+        let mut i = 0;
+        while i < group.len() {
+            let mut ordinals = group[i].ordinals();
+            let mut j = i + 1;
+            while j < group.len() && group[j - 1].precedes_yearly(&group[j]) {
+                let next = ordinals.intersection(group[j].ordinals());
+                if next.is_empty() {
+                    break;
+                }
+                ordinals = next;
+                j += 1;
+            }
+
+            let run = &group[i..j];
+            if run.len() < 2 {
+                one_offs.push(&group[i]);
+                i = j;
+                continue;
+            }
+
+            let first = &run[0];
+            let last = &run[run.len() - 1];
+            let ordinal = ordinals.preferred();
+
+            let mut rrule = Recur::new(Frequency::Yearly)
+                .by_month([first.onset.month() as u8])
+                .by_day([weekday(first.onset.weekday()).nth(ordinal)]);
+
+            // If the pattern keeps going after the end of the covered range,
+            // leave the rule open-ended. Otherwise, bound it by the last
+            // transition in the run.
+            let continues = j == group.len()
+                && last.next_of_same_kind(tz).is_some_and(|next| {
+                    last.precedes_yearly(&next) && next.ordinals().has(ordinal)
+                });
+            if !continues {
+                rrule = rrule.until(UtcForm::from_timestamp(last.timestamp));
+            }
+
+            observances.push(first.observance(Some(rrule), vec![]));
+            i = j;
+        }
+
+        if let Some((first, rest)) = one_offs.split_first() {
+            observances.push(first.observance(None, rest.iter().map(|x| x.onset).collect()));
+        }
+
+        observances
+    }
+
+    pub fn tzid(&self) -> &str {
+        &self.tzid
+    }
+
+    pub fn observances(&self) -> &[Observance] {
+        &self.observances
+    }
+
+    /// Write the properties and sub-components of the VTIMEZONE component
+    pub fn write_into<W: Write>(
+        &self,
+        w: &mut ComponentWriter<'_, W, TimeZoneC>,
+    ) -> std::fmt::Result {
+        w.simple_property(TimeZoneIdentifier, &self.tzid)?;
+
+        for observance in &self.observances {
+            if observance.daylight {
+                let mut c = w.component(DaylightC)?;
+                observance.write(&mut c)?;
+                c.end()?;
+            } else {
+                let mut c = w.component(StandardC)?;
+                observance.write(&mut c)?;
+                c.end()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Transition {
+    timestamp: Timestamp,
+    onset: civil::DateTime,
+    offset_from: Offset,
+    offset_to: Offset,
+    daylight: bool,
+    name: String,
+}
+
+impl Transition {
+    fn new(tz: &TimeZone, t: &TimeZoneTransition) -> Self {
+        let timestamp = t.timestamp();
+        let offset_from = tz.to_offset(
+            timestamp
+                .saturating_sub(jiff::SignedDuration::from_secs(1))
+                .unwrap(),
+        );
+
+        Self {
+            timestamp,
+            onset: offset_from.to_datetime(timestamp),
+            offset_from,
+            offset_to: t.offset(),
+            daylight: t.dst().is_dst(),
+            name: t.abbreviation().to_owned(),
+        }
+    }
+
+    fn same_kind(&self, other: &Self) -> bool {
+        self.daylight == other.daylight
+            && self.offset_from == other.offset_from
+            && self.offset_to == other.offset_to
+            && self.name == other.name
+    }
+
+    /// The next transition of the same kind after `self`, if it happens
+    /// within about a year
+    fn next_of_same_kind(&self, tz: &TimeZone) -> Option<Self> {
+        let limit = self.timestamp.as_second() + 400 * 24 * 60 * 60;
+        tz.following(self.timestamp)
+            .take_while(|t| t.timestamp().as_second() < limit)
+            .map(|t| Transition::new(tz, &t))
+            .find(|t| self.same_kind(t))
+    }
+
+    /// Check if `next` happens the year after `self`, on the same weekday of
+    /// the same month at the same time
+    fn precedes_yearly(&self, next: &Self) -> bool {
+        next.onset.year() == self.onset.year() + 1
+            && next.onset.month() == self.onset.month()
+            && next.onset.weekday() == self.onset.weekday()
+            && next.onset.time() == self.onset.time()
+    }
+
+    /// Which occurrences of the weekday within the month the onset is, as
+    /// counted from the start and from the end of the month
+    fn ordinals(&self) -> Ordinals {
+        let date = self.onset.date();
+        let from_start = (date.day() - 1) / 7 + 1;
+        let from_end = -((date.days_in_month() - date.day()) / 7 + 1);
+        Ordinals {
+            from_start: Some(from_start),
+            from_end: Some(from_end),
+        }
+    }
+
+    fn observance(&self, rrule: Option<Recur>, rdates: Vec<civil::DateTime>) -> Observance {
+        Observance {
+            daylight: self.daylight,
+            dtstart: self.onset,
+            offset_from: self.offset_from,
+            offset_to: self.offset_to,
+            name: self.name.clone(),
+            rrule,
+            rdates,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Ordinals {
+    from_start: Option<i8>,
+    from_end: Option<i8>,
+}
+
+impl Ordinals {
+    fn intersection(self, other: Self) -> Self {
+        Self {
+            from_start: self.from_start.filter(|&x| other.from_start == Some(x)),
+            from_end: self.from_end.filter(|&x| other.from_end == Some(x)),
+        }
+    }
+
+    fn is_empty(self) -> bool {
+        self.from_start.is_none() && self.from_end.is_none()
+    }
+
+    fn has(self, ordinal: i8) -> bool {
+        self.from_start == Some(ordinal) || self.from_end == Some(ordinal)
+    }
+
+    /// Prefer counting from the start of the month, except for rules of the
+    /// form "last Sunday", which are not captured by counting from the start.
+    /// Counting from the end otherwise gives a different day in years where
+    /// the month has a different number of the weekday.
+    fn preferred(self) -> i8 {
+        match (self.from_start, self.from_end) {
+            (_, Some(-1)) => -1,
+            (Some(from_start), _) => from_start,
+            (None, from_end) => from_end.unwrap(),
+        }
+    }
+}
+
+fn weekday(weekday: civil::Weekday) -> Weekday {
+    match weekday {
+        civil::Weekday::Monday => Weekday::Monday,
+        civil::Weekday::Tuesday => Weekday::Tuesday,
+        civil::Weekday::Wednesday => Weekday::Wednesday,
+        civil::Weekday::Thursday => Weekday::Thursday,
+        civil::Weekday::Friday => Weekday::Friday,
+        civil::Weekday::Saturday => Weekday::Saturday,
+        civil::Weekday::Sunday => Weekday::Sunday,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::write::icalstream::typed_writers::ICalStreamWriter;
+
+    use super::*;
+
+    fn write(definition: &TimeZoneDefinition) -> Result<String, std::fmt::Error> {
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.component(crate::structure::icalstream::components::ICalObject)?;
+        let mut tz = ico.component(TimeZoneC)?;
+        definition.write_into(&mut tz)?;
+        tz.end()?;
+        ico.end()?;
+
+        Ok(buf
+            .strip_prefix("BEGIN:VCALENDAR\r\n")
+            .and_then(|x| x.strip_suffix("END:VCALENDAR\r\n"))
+            .unwrap()
+            .to_owned())
+    }
+
+    fn timestamp(s: &str) -> Timestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn yearly_rules() -> std::fmt::Result {
+        let definition = TimeZoneDefinition::from_tzdb(
+            "Europe/Oslo",
+            timestamp("2023-01-01T00:00:00Z"),
+            timestamp("2025-12-31T00:00:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            write(&definition)?,
+            "BEGIN:VTIMEZONE\r\n\
+            TZID:Europe/Oslo\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20221030T030000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10\r\n\
+            TZOFFSETFROM:+0200\r\n\
+            TZOFFSETTO:+0100\r\n\
+            TZNAME:CET\r\n\
+            END:STANDARD\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20230326T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3\r\n\
+            TZOFFSETFROM:+0100\r\n\
+            TZOFFSETTO:+0200\r\n\
+            TZNAME:CEST\r\n\
+            END:DAYLIGHT\r\n\
+            END:VTIMEZONE\r\n"
+        );
+
+        Ok(())
+    }
+
+    /// Expand the observances of `definition` between `from` and `to`, for
+    /// the yearly BYMONTH/BYDAY rules that are generated
+    fn expand(definition: &TimeZoneDefinition, from: i16, to: i16) -> Vec<Timestamp> {
+        use crate::{structure::value_types::RecurrenceRule, write::value_types::AsValueType};
+
+        let mut onsets = vec![];
+        for observance in definition.observances() {
+            let mut local = vec![observance.dtstart];
+            local.extend(observance.rdates.iter().copied());
+
+            if let Some(rrule) = &observance.rrule {
+                let mut formatted = String::new();
+                AsValueType::<RecurrenceRule>::fmt(rrule, &mut formatted).unwrap();
+                assert!(!formatted.contains("UNTIL"), "{formatted}");
+
+                let part = |name: &str| {
+                    formatted
+                        .split(';')
+                        .find_map(|x| x.strip_prefix(name))
+                        .unwrap()
+                        .to_owned()
+                };
+                let month: i8 = part("BYMONTH=").parse().unwrap();
+                let by_day = part("BYDAY=");
+                let nth: i32 = by_day.strip_suffix("SU").unwrap().parse().unwrap();
+
+                for year in observance.dtstart.year() + 1..=to {
+                    let first = civil::date(year, month, 1);
+                    let date = first.nth_weekday_of_month(nth as i8, civil::Weekday::Sunday);
+                    local.push(date.unwrap().to_datetime(observance.dtstart.time()));
+                }
+            }
+
+            onsets.extend(
+                local
+                    .into_iter()
+                    .filter(|x| (from..=to).contains(&x.year()))
+                    .map(|x| observance.offset_from.to_timestamp(x).unwrap()),
+            );
+        }
+        onsets.sort();
+        onsets
+    }
+
+    #[test]
+    fn rules_match_tzdb() {
+        for tzid in ["America/New_York", "Europe/Oslo", "Australia/Sydney"] {
+            let tz = jiff::tz::db().get(tzid).unwrap();
+            let definition = TimeZoneDefinition::new(
+                tzid,
+                &tz,
+                timestamp("2024-01-01T00:00:00Z"),
+                timestamp("2025-06-30T00:00:00Z"),
+            );
+
+            let expected: Vec<Timestamp> = tz
+                .following(timestamp("2025-01-01T00:00:00Z"))
+                .take_while(|t| t.timestamp() < timestamp("2034-01-01T00:00:00Z"))
+                .map(|t| t.timestamp())
+                .collect();
+
+            assert_eq!(expand(&definition, 2025, 2033), expected, "{tzid}");
+        }
+    }
+
+    #[test]
+    fn recurring_past_the_range() -> std::fmt::Result {
+        // Referencing a single point in time in the summer still gives a
+        // definition of both observances, for recurring events
+        let definition = TimeZoneDefinition::from_tzdb(
+            "America/New_York",
+            timestamp("2024-06-26T13:00:00Z"),
+            timestamp("2024-06-26T13:00:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            write(&definition)?,
+            "BEGIN:VTIMEZONE\r\n\
+            TZID:America/New_York\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20240310T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=2SU;BYMONTH=3\r\n\
+            TZOFFSETFROM:-0500\r\n\
+            TZOFFSETTO:-0400\r\n\
+            TZNAME:EDT\r\n\
+            END:DAYLIGHT\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20241103T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=1SU;BYMONTH=11\r\n\
+            TZOFFSETFROM:-0400\r\n\
+            TZOFFSETTO:-0500\r\n\
+            TZNAME:EST\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn changing_rules() -> std::fmt::Result {
+        // The US changed its DST rules in 2007
+        let definition = TimeZoneDefinition::from_tzdb(
+            "America/New_York",
+            timestamp("2005-01-01T00:00:00Z"),
+            timestamp("2008-12-31T00:00:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            write(&definition)?,
+            "BEGIN:VTIMEZONE\r\n\
+            TZID:America/New_York\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20041031T020000\r\n\
+            RRULE:FREQ=YEARLY;UNTIL=20061029T060000Z;BYDAY=-1SU;BYMONTH=10\r\n\
+            TZOFFSETFROM:-0400\r\n\
+            TZOFFSETTO:-0500\r\n\
+            TZNAME:EST\r\n\
+            END:STANDARD\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20050403T020000\r\n\
+            RRULE:FREQ=YEARLY;UNTIL=20060402T070000Z;BYDAY=1SU;BYMONTH=4\r\n\
+            TZOFFSETFROM:-0500\r\n\
+            TZOFFSETTO:-0400\r\n\
+            TZNAME:EDT\r\n\
+            END:DAYLIGHT\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20070311T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=2SU;BYMONTH=3\r\n\
+            TZOFFSETFROM:-0500\r\n\
+            TZOFFSETTO:-0400\r\n\
+            TZNAME:EDT\r\n\
+            END:DAYLIGHT\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20071104T020000\r\n\
+            RRULE:FREQ=YEARLY;BYDAY=1SU;BYMONTH=11\r\n\
+            TZOFFSETFROM:-0400\r\n\
+            TZOFFSETTO:-0500\r\n\
+            TZNAME:EST\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn one_off_transitions() -> std::fmt::Result {
+        // Moscow moved to permanent DST in 2011, and then to permanent
+        // standard time in 2014
+        let definition = TimeZoneDefinition::from_tzdb(
+            "Europe/Moscow",
+            timestamp("2010-06-01T00:00:00Z"),
+            timestamp("2015-01-01T00:00:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            write(&definition)?,
+            "BEGIN:VTIMEZONE\r\n\
+            TZID:Europe/Moscow\r\n\
+            BEGIN:DAYLIGHT\r\n\
+            DTSTART:20100328T020000\r\n\
+            TZOFFSETFROM:+0300\r\n\
+            TZOFFSETTO:+0400\r\n\
+            TZNAME:MSD\r\n\
+            END:DAYLIGHT\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20101031T030000\r\n\
+            RDATE:20141026T020000\r\n\
+            TZOFFSETFROM:+0400\r\n\
+            TZOFFSETTO:+0300\r\n\
+            TZNAME:MSK\r\n\
+            END:STANDARD\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:20110327T020000\r\n\
+            TZOFFSETFROM:+0300\r\n\
+            TZOFFSETTO:+0400\r\n\
+            TZNAME:MSK\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_transitions() -> std::fmt::Result {
+        let definition = TimeZoneDefinition::from_tzdb(
+            "UTC",
+            timestamp("2024-01-01T00:00:00Z"),
+            timestamp("2024-12-31T00:00:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            write(&definition)?,
+            "BEGIN:VTIMEZONE\r\n\
+            TZID:UTC\r\n\
+            BEGIN:STANDARD\r\n\
+            DTSTART:19700101T000000\r\n\
+            TZOFFSETFROM:+0000\r\n\
+            TZOFFSETTO:+0000\r\n\
+            TZNAME:UTC\r\n\
+            END:STANDARD\r\n\
+            END:VTIMEZONE\r\n"
+        );
+
+        Ok(())
+    }
+}
@@ -13,7 +13,9 @@ mod writer;
 pub use content_line::ContentLine;
 pub use folding_writer::FoldingWriter;
 pub use line_stream::LineStream;
-pub use validating_writers::{NameWriter, ParamtextWriter, QuotedStringWriter};
+pub use validating_writers::{
+    DynamicName, NameError, NameWriter, ParamtextWriter, QuotedStringWriter,
+};
 pub use writer::*;
 
 #[cfg(test)]
@@ -42,6 +44,50 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn structured_values() -> std::fmt::Result {
+        use crate::structure::icalstream::{
+            components::{EventC, ICalObject},
+            properties::{
+                calendar::{ProdId, Version},
+                descriptive::Geo,
+                misc::RequestStatus,
+            },
+        };
+
+        let mut buf = String::new();
+        let mut ics = Writer::with_fmt(&mut buf);
+
+        let mut ico = ics.component(ICalObject)?;
+        ico.simple_property(Version, ("2.0", "2.0"))?;
+        ico.simple_property(ProdId, "-//test//")?;
+
+        let mut ev = ico.component(EventC)?;
+        ev.simple_property(Geo, (37.386013, -122.082932))?;
+        ev.simple_property(RequestStatus, ("2.0", "Success"))?;
+        ev.simple_property(
+            RequestStatus,
+            ("3.1", "Invalid property value", "DTSTART:96-Apr-01"),
+        )?;
+        ev.end()?;
+
+        ico.end()?;
+
+        assert_eq!(
+            &buf,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0;2.0\r\n\
+            PRODID:-//test//\r\n\
+            BEGIN:VEVENT\r\n\
+            GEO:37.386013;-122.082932\r\n\
+            REQUEST-STATUS:2.0;Success\r\n\
+            REQUEST-STATUS:3.1;Invalid property value;DTSTART:96-Apr-01\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+        Ok(())
+    }
+
     #[cfg(feature = "chrono04")]
     #[test]
     fn writer_event_chrono04() -> std::fmt::Result {
@@ -1,6 +1,13 @@
-use crate::structure::parameter_value_items::Name;
+use crate::structure::parameter_value_items::{self, Name, ParamText, QuotedString};
 
-use super::AsParamValueItem;
+use super::{
+    value_types::{
+        AlarmTriggerRelationship, CalAddress, CalendarUserType, ConferenceFeature, FreeBusyType,
+        ImageDisplay, InlineEncoding, LanguageTag, MediaType, ParticipationRole,
+        ParticipationStatus, RecurrenceIdentifierRange, RelationshipType, Uri,
+    },
+    AsParamValueItem,
+};
 
 impl<T: std::fmt::Display> AsParamValueItem<Name> for T {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
@@ -8,3 +15,106 @@ impl<T: std::fmt::Display> AsParamValueItem<Name> for T {
         write!(w, "{}", self)
     }
 }
+
+impl<T: std::fmt::Display> AsParamValueItem<ParamText> for T {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{}", self)
+    }
+}
+
+impl<T: std::fmt::Display> AsParamValueItem<QuotedString> for T {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{}", self)
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::Uri> for Uri {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::Uri> for &Uri {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::CalAddress> for CalAddress {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::CalAddress> for &CalAddress {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::MediaType> for MediaType {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::MediaType> for &MediaType {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::LanguageTag> for LanguageTag {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::LanguageTag> for &LanguageTag {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::Boolean> for bool {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(if *self { "TRUE" } else { "FALSE" })
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::PositiveInteger> for std::num::NonZeroU32 {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{}", self)
+    }
+}
+
+macro_rules! enumerated_param_value_item {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl AsParamValueItem<parameter_value_items::$t> for $t {
+                fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                    write!(w, "{}", self)
+                }
+            }
+
+            impl AsParamValueItem<parameter_value_items::$t> for &$t {
+                fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                    write!(w, "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+enumerated_param_value_item!(
+    FreeBusyType,
+    InlineEncoding,
+    AlarmTriggerRelationship,
+    CalendarUserType,
+    ParticipationStatus,
+    RecurrenceIdentifierRange,
+    RelationshipType,
+    ParticipationRole,
+    ImageDisplay,
+    ConferenceFeature,
+);
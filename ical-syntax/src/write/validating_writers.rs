@@ -16,8 +16,9 @@ use std::fmt::Write;
 /// ```
 ///
 /// This writer will only validate that the written text conforms to the
-/// `iana-token` grammar. In practice, there is a lot of violation of the
-/// `x-name` grammar anyway, except for the leading `X-`.
+/// `iana-token` grammar, since the text may be written in several pieces.
+/// Use [`DynamicName`] to validate a complete name, including the `x-name`
+/// grammar.
 ///
 /// Writing text containing forbidden characters will yield `Result::Err`.
 pub struct NameWriter<W> {
@@ -42,6 +43,148 @@ impl<W: Write> NameWriter<W> {
     }
 }
 
+/// Error for names that do not conform to the `name` grammar, see
+/// [`DynamicName`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameError {
+    /// The name, or the part of an `x-name` following the `X-` and the
+    /// vendor ID, is empty
+    Empty,
+
+    /// The name contains characters other than ALPHA, DIGIT and `-`
+    InvalidCharacter,
+
+    /// The name was expected to be an `x-name`, but does not start with `X-`
+    MissingXPrefix,
+
+    /// The vendor ID is shorter than three characters, or contains characters
+    /// other than ALPHA and DIGIT
+    InvalidVendorId,
+}
+
+impl std::fmt::Display for NameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NameError::Empty => write!(f, "empty name"),
+            NameError::InvalidCharacter => {
+                write!(f, "names may only contain letters, digits and '-'")
+            }
+            NameError::MissingXPrefix => write!(f, "non-standard names must start with 'X-'"),
+            NameError::InvalidVendorId => {
+                write!(f, "vendor IDs must be at least three letters or digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NameError {}
+
+/// A validated name for properties, parameters and components that are only
+/// known at runtime, such as non-standard properties from configuration:
+///
+/// ```abnf
+/// name          = iana-token / x-name
+///
+/// iana-token    = 1*(ALPHA / DIGIT / "-")
+/// ; iCalendar identifier registered with IANA
+///
+/// x-name        = "X-" [vendorid "-"] 1*(ALPHA / DIGIT / "-")
+/// ; Reserved for experimental use.
+///
+/// vendorid      = 3*(ALPHA / DIGIT)
+/// ; Vendor identification
+/// ```
+///
+/// Since the vendor ID is optional, and the remainder may contain `-`, any
+/// `x-name` also matches without a vendor ID. The vendor ID is therefore
+/// only validated when it is given separately, with
+/// [`DynamicName::x_vendor`].
+///
+/// ```
+/// use ical_syntax::write::DynamicName;
+///
+/// let calname = DynamicName::new("X-WR-CALNAME").unwrap();
+/// let busy = DynamicName::x_vendor("MICROSOFT", "CDO-BUSYSTATUS").unwrap();
+/// assert_eq!(busy.as_str(), "X-MICROSOFT-CDO-BUSYSTATUS");
+///
+/// assert!(DynamicName::x_vendor("WR", "CALNAME").is_err());
+/// assert!(DynamicName::new("X-").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynamicName {
+    name: String,
+}
+
+fn is_name_char(x: u8) -> bool {
+    x.is_ascii_alphanumeric() || x == b'-'
+}
+
+fn has_x_prefix(name: &str) -> bool {
+    name.get(..2)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("X-"))
+}
+
+impl DynamicName {
+    /// An `iana-token` or, when starting with `X-`, an `x-name`
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let name = name.into();
+
+        let rest = if has_x_prefix(&name) {
+            &name[2..]
+        } else {
+            &name[..]
+        };
+        if rest.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if !rest.bytes().all(is_name_char) {
+            return Err(NameError::InvalidCharacter);
+        }
+
+        Ok(Self { name })
+    }
+
+    /// An `x-name`, which must start with `X-`
+    pub fn x_name(name: impl Into<String>) -> Result<Self, NameError> {
+        let name = name.into();
+        if !has_x_prefix(&name) {
+            return Err(NameError::MissingXPrefix);
+        }
+
+        Self::new(name)
+    }
+
+    /// The `x-name` `X-<vendorid>-<name>`
+    pub fn x_vendor(vendorid: &str, name: &str) -> Result<Self, NameError> {
+        if vendorid.len() < 3 || !vendorid.bytes().all(|x| x.is_ascii_alphanumeric()) {
+            return Err(NameError::InvalidVendorId);
+        }
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+
+        Self::new(format!("X-{vendorid}-{name}"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Display for DynamicName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl std::str::FromStr for DynamicName {
+    type Err = NameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
 /// A writer for the quoted-string grammar:
 ///
 /// ```abnf
@@ -211,4 +354,62 @@ mod test {
         assert!(write!(w, ";").is_err());
         assert!(write!(w, ",").is_err());
     }
+
+    #[test]
+    fn dynamic_name() {
+        assert!(DynamicName::new("X-WR-CALNAME").is_ok());
+        assert!(DynamicName::new("x-wr-calname").is_ok());
+        assert!(DynamicName::new("VEVENT").is_ok());
+        assert!(DynamicName::x_name("X-APPLE-STRUCTURED-LOCATION").is_ok());
+
+        assert_eq!(DynamicName::new(""), Err(NameError::Empty));
+        assert_eq!(DynamicName::new("X-"), Err(NameError::Empty));
+        assert_eq!(
+            DynamicName::new("X-WR CALNAME"),
+            Err(NameError::InvalidCharacter)
+        );
+        assert_eq!(
+            DynamicName::new("X-WR:CALNAME"),
+            Err(NameError::InvalidCharacter)
+        );
+        assert_eq!(
+            DynamicName::new("X-\u{e6}\u{f8}\u{e5}"),
+            Err(NameError::InvalidCharacter)
+        );
+        assert_eq!(
+            DynamicName::x_name("WR-CALNAME"),
+            Err(NameError::MissingXPrefix)
+        );
+        assert_eq!(
+            DynamicName::new("\u{20ac}x"),
+            Err(NameError::InvalidCharacter)
+        );
+        assert_eq!(
+            DynamicName::x_name("\u{20ac}x"),
+            Err(NameError::MissingXPrefix)
+        );
+    }
+
+    #[test]
+    fn dynamic_name_vendor() {
+        assert_eq!(
+            DynamicName::x_vendor("MICROSOFT", "CDO-BUSYSTATUS")
+                .unwrap()
+                .as_str(),
+            "X-MICROSOFT-CDO-BUSYSTATUS"
+        );
+        assert_eq!(DynamicName::x_vendor("ABC", ""), Err(NameError::Empty));
+        assert_eq!(
+            DynamicName::x_vendor("ABC", "A;B"),
+            Err(NameError::InvalidCharacter)
+        );
+        assert_eq!(
+            DynamicName::x_vendor("WR", "CALNAME"),
+            Err(NameError::InvalidVendorId)
+        );
+        assert_eq!(
+            DynamicName::x_vendor("APP-LE", "LOCATION"),
+            Err(NameError::InvalidVendorId)
+        );
+    }
 }
@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use super::{AsValueType, ToValueType};
+use crate::{
+    structure::{icalstream::parameters::InlineEncoding, value_types::Binary},
+    write::PropertyValueWriter,
+};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Inline binary data for the [Binary] value type, such as an attachment in
+/// the ATTACH property
+///
+/// The data is written with the "BASE64" encoding of [RFC
+/// 4648](https://www.rfc-editor.org/rfc/rfc4648#section-4), and the
+/// `ENCODING=BASE64` parameter is set accordingly.
+///
+/// ```
+/// use ical_syntax::write::value_types::Base64Binary;
+///
+/// let icon = Base64Binary(b"\x00\x00\x01\x00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base64Binary<T>(pub T);
+
+impl<T: AsRef<[u8]>> AsValueType<Binary> for Base64Binary<T> {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        let digit = |x: u32| BASE64_ALPHABET[(x & 0x3f) as usize] as char;
+
+        for chunk in self.0.as_ref().chunks(3) {
+            let bits = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |acc, (i, &x)| acc | (x as u32) << (16 - 8 * i));
+
+            w.write_char(digit(bits >> 18))?;
+            w.write_char(digit(bits >> 12))?;
+            w.write_char(if chunk.len() > 1 {
+                digit(bits >> 6)
+            } else {
+                '='
+            })?;
+            w.write_char(if chunk.len() > 2 { digit(bits) } else { '=' })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_params<W: Write>(&self, w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        w.param(InlineEncoding, super::InlineEncoding::Base64)
+    }
+}
+
+impl<T: AsRef<[u8]>> AsValueType<Binary> for &Base64Binary<T> {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<Binary>::fmt(*self, w)
+    }
+
+    fn write_params<W: Write>(&self, w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        AsValueType::<Binary>::write_params(*self, w)
+    }
+}
+
+impl<T> ToValueType for Base64Binary<T> {
+    type ValueType = Binary;
+}
+
+impl<T> ToValueType for &Base64Binary<T> {
+    type ValueType = Binary;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_case(v: impl AsValueType<Binary>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<Binary>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    #[test]
+    fn base64() {
+        // Test vectors from RFC 4648 section 10
+        test_case(Base64Binary(b""), "");
+        test_case(Base64Binary(b"f"), "Zg==");
+        test_case(Base64Binary(b"fo"), "Zm8=");
+        test_case(Base64Binary(b"foo"), "Zm9v");
+        test_case(Base64Binary(b"foob"), "Zm9vYg==");
+        test_case(Base64Binary(b"fooba"), "Zm9vYmE=");
+        test_case(Base64Binary(b"foobar"), "Zm9vYmFy");
+
+        test_case(Base64Binary(vec![0xfb, 0xff, 0xfe]), "+//+");
+    }
+}
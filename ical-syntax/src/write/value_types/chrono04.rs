@@ -2,9 +2,9 @@
 
 use chrono::{Datelike as _, Timelike as _};
 
-use super::AsValueType;
+use super::{AsValueType, ICalDuration, ICalDurationError, UtcInstant};
 use crate::{
-    structure::value_types::{Date, DateTime, DateTimeUtc, Duration},
+    structure::value_types::{Date, DateTime, DateTimeUtc, Duration, UtcOffset},
     write::value_types::ToValueType,
 };
 
@@ -52,24 +52,40 @@ impl AsValueType<DateTime> for chrono::DateTime<chrono::Utc> {
             self.second()
         )
     }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
 }
 
 impl AsValueType<DateTime> for &chrono::DateTime<chrono::Utc> {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
         AsValueType::<DateTime>::fmt(*self, w)
     }
+
+    fn is_utc(&self) -> bool {
+        AsValueType::<DateTime>::is_utc(*self)
+    }
 }
 
 impl AsValueType<DateTimeUtc> for chrono::DateTime<chrono::Utc> {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
         AsValueType::<DateTime>::fmt(self, w)
     }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
 }
 
 impl AsValueType<DateTimeUtc> for &chrono::DateTime<chrono::Utc> {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
         AsValueType::<DateTime>::fmt(*self, w)
     }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
 }
 
 impl ToValueType for chrono::DateTime<chrono::Utc> {
@@ -80,6 +96,12 @@ impl ToValueType for &chrono::DateTime<chrono::Utc> {
     type ValueType = DateTime;
 }
 
+impl UtcInstant for chrono::DateTime<chrono::Utc> {
+    fn unix_seconds(&self) -> i64 {
+        self.timestamp()
+    }
+}
+
 impl AsValueType<Date> for chrono::NaiveDate {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
         write!(w, "{:04}{:02}{:02}", self.year(), self.month(), self.day())
@@ -100,18 +122,61 @@ impl ToValueType for &chrono::NaiveDate {
     type ValueType = Date;
 }
 
+/// `chrono::TimeDelta` is an exact duration, so it is converted to hours,
+/// minutes and seconds. Fractional seconds are truncated, as they cannot be
+/// represented.
+impl TryFrom<chrono::TimeDelta> for ICalDuration {
+    type Error = ICalDurationError;
+
+    fn try_from(value: chrono::TimeDelta) -> Result<Self, Self::Error> {
+        ICalDuration::from_exact_seconds(value.num_seconds())
+    }
+}
+
+/// Only exact durations can be converted to `chrono::TimeDelta`. Use
+/// `jiff::Span` to keep nominal durations.
+impl TryFrom<ICalDuration> for chrono::TimeDelta {
+    type Error = ICalDurationError;
+
+    fn try_from(value: ICalDuration) -> Result<Self, Self::Error> {
+        chrono::TimeDelta::try_seconds(value.to_exact_seconds()?)
+            .ok_or(ICalDurationError::OutOfRange)
+    }
+}
+
 impl AsValueType<Duration> for chrono::TimeDelta {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
-        write!(w, "{}", self)
+        let duration = ICalDuration::try_from(*self).map_err(|_| std::fmt::Error)?;
+        AsValueType::<Duration>::fmt(&duration, w)
     }
 }
 
 impl AsValueType<Duration> for &chrono::TimeDelta {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
-        write!(w, "{}", self)
+        AsValueType::<Duration>::fmt(*self, w)
     }
 }
 
+impl AsValueType<UtcOffset> for chrono::FixedOffset {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        super::fmt_utc_offset(w, self.local_minus_utc())
+    }
+}
+
+impl AsValueType<UtcOffset> for &chrono::FixedOffset {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<UtcOffset>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for chrono::FixedOffset {
+    type ValueType = UtcOffset;
+}
+
+impl ToValueType for &chrono::FixedOffset {
+    type ValueType = UtcOffset;
+}
+
 #[cfg(test)]
 mod test {
     use crate::structure::ValueType;
@@ -151,8 +216,47 @@ mod test {
 
     #[test]
     fn duration() {
-        test_case::<Duration>(chrono::TimeDelta::hours(1), "PT3600S");
-        test_case::<Duration>(&chrono::TimeDelta::hours(1), "PT3600S");
+        test_case::<Duration>(chrono::TimeDelta::hours(1), "PT1H");
+        test_case::<Duration>(&chrono::TimeDelta::hours(1), "PT1H");
+        test_case::<Duration>(chrono::TimeDelta::zero(), "PT0S");
+        test_case::<Duration>(chrono::TimeDelta::seconds(-90), "-PT1M30S");
+        test_case::<Duration>(chrono::TimeDelta::days(2), "PT48H");
+        test_case::<Duration>(chrono::TimeDelta::milliseconds(1500), "PT1S");
+    }
+
+    #[test]
+    fn ical_duration() {
+        let duration = ICalDuration::try_from(chrono::TimeDelta::seconds(-5405)).unwrap();
+        assert_eq!(
+            duration,
+            -ICalDuration {
+                hours: 1,
+                minutes: 30,
+                seconds: 5,
+                ..ICalDuration::ZERO
+            }
+        );
+        assert_eq!(
+            chrono::TimeDelta::try_from(duration),
+            Ok(chrono::TimeDelta::seconds(-5405))
+        );
+        assert_eq!(
+            chrono::TimeDelta::try_from(ICalDuration::days(1)),
+            Err(ICalDurationError::Nominal)
+        );
+    }
+
+    #[test]
+    fn utc_offset() {
+        let new_york = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        test_case::<UtcOffset>(new_york, "-0500");
+        test_case::<UtcOffset>(&new_york, "-0500");
+        test_case::<UtcOffset>(chrono::FixedOffset::east_opt(3600).unwrap(), "+0100");
+        test_case::<UtcOffset>(chrono::FixedOffset::east_opt(0).unwrap(), "+0000");
+        test_case::<UtcOffset>(
+            chrono::FixedOffset::east_opt(5 * 3600 + 30 * 60 + 15).unwrap(),
+            "+053015",
+        );
     }
 
     #[test]
@@ -172,18 +276,20 @@ mod test {
         let duration = end - start;
 
         test_case::<PeriodOfTime>(
-            PeriodOfTimeBuilder::start(start).end(end),
+            PeriodOfTimeBuilder::start(start).end(end).unwrap(),
             "20240626T120000Z/20240626T130000Z",
         );
 
         test_case::<PeriodOfTime>(
-            &PeriodOfTimeBuilder::start(start).end(end),
+            &PeriodOfTimeBuilder::start(start).end(end).unwrap(),
             "20240626T120000Z/20240626T130000Z",
         );
 
         test_case::<PeriodOfTime>(
-            PeriodOfTimeBuilder::start(start).duration(duration),
-            "20240626T120000Z/PT3600S",
+            PeriodOfTimeBuilder::start(start)
+                .duration(duration)
+                .unwrap(),
+            "20240626T120000Z/PT1H",
         );
     }
 }
@@ -0,0 +1,158 @@
+#![cfg(feature = "chrono-tz010")]
+
+use super::AsValueType;
+use crate::{
+    structure::{icalstream::parameters::TimeZoneIdentifier, value_types::DateTime},
+    write::{value_types::ToValueType, PropertyValueWriter},
+};
+
+/// `chrono::DateTime<chrono_tz::Tz>` corresponds to the _local time with time
+/// zone reference_ form of a DateTime, and the `TZID` parameter is set to the
+/// name of the time zone. This preserves the local wall-clock time across DST
+/// changes, for example for recurring meetings.
+impl AsValueType<DateTime> for chrono::DateTime<chrono_tz::Tz> {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(&self.naive_local(), w)
+    }
+
+    fn write_params<W: std::fmt::Write>(
+        &self,
+        w: &mut PropertyValueWriter<'_, '_, W>,
+    ) -> std::fmt::Result {
+        w.param(TimeZoneIdentifier, self.timezone().name())
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        self.timezone() == other.timezone()
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        Some((self.timezone().name(), self.timestamp()))
+    }
+}
+
+impl AsValueType<DateTime> for &chrono::DateTime<chrono_tz::Tz> {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(*self, w)
+    }
+
+    fn write_params<W: std::fmt::Write>(
+        &self,
+        w: &mut PropertyValueWriter<'_, '_, W>,
+    ) -> std::fmt::Result {
+        AsValueType::<DateTime>::write_params(*self, w)
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        AsValueType::<DateTime>::has_same_params(*self, *other)
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        AsValueType::<DateTime>::time_zone_reference(*self)
+    }
+}
+
+impl ToValueType for chrono::DateTime<chrono_tz::Tz> {
+    type ValueType = DateTime;
+}
+
+impl ToValueType for &chrono::DateTime<chrono_tz::Tz> {
+    type ValueType = DateTime;
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone as _;
+
+    use crate::{
+        structure::{
+            composite_value_types::{Any2, List},
+            value_types::{Date, DateTime},
+            Property,
+        },
+        write::{LineStream, PropertyWriter},
+    };
+
+    struct DateTimeStart;
+    impl Property for DateTimeStart {
+        const NAME: &'static str = "DTSTART";
+
+        type CompositeValueType = Any2<DateTime, Date>;
+    }
+
+    struct RecurrenceDateTimes;
+    impl Property for RecurrenceDateTimes {
+        const NAME: &'static str = "RDATE";
+
+        type CompositeValueType = List<Any2<DateTime, Date>>;
+    }
+
+    #[test]
+    fn datetime_zoned() -> std::fmt::Result {
+        let tz = chrono_tz::Europe::Oslo;
+        let winter = tz.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let summer = tz.with_ymd_and_hms(2024, 6, 26, 9, 0, 0).unwrap();
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        prop.value(winter)?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        prop.value(&summer)?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, RecurrenceDateTimes>::new(&mut line_stream)?;
+        prop.value([winter, summer])?;
+        prop.end()?;
+
+        assert_eq!(
+            &buf,
+            "DTSTART;TZID=Europe/Oslo:20240110T090000\r\n\
+            DTSTART;TZID=Europe/Oslo:20240626T090000\r\n\
+            RDATE;TZID=Europe/Oslo:20240110T090000,20240626T090000\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_zoned_mixed_list() -> std::fmt::Result {
+        let oslo = chrono_tz::Europe::Oslo
+            .with_ymd_and_hms(2024, 1, 10, 9, 0, 0)
+            .unwrap();
+        let new_york = oslo.with_timezone(&chrono_tz::America::New_York);
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, RecurrenceDateTimes>::new(&mut line_stream)?;
+        assert!(prop.value([oslo, new_york]).is_err());
+        prop.discard();
+
+        // Nothing but the name has been written
+        assert_eq!(&buf, "RDATE");
+        Ok(())
+    }
+
+    #[test]
+    fn period_zoned() {
+        use crate::write::value_types::{PeriodError, PeriodOfTimeBuilder};
+
+        let start = chrono_tz::Europe::Oslo
+            .with_ymd_and_hms(2024, 1, 10, 9, 0, 0)
+            .unwrap();
+        let end = start + chrono::TimeDelta::hours(2);
+        assert!(PeriodOfTimeBuilder::start(start).end(end).is_ok());
+
+        // The TZID of the start would apply to the end as well
+        let end = end.with_timezone(&chrono_tz::America::New_York);
+        assert_eq!(
+            PeriodOfTimeBuilder::start(start).end(end).err(),
+            Some(PeriodError::FormMismatch)
+        );
+    }
+}
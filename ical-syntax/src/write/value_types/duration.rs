@@ -0,0 +1,394 @@
+use std::fmt::Write;
+
+use super::{AsValueType, ToValueType};
+use crate::structure::value_types::Duration;
+
+/// Error combining [ICalDuration] values, or converting between
+/// [ICalDuration] and the duration types of other crates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ICalDurationError {
+    /// The duration does not fit in the target type
+    OutOfRange,
+
+    /// The durations have different signs, so they cannot be added. The
+    /// nominal and exact components cannot be balanced against each other.
+    MixedSigns,
+
+    /// The duration has weeks or days, which are nominal durations that
+    /// depend on their position in the calendar, and the target type can
+    /// only represent exact durations
+    Nominal,
+
+    /// The duration has years or months, which cannot be represented in
+    /// iCalendar
+    UnsupportedUnit,
+}
+
+impl std::fmt::Display for ICalDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ICalDurationError::OutOfRange => write!(f, "duration out of range"),
+            ICalDurationError::MixedSigns => {
+                write!(f, "cannot add durations with different signs")
+            }
+            ICalDurationError::Nominal => {
+                write!(
+                    f,
+                    "nominal duration (weeks or days) cannot be converted to an exact duration"
+                )
+            }
+            ICalDurationError::UnsupportedUnit => {
+                write!(
+                    f,
+                    "durations in years or months cannot be represented in iCalendar"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ICalDurationError {}
+
+/// A value of type [Duration], keeping nominal durations (weeks and days)
+/// apart from exact durations (hours, minutes and seconds)
+///
+/// A nominal day follows the wall-clock time, so `P1D` from 12:00 ends at
+/// 12:00 the next day, even across a change to or from daylight saving time.
+/// `PT24H`, on the other hand, is always exactly 24 hours.
+///
+/// ```
+/// use ical_syntax::write::value_types::ICalDuration;
+///
+/// // P1DT12H
+/// let duration = ICalDuration::days(1)
+///     .checked_add(ICalDuration::hours(12))
+///     .unwrap();
+///
+/// // -PT15M, for example for an alarm trigger
+/// let before = -ICalDuration::minutes(15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ICalDuration {
+    pub negative: bool,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+impl ICalDuration {
+    pub const ZERO: Self = Self {
+        negative: false,
+        weeks: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+    };
+
+    pub fn weeks(weeks: u32) -> Self {
+        Self {
+            weeks,
+            ..Self::ZERO
+        }
+    }
+
+    pub fn days(days: u32) -> Self {
+        Self { days, ..Self::ZERO }
+    }
+
+    pub fn hours(hours: u32) -> Self {
+        Self {
+            hours,
+            ..Self::ZERO
+        }
+    }
+
+    pub fn minutes(minutes: u32) -> Self {
+        Self {
+            minutes,
+            ..Self::ZERO
+        }
+    }
+
+    pub fn seconds(seconds: u32) -> Self {
+        Self {
+            seconds,
+            ..Self::ZERO
+        }
+    }
+
+    /// An exact duration of the given number of seconds, split into hours,
+    /// minutes and seconds
+    pub fn from_exact_seconds(seconds: i64) -> Result<Self, ICalDurationError> {
+        let abs = seconds.unsigned_abs();
+        Ok(Self {
+            negative: seconds < 0,
+            hours: (abs / 3600)
+                .try_into()
+                .map_err(|_| ICalDurationError::OutOfRange)?,
+            minutes: (abs / 60 % 60) as u32,
+            seconds: (abs % 60) as u32,
+            ..Self::ZERO
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.weeks == 0
+            && self.days == 0
+            && self.hours == 0
+            && self.minutes == 0
+            && self.seconds == 0
+    }
+
+    /// Check if the duration has a nominal part, that is weeks or days
+    pub fn is_nominal(&self) -> bool {
+        self.weeks != 0 || self.days != 0
+    }
+
+    /// The exact part of the duration, that is hours, minutes and seconds, in
+    /// seconds. The sign is not applied.
+    fn exact_seconds(&self) -> u64 {
+        self.hours as u64 * 3600 + self.minutes as u64 * 60 + self.seconds as u64
+    }
+
+    /// The duration in seconds, if it has no nominal part
+    pub fn to_exact_seconds(&self) -> Result<i64, ICalDurationError> {
+        if self.is_nominal() {
+            return Err(ICalDurationError::Nominal);
+        }
+
+        let seconds = self.exact_seconds() as i64;
+        Ok(if self.negative { -seconds } else { seconds })
+    }
+
+    /// Add the components of two durations with the same sign. Durations with
+    /// different signs yield [ICalDurationError::MixedSigns], as the nominal
+    /// and exact components cannot be balanced against each other. A zero
+    /// duration can be added to either sign.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ICalDurationError> {
+        let negative = match (self.is_zero(), rhs.is_zero()) {
+            (true, _) => rhs.negative,
+            (_, true) => self.negative,
+            _ if self.negative != rhs.negative => return Err(ICalDurationError::MixedSigns),
+            _ => self.negative,
+        };
+
+        let add = |a: u32, b: u32| a.checked_add(b).ok_or(ICalDurationError::OutOfRange);
+        Ok(Self {
+            negative,
+            weeks: add(self.weeks, rhs.weeks)?,
+            days: add(self.days, rhs.days)?,
+            hours: add(self.hours, rhs.hours)?,
+            minutes: add(self.minutes, rhs.minutes)?,
+            seconds: add(self.seconds, rhs.seconds)?,
+        })
+    }
+}
+
+impl std::ops::Neg for ICalDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            negative: !self.negative,
+            ..self
+        }
+    }
+}
+
+impl AsValueType<Duration> for ICalDuration {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        if self.negative && !self.is_zero() {
+            w.write_char('-')?;
+        }
+        w.write_char('P')?;
+
+        let exact = self.hours != 0 || self.minutes != 0 || self.seconds != 0;
+
+        // dur-week cannot be combined with anything else, so weeks are
+        // written as days when there are other components
+        if self.weeks != 0 && self.days == 0 && !exact {
+            return write!(w, "{}W", self.weeks);
+        }
+
+        let days = self.weeks as u64 * 7 + self.days as u64;
+        if days != 0 {
+            write!(w, "{days}D")?;
+        }
+
+        if exact || days == 0 {
+            w.write_char('T')?;
+
+            // A dur-hour can only be followed by a dur-minute, so the minutes
+            // must be written if there are both hours and seconds
+            let (hours, minutes, seconds) = (self.hours, self.minutes, self.seconds);
+            if hours != 0 {
+                write!(w, "{hours}H")?;
+            }
+            if minutes != 0 || (hours != 0 && seconds != 0) {
+                write!(w, "{minutes}M")?;
+            }
+            if seconds != 0 || (hours == 0 && minutes == 0) {
+                write!(w, "{seconds}S")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AsValueType<Duration> for &ICalDuration {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<Duration>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for ICalDuration {
+    type ValueType = Duration;
+}
+
+impl ToValueType for &ICalDuration {
+    type ValueType = Duration;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_case(v: impl AsValueType<Duration>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<Duration>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    #[test]
+    fn format() {
+        test_case(
+            ICalDuration {
+                days: 15,
+                hours: 5,
+                seconds: 20,
+                ..ICalDuration::ZERO
+            },
+            "P15DT5H0M20S",
+        );
+        test_case(ICalDuration::weeks(7), "P7W");
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        test_case(&ICalDuration::weeks(1), "P1W");
+        test_case(
+            ICalDuration {
+                weeks: 1,
+                days: 1,
+                ..ICalDuration::ZERO
+            },
+            "P8D",
+        );
+        test_case(
+            ICalDuration {
+                weeks: 1,
+                hours: 1,
+                ..ICalDuration::ZERO
+            },
+            "P7DT1H",
+        );
+        test_case(ICalDuration::days(1), "P1D");
+        test_case(ICalDuration::hours(24), "PT24H");
+        test_case(-ICalDuration::minutes(15), "-PT15M");
+        test_case(
+            ICalDuration {
+                hours: 1,
+                minutes: 30,
+                ..ICalDuration::ZERO
+            },
+            "PT1H30M",
+        );
+        test_case(
+            ICalDuration {
+                minutes: 1,
+                seconds: 5,
+                ..ICalDuration::ZERO
+            },
+            "PT1M5S",
+        );
+        test_case(ICalDuration::ZERO, "PT0S");
+        test_case(-ICalDuration::ZERO, "PT0S");
+    }
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(
+            ICalDuration::days(1).checked_add(ICalDuration::hours(12)),
+            Ok(ICalDuration {
+                days: 1,
+                hours: 12,
+                ..ICalDuration::ZERO
+            })
+        );
+        assert_eq!(
+            (-ICalDuration::minutes(15)).checked_add(-ICalDuration::seconds(30)),
+            Ok(-ICalDuration {
+                minutes: 15,
+                seconds: 30,
+                ..ICalDuration::ZERO
+            })
+        );
+        assert_eq!(
+            ICalDuration::ZERO.checked_add(-ICalDuration::hours(1)),
+            Ok(-ICalDuration::hours(1))
+        );
+        assert_eq!(
+            ICalDuration::days(1).checked_add(-ICalDuration::hours(1)),
+            Err(ICalDurationError::MixedSigns)
+        );
+        assert_eq!(
+            ICalDuration::seconds(u32::MAX).checked_add(ICalDuration::seconds(1)),
+            Err(ICalDurationError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn exact_seconds() {
+        assert_eq!(
+            ICalDuration::from_exact_seconds(-5405),
+            Ok(-ICalDuration {
+                hours: 1,
+                minutes: 30,
+                seconds: 5,
+                ..ICalDuration::ZERO
+            })
+        );
+        assert_eq!(
+            ICalDuration::from_exact_seconds(86400),
+            Ok(ICalDuration::hours(24))
+        );
+        assert_eq!((-ICalDuration::hours(2)).to_exact_seconds(), Ok(-7200));
+        assert_eq!(
+            ICalDuration::days(1).to_exact_seconds(),
+            Err(ICalDurationError::Nominal)
+        );
+    }
+
+    #[test]
+    fn period_of_time() {
+        use crate::{
+            structure::value_types::{DateTime, PeriodOfTime},
+            write::value_types::PeriodOfTimeBuilder,
+        };
+
+        struct Start;
+        impl AsValueType<DateTime> for Start {
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "19970101T180000Z")
+            }
+        }
+
+        let mut buf = String::new();
+        let period = PeriodOfTimeBuilder::start(Start)
+            .duration(ICalDuration::hours(5))
+            .unwrap();
+        AsValueType::<PeriodOfTime>::fmt(&period, &mut buf).unwrap();
+        assert_eq!(&buf, "19970101T180000Z/PT5H");
+    }
+}
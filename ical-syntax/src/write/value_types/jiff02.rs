@@ -1,9 +1,12 @@
 #![cfg(feature = "jiff02")]
 
-use super::AsValueType;
+use super::{AsValueType, ICalDuration, ICalDurationError, UtcInstant};
 use crate::{
-    structure::value_types::{Date, DateTime, DateTimeUtc, Duration},
-    write::value_types::ToValueType,
+    structure::{
+        icalstream::parameters::TimeZoneIdentifier,
+        value_types::{Date, DateTime, DateTimeUtc, Duration, UtcOffset},
+    },
+    write::{value_types::ToValueType, PropertyValueWriter},
 };
 
 /// `jiff::civil::DateTime` corresponds to the _floating_ form of a DateTime
@@ -26,6 +29,80 @@ impl ToValueType for jiff::civil::DateTime {
     type ValueType = DateTime;
 }
 
+/// `jiff::Zoned` corresponds to the _local time with time zone reference_
+/// form of a DateTime, and the `TZID` parameter is set to the IANA name of
+/// the time zone. This preserves the local wall-clock time across DST
+/// changes, for example for recurring meetings.
+///
+/// Time zones without an IANA name, such as fixed offsets, have no rules for
+/// the wall-clock time. These are written in the _UTC_ form instead.
+impl AsValueType<DateTime> for jiff::Zoned {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        if self.time_zone().iana_name().is_some() {
+            AsValueType::<DateTime>::fmt(&self.datetime(), w)
+        } else {
+            let utc = UtcForm::from_timestamp(self.timestamp());
+            AsValueType::<DateTime>::fmt(&utc, w)
+        }
+    }
+
+    fn write_params<W: std::fmt::Write>(
+        &self,
+        w: &mut PropertyValueWriter<'_, '_, W>,
+    ) -> std::fmt::Result {
+        match self.time_zone().iana_name() {
+            Some(tzid) => w.param(TimeZoneIdentifier, tzid),
+            None => Ok(()),
+        }
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        self.time_zone().iana_name() == other.time_zone().iana_name()
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        let tzid = self.time_zone().iana_name()?;
+        Some((tzid, self.timestamp().as_second()))
+    }
+
+    fn is_utc(&self) -> bool {
+        self.time_zone().iana_name().is_none()
+    }
+}
+
+impl AsValueType<DateTime> for &jiff::Zoned {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(*self, w)
+    }
+
+    fn write_params<W: std::fmt::Write>(
+        &self,
+        w: &mut PropertyValueWriter<'_, '_, W>,
+    ) -> std::fmt::Result {
+        AsValueType::<DateTime>::write_params(*self, w)
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        AsValueType::<DateTime>::has_same_params(*self, *other)
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        AsValueType::<DateTime>::time_zone_reference(*self)
+    }
+
+    fn is_utc(&self) -> bool {
+        AsValueType::<DateTime>::is_utc(*self)
+    }
+}
+
+impl ToValueType for jiff::Zoned {
+    type ValueType = DateTime;
+}
+
+impl ToValueType for &jiff::Zoned {
+    type ValueType = DateTime;
+}
+
 pub struct UtcForm {
     datetime: jiff::civil::DateTime,
 }
@@ -35,10 +112,16 @@ impl UtcForm {
         Self { datetime }
     }
 
+    /// Convert to the UTC form, discarding the time zone. Use `jiff::Zoned`
+    /// directly to keep the local time and time zone reference.
     pub fn from_zoned(datetime: jiff::Zoned) -> Result<Self, jiff::Error> {
-        Ok(Self {
-            datetime: datetime.in_tz("UTC")?.datetime(),
-        })
+        Ok(Self::from_timestamp(datetime.timestamp()))
+    }
+
+    pub fn from_timestamp(timestamp: jiff::Timestamp) -> Self {
+        Self {
+            datetime: jiff::tz::Offset::UTC.to_datetime(timestamp),
+        }
     }
 }
 
@@ -47,18 +130,32 @@ impl AsValueType<DateTime> for UtcForm {
         <jiff::civil::DateTime as AsValueType<DateTime>>::fmt(&self.datetime, w)?;
         write!(w, "Z")
     }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
 }
 
 impl AsValueType<DateTimeUtc> for UtcForm {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
         <Self as AsValueType<DateTime>>::fmt(self, w)
     }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
 }
 
 impl ToValueType for UtcForm {
     type ValueType = DateTime;
 }
 
+impl UtcInstant for jiff::Timestamp {
+    fn unix_seconds(&self) -> i64 {
+        self.as_second()
+    }
+}
+
 impl AsValueType<Date> for jiff::civil::Date {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
         write!(w, "{:04}{:02}{:02}", self.year(), self.month(), self.day())
@@ -69,12 +166,75 @@ impl ToValueType for jiff::civil::Date {
     type ValueType = Date;
 }
 
+/// The weeks and days of a `jiff::Span` are kept as nominal durations, and
+/// the hours, minutes and seconds as exact durations. Spans with years or
+/// months cannot be represented. Fractional seconds are truncated.
+impl TryFrom<jiff::Span> for ICalDuration {
+    type Error = ICalDurationError;
+
+    fn try_from(value: jiff::Span) -> Result<Self, Self::Error> {
+        if value.get_years() != 0 || value.get_months() != 0 {
+            return Err(ICalDurationError::UnsupportedUnit);
+        }
+
+        let abs = value.abs();
+        let subsecond_nanoseconds = i128::from(abs.get_milliseconds()) * 1_000_000
+            + i128::from(abs.get_microseconds()) * 1_000
+            + i128::from(abs.get_nanoseconds());
+        let seconds = i128::from(abs.get_seconds()) + subsecond_nanoseconds / 1_000_000_000;
+
+        let component = |x: i128| u32::try_from(x).map_err(|_| ICalDurationError::OutOfRange);
+
+        Ok(ICalDuration {
+            negative: value.is_negative(),
+            weeks: component(abs.get_weeks().into())?,
+            days: component(abs.get_days().into())?,
+            hours: component(abs.get_hours().into())?,
+            minutes: component(abs.get_minutes().into())?,
+            seconds: component(seconds)?,
+        })
+    }
+}
+
+impl TryFrom<ICalDuration> for jiff::Span {
+    type Error = ICalDurationError;
+
+    fn try_from(value: ICalDuration) -> Result<Self, Self::Error> {
+        let out_of_range = |_| ICalDurationError::OutOfRange;
+
+        let span = jiff::Span::new()
+            .try_weeks(value.weeks)
+            .map_err(out_of_range)?
+            .try_days(value.days)
+            .map_err(out_of_range)?
+            .try_hours(value.hours)
+            .map_err(out_of_range)?
+            .try_minutes(value.minutes)
+            .map_err(out_of_range)?
+            .try_seconds(value.seconds)
+            .map_err(out_of_range)?;
+
+        Ok(if value.negative { span.negate() } else { span })
+    }
+}
+
 impl AsValueType<Duration> for jiff::Span {
     fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
-        write!(w, "{}", self)
+        let duration = ICalDuration::try_from(*self).map_err(|_| std::fmt::Error)?;
+        AsValueType::<Duration>::fmt(&duration, w)
     }
 }
 
+impl AsValueType<UtcOffset> for jiff::tz::Offset {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        super::fmt_utc_offset(w, self.seconds())
+    }
+}
+
+impl ToValueType for jiff::tz::Offset {
+    type ValueType = UtcOffset;
+}
+
 #[cfg(test)]
 mod test {
     use crate::structure::ValueType;
@@ -93,6 +253,84 @@ mod test {
         test_case::<DateTime>(datetime, "20240619T152245");
     }
 
+    #[test]
+    fn datetime_utc() {
+        let timestamp: jiff::Timestamp = "2024-06-26T12:00:00Z".parse().unwrap();
+        test_case::<DateTime>(UtcForm::from_timestamp(timestamp), "20240626T120000Z");
+
+        let zoned = timestamp.to_zoned(jiff::tz::TimeZone::get("Europe/Oslo").unwrap());
+        test_case::<DateTime>(UtcForm::from_zoned(zoned).unwrap(), "20240626T120000Z");
+    }
+
+    #[test]
+    fn datetime_zoned() -> std::fmt::Result {
+        use crate::{
+            structure::{
+                composite_value_types::{Any2, List},
+                Property,
+            },
+            write::{LineStream, PropertyWriter},
+        };
+
+        struct DateTimeStart;
+        impl Property for DateTimeStart {
+            const NAME: &'static str = "DTSTART";
+
+            type CompositeValueType = Any2<DateTime, Date>;
+        }
+
+        struct RecurrenceDateTimes;
+        impl Property for RecurrenceDateTimes {
+            const NAME: &'static str = "RDATE";
+
+            type CompositeValueType = List<Any2<DateTime, Date>>;
+        }
+
+        let winter: jiff::Zoned = "2024-01-10T09:00[Europe/Oslo]".parse().unwrap();
+        let summer: jiff::Zoned = "2024-06-26T09:00[Europe/Oslo]".parse().unwrap();
+        let new_york = winter.in_tz("America/New_York").unwrap();
+        let fixed = winter.with_time_zone(jiff::tz::TimeZone::fixed(jiff::tz::offset(1)));
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        prop.value(&winter)?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        prop.value(summer.clone())?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        prop.value(fixed)?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, RecurrenceDateTimes>::new(&mut line_stream)?;
+        prop.value([&winter, &summer])?;
+        prop.end()?;
+
+        assert_eq!(
+            &buf,
+            "DTSTART;TZID=Europe/Oslo:20240110T090000\r\n\
+            DTSTART;TZID=Europe/Oslo:20240626T090000\r\n\
+            DTSTART:20240110T080000Z\r\n\
+            RDATE;TZID=Europe/Oslo:20240110T090000,20240626T090000\r\n"
+        );
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, RecurrenceDateTimes>::new(&mut line_stream)?;
+        assert!(prop.value([&winter, &new_york]).is_err());
+        prop.discard();
+
+        // Nothing but the name has been written
+        assert_eq!(&buf, "RDATE");
+
+        Ok(())
+    }
+
     #[test]
     fn date() {
         let date = jiff::civil::date(2024, 6, 26);
@@ -102,6 +340,46 @@ mod test {
     #[test]
     fn duration() {
         test_case::<Duration>(jiff::Span::new().hours(1), "PT1H");
+        test_case::<Duration>(jiff::Span::new().weeks(1), "P1W");
+        test_case::<Duration>(jiff::Span::new().weeks(1).days(1), "P8D");
+        test_case::<Duration>(jiff::Span::new().days(-1).hours(-12), "-P1DT12H");
+    }
+
+    #[test]
+    fn ical_duration() {
+        let span = jiff::Span::new().days(1).hours(2).milliseconds(1500);
+        let duration = ICalDuration::try_from(span).unwrap();
+        assert_eq!(
+            duration,
+            ICalDuration {
+                days: 1,
+                hours: 2,
+                seconds: 1,
+                ..ICalDuration::ZERO
+            }
+        );
+
+        let span = jiff::Span::try_from(-duration).unwrap();
+        assert_eq!(
+            span.fieldwise(),
+            jiff::Span::new().days(-1).hours(-2).seconds(-1)
+        );
+
+        assert_eq!(
+            ICalDuration::try_from(jiff::Span::new().months(1)),
+            Err(ICalDurationError::UnsupportedUnit)
+        );
+    }
+
+    #[test]
+    fn utc_offset() {
+        test_case::<UtcOffset>(jiff::tz::offset(-5), "-0500");
+        test_case::<UtcOffset>(jiff::tz::offset(1), "+0100");
+        test_case::<UtcOffset>(jiff::tz::Offset::UTC, "+0000");
+        test_case::<UtcOffset>(
+            jiff::tz::Offset::from_seconds(-(3600 + 30 * 60 + 15)).unwrap(),
+            "-013015",
+        );
     }
 
     #[test]
@@ -117,13 +395,67 @@ mod test {
         let duration = end - start;
 
         test_case::<PeriodOfTime>(
-            PeriodOfTimeBuilder::start(start).end(end),
+            PeriodOfTimeBuilder::start(start).end(end).unwrap(),
             "20240626T120000/20240626T130000",
         );
 
         test_case::<PeriodOfTime>(
-            PeriodOfTimeBuilder::start(start).duration(duration),
+            PeriodOfTimeBuilder::start(start)
+                .duration(duration)
+                .unwrap(),
             "20240626T120000/PT1H",
         );
     }
+
+    #[test]
+    fn period_utc_form() {
+        use crate::{
+            structure::value_types::PeriodOfTime,
+            write::value_types::{PeriodError, PeriodOfTimeBuilder},
+        };
+
+        let start = || UtcForm::from_timestamp("2024-06-26T12:00:00Z".parse().unwrap());
+        let end = || UtcForm::from_timestamp("2024-06-26T13:00:00Z".parse().unwrap());
+
+        test_case::<PeriodOfTime>(
+            PeriodOfTimeBuilder::start(start()).end(end()).unwrap(),
+            "20240626T120000Z/20240626T130000Z",
+        );
+        assert_eq!(
+            PeriodOfTimeBuilder::start(end()).end(start()).err(),
+            Some(PeriodError::EndNotAfterStart)
+        );
+
+        let floating: jiff::civil::DateTime = "2024-06-26T13:00:00".parse().unwrap();
+        assert_eq!(
+            PeriodOfTimeBuilder::start(start()).end(floating).err(),
+            Some(PeriodError::FormMismatch)
+        );
+    }
+
+    #[test]
+    fn fixed_offset_utc_form() -> std::fmt::Result {
+        use crate::write::{
+            icalstream::typed_writers::ICalStreamWriter, value_types::PeriodOfTimeBuilder,
+        };
+
+        let start: jiff::Zoned = "2024-06-26T14:00:00+02:00[+02:00]".parse().unwrap();
+        let end = || UtcForm::from_timestamp("2024-06-26T13:00:00Z".parse().unwrap());
+        assert!(AsValueType::<DateTime>::is_utc(&start));
+        assert!(AsValueType::<DateTime>::is_utc(&&start));
+
+        assert!(PeriodOfTimeBuilder::start(&start).end(end()).is_ok());
+
+        let mut buf = String::new();
+        let mut ics = ICalStreamWriter::with_fmt(&mut buf);
+        let mut ico = ics.icalendar_object("-//test//")?;
+        let mut ev = ico.event()?;
+        ev.dtstart(&start)?;
+        ev.dtend(end())?;
+        ev.end()?;
+        ico.end()?;
+
+        assert!(buf.contains("DTSTART:20240626T120000Z\r\nDTEND:20240626T130000Z\r\n"));
+        Ok(())
+    }
 }
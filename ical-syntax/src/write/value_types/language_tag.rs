@@ -0,0 +1,140 @@
+/// Error for values that cannot be used as a language tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LanguageTagError {
+    /// The language tag, or one of its subtags, is empty
+    Empty,
+
+    /// The primary language subtag is not two to eight letters, and is not
+    /// `x` or `i` for a private use or grandfathered tag
+    InvalidLanguage,
+
+    /// A subtag is longer than eight characters, or contains characters
+    /// other than letters and digits
+    InvalidSubtag,
+}
+
+impl std::fmt::Display for LanguageTagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanguageTagError::Empty => write!(f, "empty language tag or subtag"),
+            LanguageTagError::InvalidLanguage => write!(f, "invalid primary language subtag"),
+            LanguageTagError::InvalidSubtag => {
+                write!(f, "subtags must be one to eight letters or digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LanguageTagError {}
+
+/// A language tag for the LANGUAGE parameter, such as `en-US`
+///
+/// Only the general form of [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646)
+/// is validated: the tag is a sequence of subtags of one to eight letters or
+/// digits, separated by `-`, starting with a primary language subtag. The
+/// subtags are not checked against the IANA Language Subtag Registry.
+///
+/// ```
+/// # use ical_syntax::write::value_types::LanguageTag;
+/// assert!(LanguageTag::new("en-US").is_ok());
+/// assert!(LanguageTag::new("zh-Hant-TW").is_ok());
+/// assert!(LanguageTag::new("en_US").is_err());
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.10](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.10)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageTag(String);
+
+impl LanguageTag {
+    pub fn new(tag: impl Into<String>) -> Result<Self, LanguageTagError> {
+        let tag = tag.into();
+        let mut subtags = tag.split('-');
+
+        // The primary language subtag is 2*8ALPHA, or "x" for private use
+        // tags and "i" for some grandfathered tags
+        let language = subtags.next().unwrap_or_default();
+        if language.is_empty() {
+            return Err(LanguageTagError::Empty);
+        }
+        let is_language =
+            (2..=8).contains(&language.len()) && language.bytes().all(|x| x.is_ascii_alphabetic());
+        if !is_language
+            && !language.eq_ignore_ascii_case("x")
+            && !language.eq_ignore_ascii_case("i")
+        {
+            return Err(LanguageTagError::InvalidLanguage);
+        }
+
+        for subtag in subtags {
+            if subtag.is_empty() {
+                return Err(LanguageTagError::Empty);
+            }
+            if subtag.len() > 8 || !subtag.bytes().all(|x| x.is_ascii_alphanumeric()) {
+                return Err(LanguageTagError::InvalidSubtag);
+            }
+        }
+
+        Ok(Self(tag))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for LanguageTag {
+    type Error = LanguageTagError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for LanguageTag {
+    type Error = LanguageTagError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl std::fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        assert!(LanguageTag::new("no").is_ok());
+        assert!(LanguageTag::new("en-US").is_ok());
+        assert!(LanguageTag::new("sr-Latn-RS").is_ok());
+        assert!(LanguageTag::new("de-CH-1996").is_ok());
+        assert!(LanguageTag::new("x-whatever").is_ok());
+        assert!(LanguageTag::new("i-klingon").is_ok());
+
+        assert_eq!(LanguageTag::new(""), Err(LanguageTagError::Empty));
+        assert_eq!(LanguageTag::new("en-"), Err(LanguageTagError::Empty));
+        assert_eq!(
+            LanguageTag::new("e"),
+            Err(LanguageTagError::InvalidLanguage)
+        );
+        assert_eq!(
+            LanguageTag::new("e1"),
+            Err(LanguageTagError::InvalidLanguage)
+        );
+        assert_eq!(
+            LanguageTag::new("en-US_POSIX"),
+            Err(LanguageTagError::InvalidSubtag)
+        );
+        assert_eq!(
+            LanguageTag::new("en-abcdefghi"),
+            Err(LanguageTagError::InvalidSubtag)
+        );
+    }
+}
@@ -0,0 +1,122 @@
+/// Error for values that cannot be used as a media type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaTypeError {
+    /// The value is not on the form `type-name/subtype-name`
+    MissingSubtype,
+
+    /// The type or subtype name is empty, or longer than 127 characters
+    InvalidLength,
+
+    /// The type or subtype name contains a character that is not allowed,
+    /// or does not start with a letter or digit
+    InvalidCharacter(char),
+}
+
+impl std::fmt::Display for MediaTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediaTypeError::MissingSubtype => write!(f, "media type is missing a subtype"),
+            MediaTypeError::InvalidLength => {
+                write!(f, "media type names must be 1 to 127 characters")
+            }
+            MediaTypeError::InvalidCharacter(c) => {
+                write!(f, "invalid character in media type: {c:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MediaTypeError {}
+
+fn validate_restricted_name(name: &str) -> Result<(), MediaTypeError> {
+    // restricted-name = restricted-name-first *126restricted-name-chars
+    // restricted-name-first = ALPHA / DIGIT
+    // restricted-name-chars = ALPHA / DIGIT / "!" / "#" / "$" / "&" / "-" /
+    //                         "^" / "_" / "." / "+"
+    if name.is_empty() || name.len() > 127 {
+        return Err(MediaTypeError::InvalidLength);
+    }
+
+    for (i, c) in name.chars().enumerate() {
+        let is_valid = c.is_ascii_alphanumeric() || (i > 0 && "!#$&-^_.+".contains(c));
+        if !is_valid {
+            return Err(MediaTypeError::InvalidCharacter(c));
+        }
+    }
+
+    Ok(())
+}
+
+/// A media type for the FMTTYPE parameter, such as `text/html`
+///
+/// ```
+/// # use ical_syntax::write::value_types::MediaType;
+/// assert!(MediaType::new("application/msword").is_ok());
+/// assert!(MediaType::new("image/svg+xml").is_ok());
+/// assert!(MediaType::new("text/plain; charset=utf-8").is_err());
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.2.8](https://www.rfc-editor.org/rfc/rfc5545#section-3.2.8)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MediaType(String);
+
+impl MediaType {
+    pub fn new(media_type: impl Into<String>) -> Result<Self, MediaTypeError> {
+        let media_type = media_type.into();
+        let (type_name, subtype_name) = media_type
+            .split_once('/')
+            .ok_or(MediaTypeError::MissingSubtype)?;
+        validate_restricted_name(type_name)?;
+        validate_restricted_name(subtype_name)?;
+        Ok(Self(media_type))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for MediaType {
+    type Error = MediaTypeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for MediaType {
+    type Error = MediaTypeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl std::fmt::Display for MediaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        assert!(MediaType::new("text/plain").is_ok());
+        assert!(MediaType::new("application/vnd.ms-excel").is_ok());
+
+        assert_eq!(MediaType::new("text"), Err(MediaTypeError::MissingSubtype));
+        assert_eq!(MediaType::new("text/"), Err(MediaTypeError::InvalidLength));
+        assert_eq!(
+            MediaType::new("text/plain/x"),
+            Err(MediaTypeError::InvalidCharacter('/'))
+        );
+        assert_eq!(
+            MediaType::new("text/.plain"),
+            Err(MediaTypeError::InvalidCharacter('.'))
+        );
+    }
+}
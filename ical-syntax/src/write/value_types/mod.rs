@@ -1,22 +1,77 @@
+mod binary;
 mod chrono04;
+mod chrono_tz010;
+mod duration;
 pub mod jiff02;
+mod language_tag;
+mod media_type;
 mod period_of_time;
+mod recurrence_rule;
+mod request_status;
+mod time03;
+mod trigger;
+mod uri;
+mod url2;
 
 use std::{borrow::Borrow, fmt::Write};
 
-use crate::structure::{
-    composite_value_types::{Any2, Any3, IsA, ValueTypeChoice},
-    value_types::*,
-    ValueType,
+use crate::{
+    structure::{
+        composite_value_types::{Any2, Any3, Any4, Any5, Any6, Any7, Any8, IsA, ValueTypeChoice},
+        value_types::*,
+        ValueType,
+    },
+    write::PropertyValueWriter,
 };
 
+pub use binary::Base64Binary;
+pub use duration::{ICalDuration, ICalDurationError};
+pub use language_tag::{LanguageTag, LanguageTagError};
+pub use media_type::{MediaType, MediaTypeError};
 pub use period_of_time::{
-    PeriodOfTimeBuilder, PeriodOfTimeDurationValue, PeriodOfTimeStartEndValue,
+    PeriodError, PeriodOfTimeBuilder, PeriodOfTimeDurationValue, PeriodOfTimeStartEndValue,
+    PeriodUtc, UtcInstant,
 };
+pub use recurrence_rule::{Frequency, MonthNum, Recur, RecurrenceScale, Skip, Weekday, WeekdayNum};
+pub use request_status::{RequestStatus, StatusCode, StatusCodeError};
+pub use trigger::Trigger;
+pub use uri::{CalAddress, Uri, UriError};
 
 /// Trait for representing a Rust type as the given Value Type.
 pub trait AsValueType<To: ValueType> {
     fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result;
+
+    /// Write the property parameters that are implied by the value, such as
+    /// `TZID` for date-times in the local time form.
+    ///
+    /// This is called before the value is written.
+    fn write_params<W: Write>(&self, _w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        Ok(())
+    }
+
+    /// Check if `self` and `other` imply the same property parameters, as
+    /// written by [`AsValueType::write_params`].
+    ///
+    /// All the values in a list share the same property parameters, so this
+    /// is used to reject lists that mix, for example, different time zones.
+    fn has_same_params(&self, _other: &Self) -> bool {
+        true
+    }
+
+    /// The TZID of the time zone this value refers to, along with the point
+    /// in time as a UNIX timestamp.
+    ///
+    /// This is used to track which VTIMEZONE components are required, and
+    /// which range of time they must cover.
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        None
+    }
+
+    /// Whether the value is a date-time in the _UTC_ form, as opposed to the
+    /// floating form or the local time form with a time zone reference.
+    fn is_utc(&self) -> bool {
+        false
+    }
 }
 
 pub trait AsValueTypeChoice<To: ValueTypeChoice> {
@@ -36,32 +91,114 @@ pub trait ToValueType {
     type ValueType;
 }
 
-impl<RustType, VT, T0, T1> AsValueTypeChoice<Any2<T0, T1>> for RustType
-where
-    T0: ValueType,
-    T1: ValueType,
-    VT: ValueType + IsA<Any2<T0, T1>>,
-    RustType: AsValueType<VT> + ToValueType<ValueType = VT>,
-{
-    type Type = VT;
+macro_rules! any_value_type_choice {
+    ($name:ident, $( $t:ident ),+) => {
+        impl<RustType, VT, $( $t ),+> AsValueTypeChoice<$name<$( $t ),+>> for RustType
+        where
+            $( $t: ValueType, )+
+            VT: ValueType + IsA<$name<$( $t ),+>>,
+            RustType: AsValueType<VT> + ToValueType<ValueType = VT>,
+        {
+            type Type = VT;
+        }
+    };
+}
+
+any_value_type_choice!(Any2, T0, T1);
+any_value_type_choice!(Any3, T0, T1, T2);
+any_value_type_choice!(Any4, T0, T1, T2, T3);
+any_value_type_choice!(Any5, T0, T1, T2, T3, T4);
+any_value_type_choice!(Any6, T0, T1, T2, T3, T4, T5);
+any_value_type_choice!(Any7, T0, T1, T2, T3, T4, T5, T6);
+any_value_type_choice!(Any8, T0, T1, T2, T3, T4, T5, T6, T7);
+
+/// The form of a value that can be either a [Date] or a [DateTime]
+///
+/// Properties such as DTEND and RECURRENCE-ID must agree with the DTSTART
+/// property of their component: a DATE goes with a DATE, and the floating
+/// form goes with the floating form. The UTC form and local time with a time
+/// zone reference can be mixed, see [DateTimeForm::is_compatible].
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DateTimeForm {
+    Date,
+
+    /// Date with local time, also known as floating time
+    Floating,
+
+    /// Date with UTC time
+    Utc,
+
+    /// Date with local time and time zone reference
+    Local,
+}
+
+impl DateTimeForm {
+    /// Find the form of the given value
+    pub fn of<T>(value: &T) -> Result<Self, std::fmt::Error>
+    where
+        T: AsValueTypeChoice<Any2<DateTime, Date>>
+            + AsValueType<<T as AsValueTypeChoice<Any2<DateTime, Date>>>::Type>,
+    {
+        if T::Type::NAME == Date::NAME {
+            return Ok(DateTimeForm::Date);
+        }
+
+        Ok(
+            if AsValueType::<T::Type>::time_zone_reference(value).is_some() {
+                DateTimeForm::Local
+            } else if AsValueType::<T::Type>::is_utc(value) {
+                DateTimeForm::Utc
+            } else {
+                DateTimeForm::Floating
+            },
+        )
+    }
+
+    /// Check if values of the two forms can be used together, as required
+    /// for DTSTART and DTEND by RFC 5545 3.8.2.2. The UTC form and local time
+    /// with a time zone reference both refer to exact points in time, so they
+    /// can be mixed.
+    pub fn is_compatible(self, other: Self) -> bool {
+        use DateTimeForm::*;
+        matches!(
+            (self, other),
+            (Date, Date) | (Floating, Floating) | (Utc | Local, Utc | Local)
+        )
+    }
+}
+
+/// A DATE or DATE-TIME value reduced to something that can be ordered against
+/// other values of a compatible [DateTimeForm]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DateTimeKey {
+    /// An exact point in time, as a UNIX timestamp
+    Instant(i64),
+
+    /// A DATE or a DATE-TIME in the floating form, as its date and time
+    Civil(period_of_time::CivilDateTime),
 }
 
-impl<RustType, VT, T0, T1, T2> AsValueTypeChoice<Any3<T0, T1, T2>> for RustType
-where
-    T0: ValueType,
-    T1: ValueType,
-    T2: ValueType,
-    VT: ValueType + IsA<Any3<T0, T1, T2>>,
-    RustType: AsValueType<VT> + ToValueType<ValueType = VT>,
-{
-    type Type = VT;
+impl DateTimeKey {
+    pub(crate) fn of<T>(value: &T, form: DateTimeForm) -> Result<Self, std::fmt::Error>
+    where
+        T: AsValueTypeChoice<Any2<DateTime, Date>>
+            + AsValueType<<T as AsValueTypeChoice<Any2<DateTime, Date>>>::Type>,
+    {
+        if let Some((_, timestamp)) = AsValueType::<T::Type>::time_zone_reference(value) {
+            return Ok(DateTimeKey::Instant(timestamp));
+        }
+
+        let mut buf = String::new();
+        AsValueType::<T::Type>::fmt(value, &mut buf)?;
+        let civil = period_of_time::CivilDateTime::parse(&buf).ok_or(std::fmt::Error)?;
+        Ok(match form {
+            DateTimeForm::Utc => DateTimeKey::Instant(civil.unix_seconds().ok_or(std::fmt::Error)?),
+            _ => DateTimeKey::Civil(civil),
+        })
+    }
 }
 
-// TODO impl<T: AsRef<[u8]>> AsValueType<Binary> for T {
-// When using Binary, two parameters must be set: VALUE=BINARY and ENCODING=BASE64
-// I _think_ BINARY is never the default type, so the VALUE parameter is taken
-// care of. However, the ENCODING parameter probably means we must implement
-// AsCompositeValueType instead, and this messes with type composition.
+// See the binary module for AsValueType<Binary>
 
 impl<T: Borrow<bool>> AsValueType<Boolean> for T {
     fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
@@ -73,18 +210,92 @@ impl<T: Borrow<bool>> AsValueType<Boolean> for T {
     }
 }
 
-// TODO: Find a relevant type for impling AsValueType<CalAddress>
-// Maybe EmailAddress from https://crates.io/crates/email_address ?
+// See the uri module for AsValueType<CalAddress>, and the url2 module for
+// using `url::Url` directly
+
+// See the chrono04, chrono_tz010, jiff02 and time03 modules for
+// AsValueType<DateTime>, AsValueType<DateTimeUtc> and AsValueType<Date>
+
+/// Non-finite values cannot be represented as FLOAT
+impl AsValueType<Float> for f64 {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        if !self.is_finite() {
+            return Err(std::fmt::Error);
+        }
+
+        // Display never uses exponential notation for floats
+        write!(w, "{}", self)
+    }
+}
+
+impl AsValueType<Float> for &f64 {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<Float>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for f64 {
+    type ValueType = Float;
+}
+
+impl ToValueType for &f64 {
+    type ValueType = Float;
+}
+
+impl AsValueType<Float> for f32 {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        if !self.is_finite() {
+            return Err(std::fmt::Error);
+        }
+
+        write!(w, "{}", self)
+    }
+}
+
+impl AsValueType<Float> for &f32 {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<Float>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for f32 {
+    type ValueType = Float;
+}
+
+impl ToValueType for &f32 {
+    type ValueType = Float;
+}
+
+// INTEGER is a signed 32-bit integer. Wider types yield `Result::Err` for
+// values outside of that range.
+macro_rules! integer_value_type {
+    ($( $t:ty ),+) => { $(
+        impl AsValueType<Integer> for $t {
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                let value = i32::try_from(*self).map_err(|_| std::fmt::Error)?;
+                write!(w, "{}", value)
+            }
+        }
 
-// See the chrono04 and jiff02 modules for AsValueType<DateTime>,
-// AsValueType<DateTimeUtc> and AsValueType<Date>
+        impl AsValueType<Integer> for &$t {
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                AsValueType::<Integer>::fmt(*self, w)
+            }
+        }
 
-// TODO impl AsValueType<Float> for misc f-types
+        impl ToValueType for $t {
+            type ValueType = Integer;
+        }
 
-// TODO impl AsValueType<Integer>
+        impl ToValueType for &$t {
+            type ValueType = Integer;
+        }
+    )+ };
+}
 
-// TODO impl AsValueType<RecurrenceRule>. And probably elaborate types for
-// building recurrence rules
+integer_value_type!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// See the recurrence_rule module for AsValueType<RecurrenceRule>
 
 impl<T: std::fmt::Display> AsValueType<Text> for T {
     fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
@@ -92,6 +303,18 @@ impl<T: std::fmt::Display> AsValueType<Text> for T {
     }
 }
 
+impl ToValueType for &str {
+    type ValueType = Text;
+}
+
+impl ToValueType for String {
+    type ValueType = Text;
+}
+
+impl ToValueType for &String {
+    type ValueType = Text;
+}
+
 /// Helper type for the TRANSP property.
 #[derive(PartialEq, Eq, Debug)]
 pub enum TimeTransparency {
@@ -99,6 +322,7 @@ pub enum TimeTransparency {
     Transparent,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for TimeTransparency {
     fn default() -> Self {
         Self::Opaque
@@ -114,11 +338,609 @@ impl std::fmt::Display for TimeTransparency {
     }
 }
 
+/// Helper type for the CLASS property.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum Classification {
+    #[default]
+    Public,
+    Private,
+    Confidential,
+
+    /// An IANA-registered or non-standard classification, which applications
+    /// that don't recognize it treat as [Classification::Private]
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for Classification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Classification::Public => write!(f, "PUBLIC"),
+            Classification::Private => write!(f, "PRIVATE"),
+            Classification::Confidential => write!(f, "CONFIDENTIAL"),
+            Classification::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the STATUS property of a VEVENT.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum EventStatus {
+    Tentative,
+    Confirmed,
+    Cancelled,
+}
+
+impl std::fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventStatus::Tentative => write!(f, "TENTATIVE"),
+            EventStatus::Confirmed => write!(f, "CONFIRMED"),
+            EventStatus::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// Helper type for the STATUS property of a VTODO.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TodoStatus {
+    NeedsAction,
+    Completed,
+    InProcess,
+    Cancelled,
+}
+
+impl std::fmt::Display for TodoStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TodoStatus::NeedsAction => write!(f, "NEEDS-ACTION"),
+            TodoStatus::Completed => write!(f, "COMPLETED"),
+            TodoStatus::InProcess => write!(f, "IN-PROCESS"),
+            TodoStatus::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// Helper type for the STATUS property of a VJOURNAL.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum JournalStatus {
+    Draft,
+    Final,
+    Cancelled,
+}
+
+impl std::fmt::Display for JournalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JournalStatus::Draft => write!(f, "DRAFT"),
+            JournalStatus::Final => write!(f, "FINAL"),
+            JournalStatus::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// Helper type for the ACTION property.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum AlarmAction {
+    Audio,
+    Display,
+    Email,
+
+    /// An IANA-registered or non-standard action, which applications that
+    /// don't recognize it ignore
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for AlarmAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlarmAction::Audio => write!(f, "AUDIO"),
+            AlarmAction::Display => write!(f, "DISPLAY"),
+            AlarmAction::Email => write!(f, "EMAIL"),
+            AlarmAction::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the CUTYPE parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum CalendarUserType {
+    #[default]
+    Individual,
+    Group,
+    Resource,
+    Room,
+    Unknown,
+
+    /// An IANA-registered or non-standard calendar user type, which
+    /// applications that don't recognize it treat as
+    /// [CalendarUserType::Unknown]
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for CalendarUserType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarUserType::Individual => write!(f, "INDIVIDUAL"),
+            CalendarUserType::Group => write!(f, "GROUP"),
+            CalendarUserType::Resource => write!(f, "RESOURCE"),
+            CalendarUserType::Room => write!(f, "ROOM"),
+            CalendarUserType::Unknown => write!(f, "UNKNOWN"),
+            CalendarUserType::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the ROLE parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum ParticipationRole {
+    Chair,
+    #[default]
+    ReqParticipant,
+    OptParticipant,
+    NonParticipant,
+
+    /// An IANA-registered or non-standard role, which applications that don't
+    /// recognize it treat as [ParticipationRole::ReqParticipant]
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for ParticipationRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipationRole::Chair => write!(f, "CHAIR"),
+            ParticipationRole::ReqParticipant => write!(f, "REQ-PARTICIPANT"),
+            ParticipationRole::OptParticipant => write!(f, "OPT-PARTICIPANT"),
+            ParticipationRole::NonParticipant => write!(f, "NON-PARTICIPANT"),
+            ParticipationRole::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the PARTSTAT parameter.
+///
+/// [ParticipationStatus::Completed] and [ParticipationStatus::InProcess] are
+/// only valid for a VTODO, and a VJOURNAL only allows
+/// [ParticipationStatus::NeedsAction], [ParticipationStatus::Accepted] and
+/// [ParticipationStatus::Declined].
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum ParticipationStatus {
+    #[default]
+    NeedsAction,
+    Accepted,
+    Declined,
+    Tentative,
+    Delegated,
+    Completed,
+    InProcess,
+
+    /// An IANA-registered or non-standard status, which applications that
+    /// don't recognize it treat as [ParticipationStatus::NeedsAction]
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for ParticipationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipationStatus::NeedsAction => write!(f, "NEEDS-ACTION"),
+            ParticipationStatus::Accepted => write!(f, "ACCEPTED"),
+            ParticipationStatus::Declined => write!(f, "DECLINED"),
+            ParticipationStatus::Tentative => write!(f, "TENTATIVE"),
+            ParticipationStatus::Delegated => write!(f, "DELEGATED"),
+            ParticipationStatus::Completed => write!(f, "COMPLETED"),
+            ParticipationStatus::InProcess => write!(f, "IN-PROCESS"),
+            ParticipationStatus::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the RELTYPE parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum RelationshipType {
+    #[default]
+    Parent,
+    Child,
+    Sibling,
+
+    /// The alarm is a snoozed repetition of the related alarm, which has
+    /// been acknowledged. See [RFC 9074
+    /// 7](https://www.rfc-editor.org/rfc/rfc9074#section-7).
+    Snooze,
+
+    /// An IANA-registered or non-standard relationship type, which
+    /// applications that don't recognize it treat as
+    /// [RelationshipType::Parent]
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for RelationshipType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelationshipType::Parent => write!(f, "PARENT"),
+            RelationshipType::Child => write!(f, "CHILD"),
+            RelationshipType::Sibling => write!(f, "SIBLING"),
+            RelationshipType::Snooze => write!(f, "SNOOZE"),
+            RelationshipType::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the FBTYPE parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum FreeBusyType {
+    Free,
+    #[default]
+    Busy,
+    BusyUnavailable,
+    BusyTentative,
+
+    /// An IANA-registered or non-standard free/busy type, which applications
+    /// that don't recognize it treat as [FreeBusyType::Busy]
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for FreeBusyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FreeBusyType::Free => write!(f, "FREE"),
+            FreeBusyType::Busy => write!(f, "BUSY"),
+            FreeBusyType::BusyUnavailable => write!(f, "BUSY-UNAVAILABLE"),
+            FreeBusyType::BusyTentative => write!(f, "BUSY-TENTATIVE"),
+            FreeBusyType::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the CALSCALE property.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum CalendarScale {
+    #[default]
+    Gregorian,
+
+    /// A calendar scale defined by another specification, or a non-standard
+    /// calendar scale
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for CalendarScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarScale::Gregorian => write!(f, "GREGORIAN"),
+            CalendarScale::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the METHOD property, with the methods defined by iTIP,
+/// [RFC 5546 1.4](https://www.rfc-editor.org/rfc/rfc5546#section-1.4).
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Method {
+    /// Post notification of an event. Used primarily as a method of
+    /// advertising the existence of an event.
+    Publish,
+
+    /// Make a request for an event, such as a meeting request, or update an
+    /// existing request
+    Request,
+
+    /// Reply to an event request, for example by accepting or declining it
+    Reply,
+
+    /// Add one or more instances to an existing recurring event
+    Add,
+
+    /// Cancel one or more instances of an existing event
+    Cancel,
+
+    /// Request that the organizer sends the latest version of an event
+    Refresh,
+
+    /// Propose changes to an event, such as a new time for a meeting
+    Counter,
+
+    /// Decline a counter proposal
+    DeclineCounter,
+
+    /// An IANA-registered or non-standard method
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Method::Publish => write!(f, "PUBLISH"),
+            Method::Request => write!(f, "REQUEST"),
+            Method::Reply => write!(f, "REPLY"),
+            Method::Add => write!(f, "ADD"),
+            Method::Cancel => write!(f, "CANCEL"),
+            Method::Refresh => write!(f, "REFRESH"),
+            Method::Counter => write!(f, "COUNTER"),
+            Method::DeclineCounter => write!(f, "DECLINECOUNTER"),
+            Method::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the ENCODING parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum InlineEncoding {
+    #[default]
+    EightBit,
+    Base64,
+
+    /// An IANA-registered or non-standard encoding
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for InlineEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InlineEncoding::EightBit => write!(f, "8BIT"),
+            InlineEncoding::Base64 => write!(f, "BASE64"),
+            InlineEncoding::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the RANGE parameter. The default range, the single
+/// instance specified by the recurrence identifier, is expressed by leaving
+/// out the parameter.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum RecurrenceIdentifierRange {
+    ThisAndFuture,
+
+    /// An IANA-registered or non-standard range. Note that "THISANDPRIOR" is
+    /// deprecated, and MUST NOT be generated.
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for RecurrenceIdentifierRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceIdentifierRange::ThisAndFuture => write!(f, "THISANDFUTURE"),
+            RecurrenceIdentifierRange::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the RELATED parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum AlarmTriggerRelationship {
+    #[default]
+    Start,
+    End,
+
+    /// An IANA-registered or non-standard relationship
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for AlarmTriggerRelationship {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlarmTriggerRelationship::Start => write!(f, "START"),
+            AlarmTriggerRelationship::End => write!(f, "END"),
+            AlarmTriggerRelationship::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the DISPLAY parameter.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub enum ImageDisplay {
+    /// Image is displayed inline with the title of the event
+    #[default]
+    Badge,
+
+    /// Image is displayed inside of the event as a graphic
+    Graphic,
+
+    /// Image is displayed as a full image
+    Fullsize,
+
+    /// Image is displayed as a thumbnail
+    Thumbnail,
+
+    /// An IANA-registered or non-standard display mode
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for ImageDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageDisplay::Badge => write!(f, "BADGE"),
+            ImageDisplay::Graphic => write!(f, "GRAPHIC"),
+            ImageDisplay::Fullsize => write!(f, "FULLSIZE"),
+            ImageDisplay::Thumbnail => write!(f, "THUMBNAIL"),
+            ImageDisplay::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the FEATURE parameter.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ConferenceFeature {
+    /// Audio capability
+    Audio,
+
+    /// Chat or instant messaging
+    Chat,
+
+    /// Blog or Atom feed
+    Feed,
+
+    /// Moderator dial-in code
+    Moderator,
+
+    /// Phone conference
+    Phone,
+
+    /// Screen sharing
+    Screen,
+
+    /// Video capability
+    Video,
+
+    /// An IANA-registered or non-standard feature
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for ConferenceFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConferenceFeature::Audio => write!(f, "AUDIO"),
+            ConferenceFeature::Chat => write!(f, "CHAT"),
+            ConferenceFeature::Feed => write!(f, "FEED"),
+            ConferenceFeature::Moderator => write!(f, "MODERATOR"),
+            ConferenceFeature::Phone => write!(f, "PHONE"),
+            ConferenceFeature::Screen => write!(f, "SCREEN"),
+            ConferenceFeature::Video => write!(f, "VIDEO"),
+            ConferenceFeature::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the PARTICIPANT-TYPE property.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParticipantType {
+    /// A participant taking an active role, for example, a team member
+    Active,
+
+    /// A participant taking an inactive role, for example, an audience member
+    Inactive,
+
+    /// A sponsor of the event
+    Sponsor,
+
+    /// Contact information for the event
+    Contact,
+
+    /// Contact for bookings
+    BookingContact,
+
+    /// Contact for emergencies
+    EmergencyContact,
+
+    /// Contact for publicity
+    PublicityContact,
+
+    /// Contact for the event planner
+    PlannerContact,
+
+    /// A performer, for example, the musicians in a concert
+    Performer,
+
+    /// A speaker, for example, at a conference
+    Speaker,
+
+    /// An IANA-registered participant type
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for ParticipantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipantType::Active => write!(f, "ACTIVE"),
+            ParticipantType::Inactive => write!(f, "INACTIVE"),
+            ParticipantType::Sponsor => write!(f, "SPONSOR"),
+            ParticipantType::Contact => write!(f, "CONTACT"),
+            ParticipantType::BookingContact => write!(f, "BOOKING-CONTACT"),
+            ParticipantType::EmergencyContact => write!(f, "EMERGENCY-CONTACT"),
+            ParticipantType::PublicityContact => write!(f, "PUBLICITY-CONTACT"),
+            ParticipantType::PlannerContact => write!(f, "PLANNER-CONTACT"),
+            ParticipantType::Performer => write!(f, "PERFORMER"),
+            ParticipantType::Speaker => write!(f, "SPEAKER"),
+            ParticipantType::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the RESOURCE-TYPE property.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ResourceType {
+    /// A room for the event or meeting
+    Room,
+
+    /// Projection equipment
+    Projector,
+
+    /// An audio conferencing resource, for example, a speakerphone
+    RemoteConferenceAudio,
+
+    /// A video conferencing resource
+    RemoteConferenceVideo,
+
+    /// An IANA-registered resource type
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResourceType::Room => write!(f, "ROOM"),
+            ResourceType::Projector => write!(f, "PROJECTOR"),
+            ResourceType::RemoteConferenceAudio => write!(f, "REMOTE-CONFERENCE-AUDIO"),
+            ResourceType::RemoteConferenceVideo => write!(f, "REMOTE-CONFERENCE-VIDEO"),
+            ResourceType::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Helper type for the PROXIMITY property.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Proximity {
+    /// Trigger when arriving at the location
+    Arrive,
+
+    /// Trigger when departing from the location
+    Depart,
+
+    /// Trigger when connecting to a vehicle
+    Connect,
+
+    /// Trigger when disconnecting from a vehicle
+    Disconnect,
+
+    /// An IANA-registered or non-standard proximity
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for Proximity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Proximity::Arrive => write!(f, "ARRIVE"),
+            Proximity::Depart => write!(f, "DEPART"),
+            Proximity::Connect => write!(f, "CONNECT"),
+            Proximity::Disconnect => write!(f, "DISCONNECT"),
+            Proximity::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
 // TODO impl AsValueType<Time>
 
-// TODO impl AsValueType<Uri>
+// See the uri module for AsValueType<Uri>
+
+// See the chrono04, jiff02 and time03 modules for AsValueType<UtcOffset>
 
-// TODO impl AsValueType<UtcOffset>
+/// Format an offset from UTC in seconds according to the UTC-OFFSET grammar
+#[cfg(any(feature = "chrono04", feature = "jiff02", feature = "time03"))]
+fn fmt_utc_offset<W: Write>(w: &mut W, offset_seconds: i32) -> std::fmt::Result {
+    // "-0000" is not allowed, so zero must be formatted as "+0000"
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let offset_seconds = offset_seconds.unsigned_abs();
+
+    let (hours, minutes, seconds) = (
+        offset_seconds / 3600,
+        offset_seconds / 60 % 60,
+        offset_seconds % 60,
+    );
+
+    write!(w, "{sign}{hours:02}{minutes:02}")?;
+    if seconds != 0 {
+        write!(w, "{seconds:02}")?;
+    }
+
+    Ok(())
+}
 
 #[cfg(test)]
 mod test {
@@ -143,4 +965,38 @@ mod test {
         test_case::<Text>("simple text", "simple text");
         test_case::<Text>("escaping is elsewhere;,\n", "escaping is elsewhere;,\n");
     }
+
+    #[test]
+    fn integer() {
+        test_case::<Integer>(39, "39");
+        test_case::<Integer>(&-5i8, "-5");
+        test_case::<Integer>(u32::MAX >> 1, "2147483647");
+        test_case::<Integer>(i64::from(i32::MIN), "-2147483648");
+
+        let mut buf = String::new();
+        assert!(AsValueType::<Integer>::fmt(&(u32::MAX), &mut buf).is_err());
+        assert!(AsValueType::<Integer>::fmt(&(i64::MIN), &mut buf).is_err());
+    }
+
+    #[test]
+    fn float() {
+        test_case::<Float>(37.386013, "37.386013");
+        test_case::<Float>(&-122.082932, "-122.082932");
+        test_case::<Float>(0.1f32, "0.1");
+        test_case::<Float>(1e-7, "0.0000001");
+        test_case::<Float>(1e21, "1000000000000000000000");
+
+        let mut buf = String::new();
+        assert!(AsValueType::<Float>::fmt(&f64::NAN, &mut buf).is_err());
+        assert!(AsValueType::<Float>::fmt(&f32::INFINITY, &mut buf).is_err());
+    }
+
+    #[test]
+    fn tuple() {
+        test_case::<(Float, Float)>((37.386013, -122.082932), "37.386013;-122.082932");
+        test_case::<(Text, Text, Text)>(
+            ("3.1", "Invalid property value", "DTSTART:96-Apr-01"),
+            "3.1;Invalid property value;DTSTART:96-Apr-01",
+        );
+    }
 }
@@ -1,8 +1,62 @@
 use std::fmt::Write;
 
-use crate::structure::value_types::{DateTime, Duration, PeriodOfTime};
+use crate::{
+    structure::value_types::{DateTime, PeriodOfTime, PeriodOfTimeUtc},
+    write::PropertyValueWriter,
+};
 
-use super::AsValueType;
+use super::{AsValueType, ICalDuration, ICalDurationError, ToValueType};
+
+/// Error constructing a value of type [PeriodOfTime]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodError {
+    /// The start of the period MUST be before the end of the period
+    EndNotAfterStart,
+
+    /// The duration of the period MUST be positive
+    DurationNotPositive,
+
+    /// The start and end are not in the same form, or not in the same time
+    /// zone. The parameters of the value, such as TZID, apply to both.
+    FormMismatch,
+
+    /// The duration could not be converted to an [ICalDuration]
+    Duration(ICalDurationError),
+}
+
+impl std::fmt::Display for PeriodError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PeriodError::EndNotAfterStart => {
+                write!(f, "the start of the period must be before the end")
+            }
+            PeriodError::DurationNotPositive => {
+                write!(f, "the duration of the period must be positive")
+            }
+            PeriodError::FormMismatch => {
+                write!(
+                    f,
+                    "the start and end of the period must be in the same form"
+                )
+            }
+            PeriodError::Duration(err) => write!(f, "invalid duration: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PeriodError {}
+
+impl From<ICalDurationError> for PeriodError {
+    fn from(value: ICalDurationError) -> Self {
+        PeriodError::Duration(value)
+    }
+}
+
+impl From<std::convert::Infallible> for PeriodError {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
 
 pub struct PeriodOfTimeStartEndValue<StartT, EndT> {
     start: StartT,
@@ -19,6 +73,18 @@ impl<StartT: AsValueType<DateTime>, EndT: AsValueType<DateTime>> AsValueType<Per
 
         Ok(())
     }
+
+    fn write_params<W: Write>(&self, w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        self.start.write_params(w)
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        self.start.has_same_params(&other.start)
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        self.start.time_zone_reference()
+    }
 }
 
 impl<StartT: AsValueType<DateTime>, EndT: AsValueType<DateTime>> AsValueType<PeriodOfTime>
@@ -27,15 +93,27 @@ impl<StartT: AsValueType<DateTime>, EndT: AsValueType<DateTime>> AsValueType<Per
     fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
         AsValueType::<PeriodOfTime>::fmt(*self, w)
     }
+
+    fn write_params<W: Write>(&self, w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        AsValueType::<PeriodOfTime>::write_params(*self, w)
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        AsValueType::<PeriodOfTime>::has_same_params(*self, *other)
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        AsValueType::<PeriodOfTime>::time_zone_reference(*self)
+    }
 }
 
-pub struct PeriodOfTimeDurationValue<StartT, DurationT> {
+pub struct PeriodOfTimeDurationValue<StartT> {
     start: StartT,
-    duration: DurationT,
+    duration: ICalDuration,
 }
 
-impl<StartT: AsValueType<DateTime>, DurationT: AsValueType<Duration>> AsValueType<PeriodOfTime>
-    for PeriodOfTimeDurationValue<StartT, DurationT>
+impl<StartT: AsValueType<DateTime>> AsValueType<PeriodOfTime>
+    for PeriodOfTimeDurationValue<StartT>
 {
     fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
         self.start.fmt(w)?;
@@ -44,14 +122,38 @@ impl<StartT: AsValueType<DateTime>, DurationT: AsValueType<Duration>> AsValueTyp
 
         Ok(())
     }
+
+    fn write_params<W: Write>(&self, w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        self.start.write_params(w)
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        self.start.has_same_params(&other.start)
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        self.start.time_zone_reference()
+    }
 }
 
-impl<StartT: AsValueType<DateTime>, DurationT: AsValueType<Duration>> AsValueType<PeriodOfTime>
-    for &PeriodOfTimeDurationValue<StartT, DurationT>
+impl<StartT: AsValueType<DateTime>> AsValueType<PeriodOfTime>
+    for &PeriodOfTimeDurationValue<StartT>
 {
     fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
         AsValueType::<PeriodOfTime>::fmt(*self, w)
     }
+
+    fn write_params<W: Write>(&self, w: &mut PropertyValueWriter<'_, '_, W>) -> std::fmt::Result {
+        AsValueType::<PeriodOfTime>::write_params(*self, w)
+    }
+
+    fn has_same_params(&self, other: &Self) -> bool {
+        AsValueType::<PeriodOfTime>::has_same_params(*self, *other)
+    }
+
+    fn time_zone_reference(&self) -> Option<(&str, i64)> {
+        AsValueType::<PeriodOfTime>::time_zone_reference(*self)
+    }
 }
 
 pub struct PeriodOfTimeBuilder<StartT> {
@@ -63,25 +165,456 @@ impl<StartT: AsValueType<DateTime>> PeriodOfTimeBuilder<StartT> {
         Self { start }
     }
 
+    /// A period from `start` to `end`. The start MUST be before the end, and
+    /// both must be in the same form: floating, UTC, or local time in the
+    /// same time zone.
     pub fn end<EndT: AsValueType<DateTime>>(
         self,
         end: EndT,
-    ) -> PeriodOfTimeStartEndValue<StartT, EndT> {
-        // TODO validate that start is earlier than end
-        PeriodOfTimeStartEndValue {
+    ) -> Result<PeriodOfTimeStartEndValue<StartT, EndT>, PeriodError> {
+        let (start_zone, end_zone) = (self.start.time_zone_reference(), end.time_zone_reference());
+        if self.start.is_utc() != end.is_utc()
+            || start_zone.map(|(tzid, _)| tzid) != end_zone.map(|(tzid, _)| tzid)
+        {
+            return Err(PeriodError::FormMismatch);
+        }
+
+        // Values with a time zone reference are compared by their instant.
+        // Otherwise, both are in the floating or the UTC form and are
+        // compared by their date and time as written. A value that does not
+        // adhere to the DATE-TIME grammar yields an error when it is written
+        // instead.
+        let end_not_after_start = match (start_zone, end_zone) {
+            (Some((_, start)), Some((_, end))) => start >= end,
+            _ => matches!(
+                (CivilDateTime::of(&self.start), CivilDateTime::of(&end)),
+                (Some(start), Some(end)) if start >= end
+            ),
+        };
+        if end_not_after_start {
+            return Err(PeriodError::EndNotAfterStart);
+        }
+
+        Ok(PeriodOfTimeStartEndValue {
             start: self.start,
             end,
-        }
+        })
     }
 
-    pub fn duration<DurationT: AsValueType<Duration>>(
+    /// A period from `start` lasting for `duration`. The duration MUST be
+    /// positive.
+    pub fn duration<DurationT>(
         self,
         duration: DurationT,
-    ) -> PeriodOfTimeDurationValue<StartT, DurationT> {
-        // TODO validate that duration is non-negative (and non-zero?)
-        PeriodOfTimeDurationValue {
+    ) -> Result<PeriodOfTimeDurationValue<StartT>, PeriodError>
+    where
+        DurationT: TryInto<ICalDuration>,
+        PeriodError: From<DurationT::Error>,
+    {
+        let duration = duration.try_into()?;
+        if duration.negative || duration.is_zero() {
+            return Err(PeriodError::DurationNotPositive);
+        }
+
+        Ok(PeriodOfTimeDurationValue {
             start: self.start,
             duration,
+        })
+    }
+}
+
+/// A point in time that can be represented in the _UTC_ form of a DateTime,
+/// as a UNIX timestamp. Fractional seconds are truncated.
+pub trait UtcInstant {
+    fn unix_seconds(&self) -> i64;
+}
+
+impl<T: UtcInstant> UtcInstant for &T {
+    fn unix_seconds(&self) -> i64 {
+        (*self).unix_seconds()
+    }
+}
+
+/// A period of time in UTC, as required by the FREEBUSY property
+///
+/// Unlike [PeriodOfTimeBuilder], this keeps the start and end as UNIX
+/// timestamps, so periods can be compared and merged with
+/// [PeriodUtc::merge].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeriodUtc {
+    start: i64,
+    end: i64,
+
+    /// The duration as given, if the period was constructed with a duration
+    duration: Option<ICalDuration>,
+}
+
+impl PeriodUtc {
+    /// A period from `start` to `end`. The start MUST be before the end.
+    pub fn new(start: impl UtcInstant, end: impl UtcInstant) -> Result<Self, PeriodError> {
+        let (start, end) = (start.unix_seconds(), end.unix_seconds());
+        if start >= end {
+            return Err(PeriodError::EndNotAfterStart);
+        }
+
+        Ok(Self {
+            start,
+            end,
+            duration: None,
+        })
+    }
+
+    /// A period from `start` lasting for `duration`. The duration MUST be
+    /// positive. In UTC, a nominal day always lasts 24 hours.
+    pub fn with_duration<DurationT>(
+        start: impl UtcInstant,
+        duration: DurationT,
+    ) -> Result<Self, PeriodError>
+    where
+        DurationT: TryInto<ICalDuration>,
+        PeriodError: From<DurationT::Error>,
+    {
+        let duration = duration.try_into()?;
+        if duration.negative || duration.is_zero() {
+            return Err(PeriodError::DurationNotPositive);
+        }
+
+        let days = duration.weeks as i64 * 7 + duration.days as i64;
+        let exact = ICalDuration {
+            weeks: 0,
+            days: 0,
+            ..duration
+        };
+        let start = start.unix_seconds();
+        let end = (days * 86400)
+            .checked_add(exact.to_exact_seconds()?)
+            .and_then(|x| x.checked_add(start))
+            .ok_or(ICalDurationError::OutOfRange)?;
+
+        Ok(Self {
+            start,
+            end,
+            duration: Some(duration),
+        })
+    }
+
+    /// The start of the period, as a UNIX timestamp
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    /// The end of the period, as a UNIX timestamp
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    /// Sort the periods by start, and merge periods that overlap or are
+    /// adjacent. Merged periods are given by their start and end.
+    ///
+    /// This is appropriate for the values of the FREEBUSY property, which
+    /// SHOULD be ordered and SHOULD NOT overlap.
+    pub fn merge(periods: impl IntoIterator<Item = PeriodUtc>) -> Vec<PeriodUtc> {
+        let mut periods: Vec<_> = periods.into_iter().collect();
+        periods.sort_by_key(|x| (x.start, x.end));
+
+        let mut merged: Vec<PeriodUtc> = Vec::with_capacity(periods.len());
+        for period in periods {
+            match merged.last_mut() {
+                Some(last) if period.start <= last.end => {
+                    if period.end > last.end {
+                        last.end = period.end;
+                        last.duration = None;
+                    }
+                }
+                _ => merged.push(period),
+            }
+        }
+
+        merged
+    }
+}
+
+/// Format a UNIX timestamp in the _UTC_ form of a DateTime
+pub(super) fn fmt_unix_seconds<W: Write>(w: &mut W, timestamp: i64) -> std::fmt::Result {
+    let (days, seconds) = (timestamp.div_euclid(86400), timestamp.rem_euclid(86400));
+
+    // Convert days since the UNIX epoch to a date in the proleptic Gregorian
+    // calendar, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    if !(0..=9999).contains(&year) {
+        return Err(std::fmt::Error);
+    }
+
+    write!(
+        w,
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// A DATE, or a DATE-TIME in the floating or the UTC form, as its calendar
+/// date and wall clock time. Values of the same form are ordered
+/// chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct CivilDateTime {
+    date: (i64, i64, i64),
+    time: Option<(i64, i64, i64)>,
+}
+
+impl CivilDateTime {
+    /// The value as written, if it adheres to the DATE-TIME grammar
+    fn of(value: &impl AsValueType<DateTime>) -> Option<Self> {
+        let mut buf = String::new();
+        value.fmt(&mut buf).ok()?;
+        Self::parse(&buf)
+    }
+
+    /// Parse a DATE, or a DATE-TIME in the floating or the UTC form
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let number = |range: std::ops::Range<usize>| -> Option<i64> {
+            let digits = s.get(range)?;
+            digits
+                .bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| digits.parse().ok())?
+        };
+
+        let date = (number(0..4)?, number(4..6)?, number(6..8)?);
+        if !(1..=12).contains(&date.1) || !(1..=31).contains(&date.2) {
+            return None;
+        }
+
+        let time = match s.len() {
+            8 => None,
+            15 | 16
+                if s.as_bytes()[8] == b'T'
+                    && s.get(15..).is_some_and(|z| z.is_empty() || z == "Z") =>
+            {
+                let time = (number(9..11)?, number(11..13)?, number(13..15)?);
+                if time.0 > 23 || time.1 > 59 || time.2 > 60 {
+                    return None;
+                }
+                Some(time)
+            }
+            _ => return None,
+        };
+
+        Some(Self { date, time })
+    }
+
+    /// The UNIX timestamp of a DATE-TIME, taking the wall clock time as UTC
+    pub(crate) fn unix_seconds(self) -> Option<i64> {
+        let (year, month, day) = self.date;
+        let (hour, minute, second) = self.time?;
+
+        // The inverse of the conversion in fmt_unix_seconds
+        let year = year - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+
+        Some(days * 86400 + hour * 3600 + minute * 60 + second)
+    }
+}
+
+impl AsValueType<PeriodOfTimeUtc> for PeriodUtc {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        fmt_unix_seconds(w, self.start)?;
+        write!(w, "/")?;
+        match &self.duration {
+            Some(duration) => duration.fmt(w),
+            None => fmt_unix_seconds(w, self.end),
+        }
+    }
+}
+
+impl AsValueType<PeriodOfTimeUtc> for &PeriodUtc {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<PeriodOfTimeUtc>::fmt(*self, w)
+    }
+}
+
+impl AsValueType<PeriodOfTime> for PeriodUtc {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<PeriodOfTimeUtc>::fmt(self, w)
+    }
+}
+
+impl AsValueType<PeriodOfTime> for &PeriodUtc {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<PeriodOfTimeUtc>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for PeriodUtc {
+    type ValueType = PeriodOfTime;
+}
+
+impl ToValueType for &PeriodUtc {
+    type ValueType = PeriodOfTime;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    impl UtcInstant for i64 {
+        fn unix_seconds(&self) -> i64 {
+            *self
         }
     }
+
+    fn test_case(v: impl AsValueType<PeriodOfTimeUtc>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<PeriodOfTimeUtc>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    // 1997-01-01T18:00:00Z
+    const START: i64 = 852141600;
+    const HOUR: i64 = 3600;
+
+    #[test]
+    fn period_utc() {
+        test_case(
+            PeriodUtc::new(START, START + 13 * HOUR).unwrap(),
+            "19970101T180000Z/19970102T070000Z",
+        );
+        test_case(
+            PeriodUtc::with_duration(
+                START,
+                ICalDuration {
+                    hours: 5,
+                    minutes: 30,
+                    ..ICalDuration::ZERO
+                },
+            )
+            .unwrap(),
+            "19970101T180000Z/PT5H30M",
+        );
+        test_case(
+            PeriodUtc::new(-1, 0).unwrap(),
+            "19691231T235959Z/19700101T000000Z",
+        );
+        test_case(
+            PeriodUtc::new(951782400, 951868800).unwrap(),
+            "20000229T000000Z/20000301T000000Z",
+        );
+    }
+
+    #[test]
+    fn unix_seconds() {
+        for timestamp in [START, -1, 0, 951782400, 951868800, 253402300799] {
+            let mut buf = String::new();
+            fmt_unix_seconds(&mut buf, timestamp).unwrap();
+            let civil = CivilDateTime::parse(&buf).unwrap();
+            assert_eq!(civil.unix_seconds(), Some(timestamp), "{buf}");
+        }
+        assert_eq!(
+            CivilDateTime::parse("19970101").unwrap().unix_seconds(),
+            None
+        );
+    }
+
+    #[test]
+    fn civil_date_time() {
+        let parse = |s| CivilDateTime::parse(s).unwrap();
+        assert!(parse("19970101") < parse("19970102"));
+        assert!(parse("19970101T180000") < parse("19970101T180001"));
+        assert!(parse("19971231T235959") < parse("19980101T000000"));
+        assert_eq!(parse("19970101T180000"), parse("19970101T180000Z"));
+
+        for invalid in [
+            "1997010+",
+            "1997011",
+            "19971301",
+            "19970100",
+            "19970101T",
+            "19970101T240000",
+            "19970101 180000",
+            "19970101T180000+01",
+            "19970101Z",
+        ] {
+            assert_eq!(CivilDateTime::parse(invalid), None, "{invalid}");
+        }
+
+        struct Floating(&'static str);
+        impl AsValueType<DateTime> for Floating {
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "{}", self.0)
+            }
+        }
+
+        assert!(PeriodOfTimeBuilder::start(Floating("19970101T180000"))
+            .end(Floating("19970102T070000"))
+            .is_ok());
+        assert_eq!(
+            PeriodOfTimeBuilder::start(Floating("19970102T070000"))
+                .end(Floating("19970101T180000"))
+                .err(),
+            Some(PeriodError::EndNotAfterStart)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            PeriodUtc::new(START, START),
+            Err(PeriodError::EndNotAfterStart)
+        );
+        assert_eq!(
+            PeriodUtc::with_duration(START, -ICalDuration::hours(1)),
+            Err(PeriodError::DurationNotPositive)
+        );
+        assert_eq!(
+            PeriodUtc::with_duration(START, ICalDuration::ZERO),
+            Err(PeriodError::DurationNotPositive)
+        );
+
+        struct Start;
+        impl AsValueType<DateTime> for Start {
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "19970101T180000Z")
+            }
+        }
+
+        assert_eq!(
+            PeriodOfTimeBuilder::start(Start)
+                .duration(-ICalDuration::days(1))
+                .err(),
+            Some(PeriodError::DurationNotPositive)
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let a = PeriodUtc::new(START, START + 2 * HOUR).unwrap();
+        let b = PeriodUtc::with_duration(START + HOUR, ICalDuration::hours(2)).unwrap();
+        let c = PeriodUtc::with_duration(START + 3 * HOUR, ICalDuration::hours(1)).unwrap();
+        let d = PeriodUtc::with_duration(START + 5 * HOUR, ICalDuration::days(1)).unwrap();
+        let e = PeriodUtc::new(START + 6 * HOUR, START + 7 * HOUR).unwrap();
+
+        let merged = PeriodUtc::merge([e, d, c, b, a]);
+        assert_eq!(
+            merged,
+            [PeriodUtc::new(START, START + 4 * HOUR).unwrap(), d,]
+        );
+
+        test_case(merged[0], "19970101T180000Z/19970101T220000Z");
+        test_case(merged[1], "19970101T230000Z/P1D");
+    }
 }
@@ -0,0 +1,631 @@
+use std::fmt::Write;
+
+use super::{AsValueType, ToValueType};
+use crate::structure::value_types::{Date, DateTime, DateTimeUtc, RecurrenceRule};
+
+/// The FREQ rule part of a recurrence rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn as_str(self) -> &'static str {
+        match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// A day of the week, as used in the BYDAY and WKST rule parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// The `n`th occurrence of this weekday within the month or year. Negative
+    /// values count from the end, so `Weekday::Sunday.nth(-1)` is the last
+    /// Sunday.
+    pub fn nth(self, n: i8) -> WeekdayNum {
+        WeekdayNum {
+            ordinal: Some(n),
+            weekday: self,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Weekday::Monday => "MO",
+            Weekday::Tuesday => "TU",
+            Weekday::Wednesday => "WE",
+            Weekday::Thursday => "TH",
+            Weekday::Friday => "FR",
+            Weekday::Saturday => "SA",
+            Weekday::Sunday => "SU",
+        }
+    }
+}
+
+/// An element of the BYDAY rule part: a weekday, optionally with an ordinal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekdayNum {
+    pub ordinal: Option<i8>,
+    pub weekday: Weekday,
+}
+
+impl From<Weekday> for WeekdayNum {
+    fn from(weekday: Weekday) -> Self {
+        WeekdayNum {
+            ordinal: None,
+            weekday,
+        }
+    }
+}
+
+/// An element of the BYMONTH rule part: a month number, which can be a leap
+/// month in calendar systems that have them. Leap months require
+/// [Recur::rscale].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MonthNum {
+    pub month: u8,
+    pub leap: bool,
+}
+
+impl MonthNum {
+    /// The leap month that follows the given month, such as `5L` for the
+    /// Hebrew month Adar I, which precedes the regular Adar (month 6)
+    pub fn leap(month: u8) -> Self {
+        MonthNum { month, leap: true }
+    }
+}
+
+impl From<u8> for MonthNum {
+    fn from(month: u8) -> Self {
+        MonthNum { month, leap: false }
+    }
+}
+
+/// The calendar system of the RSCALE rule part, named as in the Unicode
+/// Common Locale Data Repository (CLDR)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RecurrenceScale {
+    Gregorian,
+    Hebrew,
+    Chinese,
+    Dangi,
+    Islamic,
+    IslamicCivil,
+    IslamicUmalqura,
+    Persian,
+    Indian,
+    Coptic,
+    Ethiopic,
+
+    /// Another CLDR-registered or non-standard calendar system
+    Other(crate::write::DynamicName),
+}
+
+impl std::fmt::Display for RecurrenceScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceScale::Gregorian => write!(f, "GREGORIAN"),
+            RecurrenceScale::Hebrew => write!(f, "HEBREW"),
+            RecurrenceScale::Chinese => write!(f, "CHINESE"),
+            RecurrenceScale::Dangi => write!(f, "DANGI"),
+            RecurrenceScale::Islamic => write!(f, "ISLAMIC"),
+            RecurrenceScale::IslamicCivil => write!(f, "ISLAMIC-CIVIL"),
+            RecurrenceScale::IslamicUmalqura => write!(f, "ISLAMIC-UMALQURA"),
+            RecurrenceScale::Persian => write!(f, "PERSIAN"),
+            RecurrenceScale::Indian => write!(f, "INDIAN"),
+            RecurrenceScale::Coptic => write!(f, "COPTIC"),
+            RecurrenceScale::Ethiopic => write!(f, "ETHIOPIC"),
+            RecurrenceScale::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The SKIP rule part, which determines how instances that fall on an
+/// invalid date are handled, such as February 30 or a leap month in a year
+/// that does not have one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Skip {
+    /// Leave out the instance
+    #[default]
+    Omit,
+
+    /// Move the instance to the previous valid day or month
+    Backward,
+
+    /// Move the instance to the next valid day or month
+    Forward,
+}
+
+impl Skip {
+    fn as_str(self) -> &'static str {
+        match self {
+            Skip::Omit => "OMIT",
+            Skip::Backward => "BACKWARD",
+            Skip::Forward => "FORWARD",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum End {
+    Count(u32),
+    Until(Result<String, std::fmt::Error>),
+}
+
+/// Builder for values of type [RecurrenceRule]
+///
+/// The rule parts are validated against the ranges given in RFC 5545 when
+/// the value is written, and invalid rules yield `Result::Err`.
+///
+/// ```
+/// use ical_syntax::write::value_types::{Frequency, Recur, Weekday};
+///
+/// // The last Sunday of October, every year
+/// let rule = Recur::new(Frequency::Yearly)
+///     .by_month([10])
+///     .by_day([Weekday::Sunday.nth(-1)]);
+/// ```
+///
+/// Rules in other calendar systems are given with [Recur::rscale]:
+///
+/// ```
+/// use ical_syntax::write::value_types::{Frequency, MonthNum, Recur, RecurrenceScale, Skip};
+///
+/// // Adar I 8 in leap years, and Adar 8 in other years
+/// let rule = Recur::new(Frequency::Yearly)
+///     .rscale(RecurrenceScale::Hebrew)
+///     .by_month_num([MonthNum::leap(5)])
+///     .by_month_day([8])
+///     .skip(Skip::Forward);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recur {
+    freq: Frequency,
+    rscale: Option<RecurrenceScale>,
+    skip: Option<Skip>,
+    end: Option<End>,
+    interval: Option<u32>,
+    by_second: Vec<u8>,
+    by_minute: Vec<u8>,
+    by_hour: Vec<u8>,
+    by_day: Vec<WeekdayNum>,
+    by_month_day: Vec<i8>,
+    by_year_day: Vec<i16>,
+    by_week_no: Vec<i8>,
+    by_month: Vec<MonthNum>,
+    by_set_pos: Vec<i16>,
+    wkst: Option<Weekday>,
+}
+
+impl Recur {
+    pub fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            rscale: None,
+            skip: None,
+            end: None,
+            interval: None,
+            by_second: vec![],
+            by_minute: vec![],
+            by_hour: vec![],
+            by_day: vec![],
+            by_month_day: vec![],
+            by_year_day: vec![],
+            by_week_no: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            wkst: None,
+        }
+    }
+
+    /// Bound the recurrence to `count` occurrences. This replaces any
+    /// previously given UNTIL.
+    pub fn count(mut self, count: u32) -> Self {
+        self.end = Some(End::Count(count));
+        self
+    }
+
+    /// Bound the recurrence by a date with UTC time. This is the required
+    /// form when DTSTART is a date with UTC time or a date with local time
+    /// and time zone reference, and in STANDARD and DAYLIGHT sub-components.
+    ///
+    /// This replaces any previously given UNTIL or COUNT.
+    pub fn until(self, until: impl AsValueType<DateTimeUtc>) -> Self {
+        self.until_value(until)
+    }
+
+    /// Bound the recurrence by a date, for use when DTSTART is a DATE.
+    ///
+    /// This replaces any previously given UNTIL or COUNT.
+    pub fn until_date(self, until: impl AsValueType<Date>) -> Self {
+        self.until_value(until)
+    }
+
+    /// Bound the recurrence by a date with local time, for use when DTSTART
+    /// is a date with local time. Values with a time zone reference cannot be
+    /// represented here, and will yield `Result::Err` when written.
+    ///
+    /// This replaces any previously given UNTIL or COUNT.
+    pub fn until_floating(self, until: impl AsValueType<DateTime>) -> Self {
+        self.until_value(until)
+    }
+
+    fn until_value<V: crate::structure::ValueType>(mut self, until: impl AsValueType<V>) -> Self {
+        let formatted = if until.time_zone_reference().is_some() {
+            Err(std::fmt::Error)
+        } else {
+            let mut buf = String::new();
+            until.fmt(&mut buf).map(|()| buf)
+        };
+        self.end = Some(End::Until(formatted));
+        self
+    }
+
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn by_second(mut self, seconds: impl IntoIterator<Item = u8>) -> Self {
+        self.by_second = seconds.into_iter().collect();
+        self
+    }
+
+    pub fn by_minute(mut self, minutes: impl IntoIterator<Item = u8>) -> Self {
+        self.by_minute = minutes.into_iter().collect();
+        self
+    }
+
+    pub fn by_hour(mut self, hours: impl IntoIterator<Item = u8>) -> Self {
+        self.by_hour = hours.into_iter().collect();
+        self
+    }
+
+    pub fn by_day(mut self, days: impl IntoIterator<Item = impl Into<WeekdayNum>>) -> Self {
+        self.by_day = days.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn by_month_day(mut self, days: impl IntoIterator<Item = i8>) -> Self {
+        self.by_month_day = days.into_iter().collect();
+        self
+    }
+
+    pub fn by_year_day(mut self, days: impl IntoIterator<Item = i16>) -> Self {
+        self.by_year_day = days.into_iter().collect();
+        self
+    }
+
+    pub fn by_week_no(mut self, weeks: impl IntoIterator<Item = i8>) -> Self {
+        self.by_week_no = weeks.into_iter().collect();
+        self
+    }
+
+    pub fn by_month(mut self, months: impl IntoIterator<Item = u8>) -> Self {
+        self.by_month = months.into_iter().map(MonthNum::from).collect();
+        self
+    }
+
+    /// Like [Recur::by_month], but allowing leap months, see [MonthNum::leap]
+    pub fn by_month_num(mut self, months: impl IntoIterator<Item = MonthNum>) -> Self {
+        self.by_month = months.into_iter().collect();
+        self
+    }
+
+    pub fn by_set_pos(mut self, positions: impl IntoIterator<Item = i16>) -> Self {
+        self.by_set_pos = positions.into_iter().collect();
+        self
+    }
+
+    pub fn wkst(mut self, weekday: Weekday) -> Self {
+        self.wkst = Some(weekday);
+        self
+    }
+
+    /// Interpret the rule in the given calendar system, rather than in the
+    /// calendar system of DTSTART. This is required for leap months and
+    /// [Recur::skip].
+    pub fn rscale(mut self, scale: RecurrenceScale) -> Self {
+        self.rscale = Some(scale);
+        self
+    }
+
+    /// How to handle instances that fall on an invalid date. This requires
+    /// [Recur::rscale], which can be [RecurrenceScale::Gregorian] to handle,
+    /// for example, February 30 in a Gregorian rule.
+    pub fn skip(mut self, skip: Skip) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    /// Check the rule parts against the constraints of RFC 5545 3.3.10 and
+    /// RFC 7529 4.1
+    fn is_valid(&self) -> bool {
+        fn in_range<T: Copy + PartialOrd>(values: &[T], min: T, max: T) -> bool {
+            values.iter().all(|&x| min <= x && x <= max)
+        }
+
+        fn signed_in_range<T: Copy + PartialOrd + Default + std::ops::Neg<Output = T>>(
+            values: &[T],
+            max: T,
+        ) -> bool {
+            values
+                .iter()
+                .all(|&x| x != T::default() && -max <= x && x <= max)
+        }
+
+        use Frequency::*;
+        let freq = self.freq;
+
+        // Calendar systems such as the Coptic and Ethiopic have 13 months
+        let has_rscale = self.rscale.is_some();
+        let max_month = if has_rscale { 13 } else { 12 };
+
+        let has_ordinal_day = self.by_day.iter().any(|x| x.ordinal.is_some());
+        let has_other_by = !(self.by_second.is_empty()
+            && self.by_minute.is_empty()
+            && self.by_hour.is_empty()
+            && self.by_day.is_empty()
+            && self.by_month_day.is_empty()
+            && self.by_year_day.is_empty()
+            && self.by_week_no.is_empty()
+            && self.by_month.is_empty());
+
+        self.interval != Some(0)
+            && in_range(&self.by_second, 0, 60)
+            && in_range(&self.by_minute, 0, 59)
+            && in_range(&self.by_hour, 0, 23)
+            && self
+                .by_day
+                .iter()
+                .all(|x| x.ordinal.is_none_or(|n| n != 0 && (-53..=53).contains(&n)))
+            && signed_in_range(&self.by_month_day, 31)
+            && signed_in_range(&self.by_year_day, 366)
+            && signed_in_range(&self.by_week_no, 53)
+            && self
+                .by_month
+                .iter()
+                .all(|x| (1..=max_month).contains(&x.month) && (!x.leap || has_rscale))
+            && (self.skip.is_none() || has_rscale)
+            && signed_in_range(&self.by_set_pos, 366)
+            && (!has_ordinal_day || matches!(freq, Monthly | Yearly))
+            && (!has_ordinal_day || self.by_week_no.is_empty())
+            && (self.by_month_day.is_empty() || freq != Weekly)
+            && (self.by_year_day.is_empty() || !matches!(freq, Daily | Weekly | Monthly))
+            && (self.by_week_no.is_empty() || freq == Yearly)
+            && (self.by_set_pos.is_empty() || has_other_by)
+    }
+}
+
+fn write_list<W: Write, T>(
+    w: &mut W,
+    name: &str,
+    values: &[T],
+    mut item: impl FnMut(&mut W, &T) -> std::fmt::Result,
+) -> std::fmt::Result {
+    let Some((first, rest)) = values.split_first() else {
+        return Ok(());
+    };
+
+    write!(w, ";{name}=")?;
+    item(w, first)?;
+    for x in rest {
+        w.write_char(',')?;
+        item(w, x)?;
+    }
+    Ok(())
+}
+
+impl AsValueType<RecurrenceRule> for Recur {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        if !self.is_valid() {
+            return Err(std::fmt::Error);
+        }
+
+        // FREQ MUST be the first rule part for backward compatibility
+        write!(w, "FREQ={}", self.freq.as_str())?;
+
+        if let Some(rscale) = &self.rscale {
+            write!(w, ";RSCALE={rscale}")?;
+        }
+
+        match &self.end {
+            Some(End::Count(count)) => write!(w, ";COUNT={count}")?,
+            Some(End::Until(until)) => write!(w, ";UNTIL={}", until.as_ref().map_err(|&e| e)?)?,
+            None => (),
+        }
+
+        if let Some(interval) = self.interval {
+            write!(w, ";INTERVAL={interval}")?;
+        }
+
+        let display = |w: &mut W, x: &dyn std::fmt::Display| write!(w, "{x}");
+        write_list(w, "BYSECOND", &self.by_second, |w, x| display(w, x))?;
+        write_list(w, "BYMINUTE", &self.by_minute, |w, x| display(w, x))?;
+        write_list(w, "BYHOUR", &self.by_hour, |w, x| display(w, x))?;
+        write_list(w, "BYDAY", &self.by_day, |w, x| {
+            if let Some(n) = x.ordinal {
+                write!(w, "{n}")?;
+            }
+            w.write_str(x.weekday.as_str())
+        })?;
+        write_list(w, "BYMONTHDAY", &self.by_month_day, |w, x| display(w, x))?;
+        write_list(w, "BYYEARDAY", &self.by_year_day, |w, x| display(w, x))?;
+        write_list(w, "BYWEEKNO", &self.by_week_no, |w, x| display(w, x))?;
+        write_list(w, "BYMONTH", &self.by_month, |w, x| {
+            write!(w, "{}", x.month)?;
+            if x.leap {
+                w.write_char('L')?;
+            }
+            Ok(())
+        })?;
+        write_list(w, "BYSETPOS", &self.by_set_pos, |w, x| display(w, x))?;
+
+        if let Some(skip) = self.skip {
+            write!(w, ";SKIP={}", skip.as_str())?;
+        }
+
+        if let Some(wkst) = self.wkst {
+            write!(w, ";WKST={}", wkst.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AsValueType<RecurrenceRule> for &Recur {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<RecurrenceRule>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for Recur {
+    type ValueType = RecurrenceRule;
+}
+
+impl ToValueType for &Recur {
+    type ValueType = RecurrenceRule;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_case(v: impl AsValueType<RecurrenceRule>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<RecurrenceRule>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    fn is_err(v: impl AsValueType<RecurrenceRule>) -> bool {
+        let mut buf = String::new();
+        AsValueType::<RecurrenceRule>::fmt(&v, &mut buf).is_err()
+    }
+
+    #[test]
+    fn recur() {
+        test_case(
+            Recur::new(Frequency::Daily).count(10).interval(2),
+            "FREQ=DAILY;COUNT=10;INTERVAL=2",
+        );
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        test_case(
+            &Recur::new(Frequency::Yearly)
+                .by_month([10])
+                .by_day([Weekday::Sunday.nth(-1)]),
+            "FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10",
+        );
+        test_case(
+            Recur::new(Frequency::Monthly)
+                .by_day([
+                    Weekday::Monday,
+                    Weekday::Tuesday,
+                    Weekday::Wednesday,
+                    Weekday::Thursday,
+                    Weekday::Friday,
+                ])
+                .by_set_pos([-1]),
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+        );
+        test_case(
+            Recur::new(Frequency::Yearly)
+                .interval(2)
+                .by_month([1])
+                .by_day([Weekday::Sunday])
+                .by_hour([8, 9])
+                .by_minute([30])
+                .wkst(Weekday::Sunday),
+            "FREQ=YEARLY;INTERVAL=2;BYMINUTE=30;BYHOUR=8,9;BYDAY=SU;BYMONTH=1;WKST=SU",
+        );
+    }
+
+    #[test]
+    fn rscale() {
+        test_case(
+            Recur::new(Frequency::Yearly)
+                .rscale(RecurrenceScale::Hebrew)
+                .by_month_num([MonthNum::leap(5)])
+                .by_month_day([8])
+                .skip(Skip::Forward),
+            "FREQ=YEARLY;RSCALE=HEBREW;BYMONTHDAY=8;BYMONTH=5L;SKIP=FORWARD",
+        );
+        test_case(
+            Recur::new(Frequency::Monthly)
+                .rscale(RecurrenceScale::Gregorian)
+                .by_month_day([30])
+                .skip(Skip::Backward)
+                .count(12),
+            "FREQ=MONTHLY;RSCALE=GREGORIAN;COUNT=12;BYMONTHDAY=30;SKIP=BACKWARD",
+        );
+        test_case(
+            Recur::new(Frequency::Yearly)
+                .rscale(RecurrenceScale::Ethiopic)
+                .by_month([13]),
+            "FREQ=YEARLY;RSCALE=ETHIOPIC;BYMONTH=13",
+        );
+    }
+
+    #[cfg(feature = "chrono04")]
+    #[test]
+    fn until() {
+        let until = chrono::DateTime::parse_from_rfc3339("1998-04-04T07:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        test_case(
+            Recur::new(Frequency::Yearly).count(3).until(until),
+            "FREQ=YEARLY;UNTIL=19980404T070000Z",
+        );
+        test_case(
+            Recur::new(Frequency::Yearly).until_date(until.date_naive()),
+            "FREQ=YEARLY;UNTIL=19980404",
+        );
+        test_case(
+            Recur::new(Frequency::Yearly).until_floating(until.naive_utc()),
+            "FREQ=YEARLY;UNTIL=19980404T070000",
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(is_err(Recur::new(Frequency::Daily).interval(0)));
+        assert!(is_err(Recur::new(Frequency::Daily).by_hour([24])));
+        assert!(is_err(Recur::new(Frequency::Yearly).by_month([0])));
+        assert!(is_err(Recur::new(Frequency::Yearly).by_month_day([0])));
+        assert!(is_err(Recur::new(Frequency::Weekly).by_month_day([1])));
+        assert!(is_err(
+            Recur::new(Frequency::Weekly).by_day([Weekday::Monday.nth(1)])
+        ));
+        assert!(is_err(Recur::new(Frequency::Monthly).by_week_no([1])));
+        assert!(is_err(Recur::new(Frequency::Monthly).by_year_day([1])));
+        assert!(is_err(Recur::new(Frequency::Monthly).by_set_pos([1])));
+        assert!(is_err(Recur::new(Frequency::Yearly).by_month([13])));
+        assert!(is_err(
+            Recur::new(Frequency::Yearly).by_month_num([MonthNum::leap(5)])
+        ));
+        assert!(is_err(Recur::new(Frequency::Monthly).skip(Skip::Omit)));
+    }
+}
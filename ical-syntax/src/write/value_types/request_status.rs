@@ -0,0 +1,323 @@
+use std::fmt::Write;
+
+use crate::{
+    structure::{composite_value_types::Any2, value_types::Text},
+    write::{composite_value_types::AsCompositeValueType, PropertyValueWriter},
+};
+
+/// Error for status codes that cannot be used in REQUEST-STATUS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusCodeError {
+    /// The first level of the status code is not one of the defined classes,
+    /// `1` to `5`
+    InvalidClass(u8),
+}
+
+impl std::fmt::Display for StatusCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusCodeError::InvalidClass(class) => {
+                write!(f, "invalid request status class: {class}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StatusCodeError {}
+
+/// The hierarchical, numeric status code of REQUEST-STATUS, such as `3.7`
+///
+/// The first level is the class of the status:
+///
+/// - `1`: Preliminary success. The request has been received, but processing
+///   has not finished.
+/// - `2`: Successful. The request was completed, possibly with fallbacks.
+/// - `3`: Client error. The request was not completed because of a problem
+///   with the request itself.
+/// - `4`: Scheduling error. The request was not completed because of a
+///   problem with the calendar service or the calendar user.
+/// - `5`: Request not processed ([RFC 5546
+///   3.6](https://www.rfc-editor.org/rfc/rfc5546#section-3.6)).
+///
+/// The second and the optional third level provide for successive levels of
+/// granularity. The status codes defined by RFC 5546 are available as
+/// associated constants, and their descriptions through
+/// [StatusCode::description].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StatusCode {
+    class: u8,
+    code: u16,
+    detail: Option<u16>,
+}
+
+impl StatusCode {
+    pub const SUCCESS: Self = Self::known(2, 0);
+    pub const SUCCESS_FALLBACK: Self = Self::known(2, 1);
+    pub const SUCCESS_INVALID_PROPERTY_IGNORED: Self = Self::known(2, 2);
+    pub const SUCCESS_INVALID_PARAMETER_IGNORED: Self = Self::known(2, 3);
+    pub const SUCCESS_UNKNOWN_PROPERTY_IGNORED: Self = Self::known(2, 4);
+    pub const SUCCESS_UNKNOWN_PROPERTY_VALUE_IGNORED: Self = Self::known(2, 5);
+    pub const SUCCESS_INVALID_COMPONENT_IGNORED: Self = Self::known(2, 6);
+    pub const SUCCESS_FORWARDED: Self = Self::known(2, 7);
+    pub const SUCCESS_REPEATING_EVENT_IGNORED: Self = Self::known(2, 8);
+    pub const SUCCESS_END_TRUNCATED: Self = Self::known(2, 9);
+    pub const SUCCESS_REPEATING_TODO_IGNORED: Self = Self::known(2, 10);
+    pub const SUCCESS_RRULE_CLIPPED: Self = Self::known(2, 11);
+    pub const INVALID_PROPERTY_NAME: Self = Self::known(3, 0);
+    pub const INVALID_PROPERTY_VALUE: Self = Self::known(3, 1);
+    pub const INVALID_PROPERTY_PARAMETER: Self = Self::known(3, 2);
+    pub const INVALID_PROPERTY_PARAMETER_VALUE: Self = Self::known(3, 3);
+    pub const INVALID_COMPONENT_SEQUENCE: Self = Self::known(3, 4);
+    pub const INVALID_DATE_OR_TIME: Self = Self::known(3, 5);
+    pub const INVALID_RULE: Self = Self::known(3, 6);
+    pub const INVALID_CALENDAR_USER: Self = Self::known(3, 7);
+    pub const NO_AUTHORITY: Self = Self::known(3, 8);
+    pub const UNSUPPORTED_VERSION: Self = Self::known(3, 9);
+    pub const REQUEST_TOO_LARGE: Self = Self::known(3, 10);
+    pub const REQUIRED_MISSING: Self = Self::known(3, 11);
+    pub const UNKNOWN_FOUND: Self = Self::known(3, 12);
+    pub const UNSUPPORTED_FOUND: Self = Self::known(3, 13);
+    pub const UNSUPPORTED_CAPABILITY: Self = Self::known(3, 14);
+    pub const EVENT_CONFLICT: Self = Self::known(4, 0);
+    pub const REQUEST_MAY_BE_SUPPORTED: Self = Self::known(5, 0);
+    pub const SERVICE_UNAVAILABLE: Self = Self::known(5, 1);
+    pub const INVALID_CALENDAR_SERVICE: Self = Self::known(5, 2);
+    pub const NO_SCHEDULING_SUPPORT: Self = Self::known(5, 3);
+
+    const fn known(class: u8, code: u16) -> Self {
+        Self {
+            class,
+            code,
+            detail: None,
+        }
+    }
+
+    /// A two-level status code, such as `3.7`
+    pub fn new(class: u8, code: u16) -> Result<Self, StatusCodeError> {
+        if !(1..=5).contains(&class) {
+            return Err(StatusCodeError::InvalidClass(class));
+        }
+
+        Ok(Self::known(class, code))
+    }
+
+    /// Add a third level to the status code, such as `3.1.1`
+    pub fn with_detail(self, detail: u16) -> Self {
+        Self {
+            detail: Some(detail),
+            ..self
+        }
+    }
+
+    pub fn class(&self) -> u8 {
+        self.class
+    }
+
+    pub fn code(&self) -> u16 {
+        self.code
+    }
+
+    pub fn detail(&self) -> Option<u16> {
+        self.detail
+    }
+
+    /// Check if the status is in one of the success classes, `1` and `2`
+    pub fn is_success(&self) -> bool {
+        self.class <= 2
+    }
+
+    /// The description given for the status code in [RFC 5546
+    /// 3.6](https://www.rfc-editor.org/rfc/rfc5546#section-3.6), if any
+    pub fn description(&self) -> Option<&'static str> {
+        if self.detail.is_some() {
+            return None;
+        }
+
+        let description = match (self.class, self.code) {
+            (2, 0) => "Success",
+            (2, 1) => "Success, but fallback taken on one or more property values",
+            (2, 2) => "Success; invalid property ignored",
+            (2, 3) => "Success; invalid property parameter ignored",
+            (2, 4) => "Success; unknown, non-standard property ignored",
+            (2, 5) => "Success; unknown, non-standard property value ignored",
+            (2, 6) => "Success; invalid calendar component ignored",
+            (2, 7) => "Success; request forwarded to Calendar User",
+            (2, 8) => "Success; repeating event ignored. Scheduled as a single component",
+            (2, 9) => "Success; truncated end date time to date boundary",
+            (2, 10) => "Success; repeating VTODO ignored. Scheduled as a single VTODO",
+            (2, 11) => "Success; unbounded RRULE clipped at some finite number of instances",
+            (3, 0) => "Invalid property name",
+            (3, 1) => "Invalid property value",
+            (3, 2) => "Invalid property parameter",
+            (3, 3) => "Invalid property parameter value",
+            (3, 4) => "Invalid calendar component sequence",
+            (3, 5) => "Invalid date or time",
+            (3, 6) => "Invalid rule",
+            (3, 7) => "Invalid Calendar User",
+            (3, 8) => "No authority",
+            (3, 9) => "Unsupported version",
+            (3, 10) => "Request entity too large",
+            (3, 11) => "Required component or property missing",
+            (3, 12) => "Unknown component or property found",
+            (3, 13) => "Unsupported component or property found",
+            (3, 14) => "Unsupported capability",
+            (4, 0) => "Event conflict. Date/time is busy",
+            (5, 0) => "Request MAY supported",
+            (5, 1) => "Service unavailable",
+            (5, 2) => "Invalid calendar service",
+            (5, 3) => "No scheduling support for user",
+            _ => return None,
+        };
+
+        Some(description)
+    }
+}
+
+impl std::fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.class, self.code)?;
+        if let Some(detail) = self.detail {
+            write!(f, ".{detail}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Value of the REQUEST-STATUS property
+///
+/// The description and the exception data are TEXT, and are escaped when
+/// written.
+///
+/// ```
+/// use ical_syntax::write::value_types::{RequestStatus, StatusCode};
+///
+/// // REQUEST-STATUS:2.0;Success
+/// let status = RequestStatus::from(StatusCode::SUCCESS);
+///
+/// // REQUEST-STATUS:3.1;Invalid property value;DTSTART:96-Apr-01
+/// let status = RequestStatus::from(StatusCode::INVALID_PROPERTY_VALUE)
+///     .with_exception_data("DTSTART:96-Apr-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestStatus<'a> {
+    pub code: StatusCode,
+    pub description: &'a str,
+    pub exception_data: Option<&'a str>,
+}
+
+impl<'a> RequestStatus<'a> {
+    pub fn new(code: StatusCode, description: &'a str) -> Self {
+        Self {
+            code,
+            description,
+            exception_data: None,
+        }
+    }
+
+    /// Add exception data, for example the offending property name and value
+    /// or the complete property line
+    pub fn with_exception_data(self, exception_data: &'a str) -> Self {
+        Self {
+            exception_data: Some(exception_data),
+            ..self
+        }
+    }
+}
+
+/// Use the description from the RFC 5546 catalogue, or an empty description
+/// for status codes that are not in it
+impl From<StatusCode> for RequestStatus<'static> {
+    fn from(code: StatusCode) -> Self {
+        Self::new(code, code.description().unwrap_or_default())
+    }
+}
+
+impl AsCompositeValueType<Any2<(Text, Text), (Text, Text, Text)>> for RequestStatus<'_> {
+    fn write_into<W: Write>(
+        self,
+        mut prop_value_writer: PropertyValueWriter<W>,
+    ) -> std::fmt::Result {
+        let mut tw = prop_value_writer.value_tuple_writer()?;
+        write!(tw.next_raw_value_writer()?, "{}", self.code)?;
+        tw.next_value_writer()?.write_str(self.description)?;
+        if let Some(exception_data) = self.exception_data {
+            tw.next_value_writer()?.write_str(exception_data)?;
+        }
+        Ok(())
+    }
+}
+
+impl AsCompositeValueType<Any2<(Text, Text), (Text, Text, Text)>> for &RequestStatus<'_> {
+    fn write_into<W: Write>(self, prop_value_writer: PropertyValueWriter<W>) -> std::fmt::Result {
+        (*self).write_into(prop_value_writer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        structure::icalstream::properties::misc,
+        write::{LineStream, PropertyWriter},
+    };
+
+    fn test_case(status: RequestStatus, expected: &str) {
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut p = PropertyWriter::<_, misc::RequestStatus>::new(&mut line_stream).unwrap();
+        p.value(status).unwrap();
+        p.end().unwrap();
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn status_code() {
+        assert_eq!(StatusCode::new(3, 7), Ok(StatusCode::INVALID_CALENDAR_USER));
+        assert_eq!(StatusCode::new(0, 1), Err(StatusCodeError::InvalidClass(0)));
+        assert_eq!(StatusCode::new(6, 0), Err(StatusCodeError::InvalidClass(6)));
+
+        assert_eq!(
+            StatusCode::SUCCESS_REPEATING_TODO_IGNORED.to_string(),
+            "2.10"
+        );
+        assert_eq!(
+            StatusCode::INVALID_PROPERTY_VALUE
+                .with_detail(1)
+                .to_string(),
+            "3.1.1"
+        );
+
+        assert_eq!(
+            StatusCode::INVALID_CALENDAR_USER.description(),
+            Some("Invalid Calendar User")
+        );
+        assert_eq!(StatusCode::new(3, 15).unwrap().description(), None);
+        assert_eq!(StatusCode::SUCCESS.with_detail(1).description(), None);
+
+        assert!(StatusCode::SUCCESS_FORWARDED.is_success());
+        assert!(!StatusCode::SERVICE_UNAVAILABLE.is_success());
+    }
+
+    #[test]
+    fn request_status() {
+        test_case(
+            RequestStatus::from(StatusCode::SUCCESS),
+            "REQUEST-STATUS:2.0;Success\r\n",
+        );
+        test_case(
+            RequestStatus::from(StatusCode::INVALID_PROPERTY_VALUE)
+                .with_exception_data("DTSTART:96-Apr-01"),
+            "REQUEST-STATUS:3.1;Invalid property value;DTSTART:96-Apr-01\r\n",
+        );
+        test_case(
+            RequestStatus::from(StatusCode::SUCCESS_REPEATING_EVENT_IGNORED)
+                .with_exception_data("RRULE:FREQ=WEEKLY;INTERVAL=2"),
+            "REQUEST-STATUS:2.8;Success\\; repeating event ignored. Scheduled as a single\r\n  \
+            component;RRULE:FREQ=WEEKLY\\;INTERVAL=2\r\n",
+        );
+        test_case(
+            RequestStatus::new(StatusCode::new(3, 1).unwrap().with_detail(1), "Bad, bad"),
+            "REQUEST-STATUS:3.1.1;Bad\\, bad\r\n",
+        );
+    }
+}
@@ -0,0 +1,341 @@
+#![cfg(feature = "time03")]
+
+use super::{AsValueType, ICalDuration, ICalDurationError, UtcInstant};
+use crate::{
+    structure::value_types::{Date, DateTime, DateTimeUtc, Duration, UtcOffset},
+    write::value_types::ToValueType,
+};
+
+/// `time::PrimitiveDateTime` corresponds to the _floating_ form of a DateTime
+impl AsValueType<DateTime> for time::PrimitiveDateTime {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(
+            w,
+            "{:04}{:02}{:02}T{:02}{:02}{:02}",
+            self.year(),
+            self.month() as u8,
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+impl AsValueType<DateTime> for &time::PrimitiveDateTime {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for time::PrimitiveDateTime {
+    type ValueType = DateTime;
+}
+
+impl ToValueType for &time::PrimitiveDateTime {
+    type ValueType = DateTime;
+}
+
+/// `time::OffsetDateTime` is converted to UTC, and corresponds to the _UTC_
+/// form of a DateTime. The offset itself is not preserved.
+impl AsValueType<DateTime> for time::OffsetDateTime {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        let utc = self
+            .checked_to_offset(time::UtcOffset::UTC)
+            .ok_or(std::fmt::Error)?;
+        AsValueType::<DateTime>::fmt(&time::PrimitiveDateTime::new(utc.date(), utc.time()), w)?;
+        write!(w, "Z")
+    }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
+}
+
+impl AsValueType<DateTime> for &time::OffsetDateTime {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(*self, w)
+    }
+
+    fn is_utc(&self) -> bool {
+        AsValueType::<DateTime>::is_utc(*self)
+    }
+}
+
+impl AsValueType<DateTimeUtc> for time::OffsetDateTime {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(self, w)
+    }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
+}
+
+impl AsValueType<DateTimeUtc> for &time::OffsetDateTime {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<DateTime>::fmt(*self, w)
+    }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
+}
+
+impl ToValueType for time::OffsetDateTime {
+    type ValueType = DateTime;
+}
+
+impl ToValueType for &time::OffsetDateTime {
+    type ValueType = DateTime;
+}
+
+impl UtcInstant for time::OffsetDateTime {
+    fn unix_seconds(&self) -> i64 {
+        self.unix_timestamp()
+    }
+}
+
+impl AsValueType<Date> for time::Date {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(
+            w,
+            "{:04}{:02}{:02}",
+            self.year(),
+            self.month() as u8,
+            self.day()
+        )
+    }
+}
+
+impl AsValueType<Date> for &time::Date {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<Date>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for time::Date {
+    type ValueType = Date;
+}
+
+impl ToValueType for &time::Date {
+    type ValueType = Date;
+}
+
+/// `time::Duration` is an exact duration, so it is converted to hours, minutes
+/// and seconds. Fractional seconds are truncated, as they cannot be
+/// represented.
+impl TryFrom<time::Duration> for ICalDuration {
+    type Error = ICalDurationError;
+
+    fn try_from(value: time::Duration) -> Result<Self, Self::Error> {
+        ICalDuration::from_exact_seconds(value.whole_seconds())
+    }
+}
+
+/// Only exact durations can be converted to `time::Duration`
+impl TryFrom<ICalDuration> for time::Duration {
+    type Error = ICalDurationError;
+
+    fn try_from(value: ICalDuration) -> Result<Self, Self::Error> {
+        Ok(time::Duration::seconds(value.to_exact_seconds()?))
+    }
+}
+
+impl AsValueType<Duration> for time::Duration {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        let duration = ICalDuration::try_from(*self).map_err(|_| std::fmt::Error)?;
+        AsValueType::<Duration>::fmt(&duration, w)
+    }
+}
+
+impl AsValueType<Duration> for &time::Duration {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<Duration>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for time::Duration {
+    type ValueType = Duration;
+}
+
+impl ToValueType for &time::Duration {
+    type ValueType = Duration;
+}
+
+impl AsValueType<UtcOffset> for time::UtcOffset {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        super::fmt_utc_offset(w, self.whole_seconds())
+    }
+}
+
+impl AsValueType<UtcOffset> for &time::UtcOffset {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<UtcOffset>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for time::UtcOffset {
+    type ValueType = UtcOffset;
+}
+
+impl ToValueType for &time::UtcOffset {
+    type ValueType = UtcOffset;
+}
+
+#[cfg(test)]
+mod test {
+    use time::macros::{datetime, offset};
+
+    use crate::structure::ValueType;
+
+    use super::*;
+
+    fn test_case<V: ValueType>(v: impl AsValueType<V>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<V>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    #[test]
+    fn datetime_floating() {
+        let datetime = datetime!(2024-06-26 12:00:00);
+        test_case::<DateTime>(datetime, "20240626T120000");
+        test_case::<DateTime>(&datetime, "20240626T120000");
+    }
+
+    #[test]
+    fn datetime_utc() {
+        let datetime = datetime!(2024-06-26 14:00:00 +02:00);
+        test_case::<DateTime>(datetime, "20240626T120000Z");
+        test_case::<DateTime>(&datetime, "20240626T120000Z");
+        test_case::<DateTimeUtc>(datetime, "20240626T120000Z");
+    }
+
+    #[test]
+    fn date() {
+        let date = time::Date::from_calendar_date(2024, time::Month::June, 26).unwrap();
+        test_case::<Date>(date, "20240626");
+        test_case::<Date>(&date, "20240626");
+    }
+
+    #[test]
+    fn duration() {
+        test_case::<Duration>(time::Duration::hours(1), "PT1H");
+        test_case::<Duration>(&time::Duration::hours(1), "PT1H");
+        test_case::<Duration>(time::Duration::ZERO, "PT0S");
+        test_case::<Duration>(time::Duration::seconds(-90), "-PT1M30S");
+        test_case::<Duration>(time::Duration::seconds(3605), "PT1H0M5S");
+        test_case::<Duration>(time::Duration::days(2), "PT48H");
+        test_case::<Duration>(time::Duration::milliseconds(1500), "PT1S");
+    }
+
+    #[test]
+    fn utc_offset() {
+        test_case::<UtcOffset>(offset!(-5), "-0500");
+        test_case::<UtcOffset>(&offset!(+1), "+0100");
+        test_case::<UtcOffset>(offset!(UTC), "+0000");
+        test_case::<UtcOffset>(offset!(+5:30:15), "+053015");
+    }
+
+    #[test]
+    fn choice_and_list() -> std::fmt::Result {
+        use crate::{
+            structure::{
+                composite_value_types::{Any2, Any3, List},
+                value_types::PeriodOfTime,
+                Property,
+            },
+            write::{LineStream, PropertyWriter},
+        };
+
+        struct DateTimeStart;
+        impl Property for DateTimeStart {
+            const NAME: &'static str = "DTSTART";
+
+            type CompositeValueType = Any2<DateTime, Date>;
+        }
+
+        struct RecurrenceDateTimes;
+        impl Property for RecurrenceDateTimes {
+            const NAME: &'static str = "RDATE";
+
+            type CompositeValueType = List<Any3<DateTime, Date, PeriodOfTime>>;
+        }
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        prop.value(time::Date::from_calendar_date(2024, time::Month::June, 26).unwrap())?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, DateTimeStart>::new(&mut line_stream)?;
+        prop.value(datetime!(2024-06-26 12:00:00 UTC))?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, RecurrenceDateTimes>::new(&mut line_stream)?;
+        prop.value([
+            datetime!(2024-06-26 12:00:00),
+            datetime!(2024-06-27 12:00:00),
+        ])?;
+        prop.end()?;
+
+        assert_eq!(
+            &buf,
+            "DTSTART;VALUE=DATE:20240626\r\n\
+            DTSTART:20240626T120000Z\r\n\
+            RDATE:20240626T120000,20240627T120000\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn period_of_time() {
+        use crate::{
+            structure::value_types::PeriodOfTime, write::value_types::PeriodOfTimeBuilder,
+        };
+
+        let start = datetime!(2024-06-26 12:00:00 UTC);
+        let end = datetime!(2024-06-26 13:00:00 UTC);
+
+        test_case::<PeriodOfTime>(
+            PeriodOfTimeBuilder::start(start).end(end).unwrap(),
+            "20240626T120000Z/20240626T130000Z",
+        );
+
+        test_case::<PeriodOfTime>(
+            PeriodOfTimeBuilder::start(start)
+                .duration(end - start)
+                .unwrap(),
+            "20240626T120000Z/PT1H",
+        );
+
+        assert!(PeriodOfTimeBuilder::start(end).end(start).is_err());
+    }
+
+    #[test]
+    fn period_utc() {
+        use crate::{
+            structure::value_types::PeriodOfTimeUtc,
+            write::value_types::{PeriodError, PeriodUtc},
+        };
+
+        let start = datetime!(2024-06-26 14:00:00 +02:00);
+        let end = datetime!(2024-06-26 13:00:00 UTC);
+
+        test_case::<PeriodOfTimeUtc>(
+            PeriodUtc::new(start, end).unwrap(),
+            "20240626T120000Z/20240626T130000Z",
+        );
+        test_case::<PeriodOfTimeUtc>(
+            PeriodUtc::with_duration(start, time::Duration::minutes(90)).unwrap(),
+            "20240626T120000Z/PT1H30M",
+        );
+        assert_eq!(
+            PeriodUtc::with_duration(start, time::Duration::ZERO),
+            Err(PeriodError::DurationNotPositive)
+        );
+    }
+}
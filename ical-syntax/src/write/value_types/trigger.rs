@@ -0,0 +1,106 @@
+use std::fmt::Write;
+
+use super::{period_of_time::fmt_unix_seconds, AsValueType, ICalDuration, ToValueType, UtcInstant};
+use crate::{
+    structure::{icalstream::parameters, icalstream::properties, value_types::DateTimeUtc},
+    write::PropertyWriter,
+};
+
+/// Helper type for the TRIGGER property
+///
+/// ```
+/// use ical_syntax::write::value_types::{ICalDuration, Trigger};
+///
+/// // TRIGGER:-PT15M
+/// let trigger = Trigger::Start(-ICalDuration::minutes(15));
+///
+/// // TRIGGER;RELATED=END:PT5M
+/// let trigger = Trigger::End(ICalDuration::minutes(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    /// Relative to the start of the event or to-do. A negative duration
+    /// triggers before the start.
+    Start(ICalDuration),
+
+    /// Relative to the end of the event, or the due time of the to-do. A
+    /// negative duration triggers before the end.
+    End(ICalDuration),
+
+    /// At an absolute point in time, as a UNIX timestamp. See [Trigger::at].
+    At(i64),
+}
+
+impl Trigger {
+    /// Trigger at an absolute point in time
+    pub fn at(instant: impl UtcInstant) -> Self {
+        Trigger::At(instant.unix_seconds())
+    }
+
+    /// Write the value of the TRIGGER property, along with the RELATED or
+    /// VALUE parameter it implies
+    pub(crate) fn write<W: Write>(
+        &self,
+        p: &mut PropertyWriter<'_, W, properties::alarm::Trigger>,
+    ) -> std::fmt::Result {
+        match self {
+            Trigger::Start(duration) => p.value(duration),
+            Trigger::End(duration) => {
+                p.param(
+                    parameters::AlarmTriggerRelationship,
+                    super::AlarmTriggerRelationship::End,
+                )?;
+                p.value(duration)
+            }
+            Trigger::At(timestamp) => p.value(UnixTimestamp(*timestamp)),
+        }
+    }
+}
+
+/// A UNIX timestamp in the _UTC_ form of a DateTime
+struct UnixTimestamp(i64);
+
+impl AsValueType<DateTimeUtc> for UnixTimestamp {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        fmt_unix_seconds(w, self.0)
+    }
+
+    fn is_utc(&self) -> bool {
+        true
+    }
+}
+
+impl ToValueType for UnixTimestamp {
+    type ValueType = DateTimeUtc;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::write::LineStream;
+
+    fn test_case(trigger: Trigger, expected: &str) {
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut p = PropertyWriter::new(&mut line_stream).unwrap();
+        trigger.write(&mut p).unwrap();
+        p.end().unwrap();
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn trigger() {
+        test_case(
+            Trigger::Start(-ICalDuration::minutes(15)),
+            "TRIGGER:-PT15M\r\n",
+        );
+        test_case(
+            Trigger::End(ICalDuration::minutes(5)),
+            "TRIGGER;RELATED=END:PT5M\r\n",
+        );
+        test_case(
+            Trigger::At(883630800),
+            "TRIGGER;VALUE=DATE-TIME:19980101T050000Z\r\n",
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::fmt::Write;
+
+use crate::{structure::value_types, write::value_types::ToValueType};
+
+use super::AsValueType;
+
+/// Error for values that cannot be represented as a URI or CAL-ADDRESS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UriError {
+    /// The value does not start with a scheme, such as `https:`
+    MissingScheme,
+
+    /// The value contains a character that is not valid in a URI, or which
+    /// would break the content line
+    InvalidCharacter(char),
+
+    /// A `%` is not followed by two hexadecimal digits
+    InvalidPercentEncoding,
+
+    /// A CAL-ADDRESS must be a `mailto:` URI
+    NotMailto,
+
+    /// The email address is not on the form `local-part@domain`
+    InvalidEmailAddress,
+}
+
+impl std::fmt::Display for UriError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UriError::MissingScheme => write!(f, "URI is missing a scheme"),
+            UriError::InvalidCharacter(c) => write!(f, "invalid character in URI: {c:?}"),
+            UriError::InvalidPercentEncoding => write!(f, "invalid percent-encoding in URI"),
+            UriError::NotMailto => write!(f, "calendar user address is not a mailto: URI"),
+            UriError::InvalidEmailAddress => write!(f, "invalid email address"),
+        }
+    }
+}
+
+impl std::error::Error for UriError {}
+
+fn validate_uri(uri: &str) -> Result<(), UriError> {
+    // scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+    let (scheme, _) = uri.split_once(':').ok_or(UriError::MissingScheme)?;
+    let mut scheme_chars = scheme.bytes();
+    if !scheme_chars.next().is_some_and(|x| x.is_ascii_alphabetic())
+        || !scheme_chars.all(|x| x.is_ascii_alphanumeric() || b"+-.".contains(&x))
+    {
+        return Err(UriError::MissingScheme);
+    }
+
+    let mut chars = uri.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => {
+                let hex = [chars.next(), chars.next()];
+                if !hex.iter().all(|x| x.is_some_and(|x| x.is_ascii_hexdigit())) {
+                    return Err(UriError::InvalidPercentEncoding);
+                }
+            }
+
+            c if is_uri_char(c) => {}
+
+            c => return Err(UriError::InvalidCharacter(c)),
+        }
+    }
+
+    Ok(())
+}
+
+/// The unreserved characters, gen-delims and sub-delims from RFC 3986. The
+/// TEXT escaping does not apply to URI values, so `,` and `;` are written
+/// as-is.
+fn is_uri_char(c: char) -> bool {
+    matches!(c,
+        'A'..='Z' | 'a'..='z' | '0'..='9'
+        | '-' | '.' | '_' | '~'
+        | ':' | '/' | '?' | '#' | '[' | ']' | '@'
+        | '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '='
+    )
+}
+
+/// Percent-encode the characters that are not valid in a URI, leaving
+/// existing percent-encoding as it is
+#[cfg(feature = "url2")]
+pub(super) fn percent_encode_invalid(uri: &str) -> String {
+    let mut buf = String::with_capacity(uri.len());
+    for c in uri.chars() {
+        if c == '%' || is_uri_char(c) {
+            buf.push(c);
+        } else {
+            let mut utf8 = [0; 4];
+            for x in c.encode_utf8(&mut utf8).bytes() {
+                write!(buf, "%{x:02X}").unwrap();
+            }
+        }
+    }
+    buf
+}
+
+/// A validated value for the URI value type.
+///
+/// The URI must be absolute, that is, it must include a scheme. Characters
+/// outside of the URI grammar must be percent-encoded.
+///
+/// ```
+/// # use ical_syntax::write::value_types::Uri;
+/// assert!(Uri::new("http://example.com/my-report.txt").is_ok());
+/// assert!(Uri::new("http://example.com/my report.txt").is_err());
+/// assert!(Uri::new("my-report.txt").is_err());
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.3.13](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.13)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Uri(String);
+
+impl Uri {
+    pub fn new(uri: impl Into<String>) -> Result<Self, UriError> {
+        let uri = uri.into();
+        validate_uri(&uri)?;
+        Ok(Self(uri))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Uri {
+    type Error = UriError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for Uri {
+    type Error = UriError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<CalAddress> for Uri {
+    fn from(value: CalAddress) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for Uri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsValueType<value_types::Uri> for Uri {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(&self.0)
+    }
+}
+
+impl AsValueType<value_types::Uri> for &Uri {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<value_types::Uri>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for Uri {
+    type ValueType = value_types::Uri;
+}
+
+impl ToValueType for &Uri {
+    type ValueType = value_types::Uri;
+}
+
+const MAILTO: &str = "mailto:";
+
+/// A validated value for the CAL-ADDRESS value type.
+///
+/// Only `mailto:` URIs are accepted. Use [`CalAddress::from_email`] to build
+/// the URI from a plain email address:
+///
+/// ```
+/// # use ical_syntax::write::value_types::CalAddress;
+/// let address = CalAddress::from_email("jane_doe@example.com").unwrap();
+/// assert_eq!(address.as_str(), "mailto:jane_doe@example.com");
+///
+/// let address = CalAddress::from_email("\"Jane Doe\"@example.com").unwrap();
+/// assert_eq!(address.as_str(), "mailto:%22Jane%20Doe%22@example.com");
+///
+/// assert!(CalAddress::new("mailto:jane_doe@example.com").is_ok());
+/// assert!(CalAddress::new("http://example.com/jane_doe").is_err());
+/// ```
+///
+/// Reference: [RFC 5545
+/// 3.3.3](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.3)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalAddress(Uri);
+
+impl CalAddress {
+    pub fn new(uri: impl Into<String>) -> Result<Self, UriError> {
+        Self::try_from(Uri::new(uri)?)
+    }
+
+    /// Build a `mailto:` URI from an email address, percent-encoding it as
+    /// necessary.
+    pub fn from_email(address: &str) -> Result<Self, UriError> {
+        let (local_part, domain) = address
+            .rsplit_once('@')
+            .ok_or(UriError::InvalidEmailAddress)?;
+        if local_part.is_empty() || domain.is_empty() {
+            return Err(UriError::InvalidEmailAddress);
+        }
+
+        let mut uri = String::from(MAILTO);
+        percent_encode(&mut uri, local_part);
+        uri.push('@');
+        percent_encode(&mut uri, domain);
+
+        Ok(Self(Uri(uri)))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+fn percent_encode(buf: &mut String, s: &str) {
+    for x in s.bytes() {
+        match x {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => buf.push(x as char),
+            b'-' | b'.' | b'_' | b'~' => buf.push(x as char),
+            b'!' | b'$' | b'\'' | b'(' | b')' | b'*' | b'+' | b'=' => buf.push(x as char),
+            x => write!(buf, "%{x:02X}").unwrap(),
+        }
+    }
+}
+
+impl TryFrom<Uri> for CalAddress {
+    type Error = UriError;
+
+    fn try_from(value: Uri) -> Result<Self, Self::Error> {
+        let is_mailto = value
+            .as_str()
+            .get(..MAILTO.len())
+            .is_some_and(|scheme| scheme.eq_ignore_ascii_case(MAILTO));
+        if !is_mailto {
+            return Err(UriError::NotMailto);
+        }
+        if value.as_str().len() == MAILTO.len() {
+            return Err(UriError::InvalidEmailAddress);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&str> for CalAddress {
+    type Error = UriError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for CalAddress {
+    type Error = UriError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl std::fmt::Display for CalAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsValueType<value_types::CalAddress> for CalAddress {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+impl AsValueType<value_types::CalAddress> for &CalAddress {
+    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<value_types::CalAddress>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for CalAddress {
+    type ValueType = value_types::CalAddress;
+}
+
+impl ToValueType for &CalAddress {
+    type ValueType = value_types::CalAddress;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::structure::{value_types, ValueType};
+
+    use super::*;
+
+    fn test_case<V: ValueType>(v: impl AsValueType<V>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<V>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    #[test]
+    fn uri() {
+        let uri = Uri::new("http://example.com/my-report.txt").unwrap();
+        test_case::<value_types::Uri>(&uri, "http://example.com/my-report.txt");
+        test_case::<value_types::Uri>(uri, "http://example.com/my-report.txt");
+
+        let uri = Uri::new("https://example.com/?q=a%20b&r=1#frag").unwrap();
+        test_case::<value_types::Uri>(uri, "https://example.com/?q=a%20b&r=1#frag");
+
+        // Sub-delims are not escaped in URI values
+        let uri = Uri::new("https://example.com/a;b?x=1,2").unwrap();
+        test_case::<value_types::Uri>(uri, "https://example.com/a;b?x=1,2");
+    }
+
+    #[test]
+    fn uri_invalid() {
+        assert_eq!(Uri::new("example.com"), Err(UriError::MissingScheme));
+        assert_eq!(Uri::new(":example.com"), Err(UriError::MissingScheme));
+        assert_eq!(
+            Uri::new("1http://example.com"),
+            Err(UriError::MissingScheme)
+        );
+        assert_eq!(
+            Uri::new("http://example.com/a b"),
+            Err(UriError::InvalidCharacter(' '))
+        );
+        assert_eq!(
+            Uri::new("http://example.com/\"a\""),
+            Err(UriError::InvalidCharacter('"'))
+        );
+        assert_eq!(
+            Uri::new("http://example.com/\r\n"),
+            Err(UriError::InvalidCharacter('\r'))
+        );
+        assert_eq!(
+            Uri::new("http://example.com/\u{e6}"),
+            Err(UriError::InvalidCharacter('\u{e6}'))
+        );
+        assert_eq!(
+            Uri::new("http://example.com/%2"),
+            Err(UriError::InvalidPercentEncoding)
+        );
+        assert_eq!(
+            Uri::new("http://example.com/%zz"),
+            Err(UriError::InvalidPercentEncoding)
+        );
+    }
+
+    #[test]
+    fn cal_address() {
+        let address = CalAddress::new("mailto:jane_doe@example.com").unwrap();
+        test_case::<value_types::CalAddress>(&address, "mailto:jane_doe@example.com");
+        test_case::<value_types::CalAddress>(address, "mailto:jane_doe@example.com");
+
+        assert!(CalAddress::new("MAILTO:jane_doe@example.com").is_ok());
+    }
+
+    #[test]
+    fn cal_address_invalid() {
+        assert_eq!(
+            CalAddress::new("http://example.com/"),
+            Err(UriError::NotMailto)
+        );
+        assert_eq!(
+            CalAddress::new("mailto:"),
+            Err(UriError::InvalidEmailAddress)
+        );
+        assert_eq!(
+            CalAddress::new("mailto:a b@example.com"),
+            Err(UriError::InvalidCharacter(' '))
+        );
+    }
+
+    #[test]
+    fn cal_address_from_email() {
+        let address = CalAddress::from_email("jane_doe@example.com").unwrap();
+        assert_eq!(address.as_str(), "mailto:jane_doe@example.com");
+
+        let address = CalAddress::from_email("a,b;c%d?e&f/g@example.com").unwrap();
+        assert_eq!(
+            address.as_str(),
+            "mailto:a%2Cb%3Bc%25d%3Fe%26f%2Fg@example.com"
+        );
+
+        let address = CalAddress::from_email("\u{f8}@example.com").unwrap();
+        assert_eq!(address.as_str(), "mailto:%C3%B8@example.com");
+
+        assert_eq!(
+            CalAddress::from_email("example.com"),
+            Err(UriError::InvalidEmailAddress)
+        );
+        assert_eq!(
+            CalAddress::from_email("@example.com"),
+            Err(UriError::InvalidEmailAddress)
+        );
+        assert_eq!(
+            CalAddress::from_email("jane_doe@"),
+            Err(UriError::InvalidEmailAddress)
+        );
+    }
+}
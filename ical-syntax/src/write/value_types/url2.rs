@@ -0,0 +1,127 @@
+#![cfg(feature = "url2")]
+
+use super::{uri::percent_encode_invalid, AsValueType, CalAddress, Uri, UriError};
+use crate::{
+    structure::{parameter_value_items, value_types},
+    write::{value_types::ToValueType, AsParamValueItem},
+};
+
+impl TryFrom<url::Url> for Uri {
+    type Error = UriError;
+
+    fn try_from(value: url::Url) -> Result<Self, Self::Error> {
+        Uri::new(percent_encode_invalid(value.as_str()))
+    }
+}
+
+impl TryFrom<&url::Url> for Uri {
+    type Error = UriError;
+
+    fn try_from(value: &url::Url) -> Result<Self, Self::Error> {
+        Uri::new(percent_encode_invalid(value.as_str()))
+    }
+}
+
+impl TryFrom<url::Url> for CalAddress {
+    type Error = UriError;
+
+    fn try_from(value: url::Url) -> Result<Self, Self::Error> {
+        CalAddress::try_from(Uri::try_from(value)?)
+    }
+}
+
+impl TryFrom<&url::Url> for CalAddress {
+    type Error = UriError;
+
+    fn try_from(value: &url::Url) -> Result<Self, Self::Error> {
+        CalAddress::try_from(Uri::try_from(value)?)
+    }
+}
+
+/// `url::Url` is written as-is, except that the characters which `url` leaves
+/// unencoded but which are not valid in a URI, such as `|`, are
+/// percent-encoded.
+impl AsValueType<value_types::Uri> for url::Url {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        let uri = Uri::try_from(self).map_err(|_| std::fmt::Error)?;
+        AsValueType::<value_types::Uri>::fmt(&uri, w)
+    }
+}
+
+impl AsValueType<value_types::Uri> for &url::Url {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsValueType::<value_types::Uri>::fmt(*self, w)
+    }
+}
+
+impl ToValueType for url::Url {
+    type ValueType = value_types::Uri;
+}
+
+impl ToValueType for &url::Url {
+    type ValueType = value_types::Uri;
+}
+
+impl AsParamValueItem<parameter_value_items::Uri> for url::Url {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        let uri = Uri::try_from(self).map_err(|_| std::fmt::Error)?;
+        AsParamValueItem::<parameter_value_items::Uri>::fmt(&uri, w)
+    }
+}
+
+impl AsParamValueItem<parameter_value_items::Uri> for &url::Url {
+    fn fmt<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        AsParamValueItem::<parameter_value_items::Uri>::fmt(*self, w)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::structure::ValueType;
+
+    use super::*;
+
+    fn test_case<V: ValueType>(v: impl AsValueType<V>, expected: &str) {
+        let mut buf = String::new();
+        AsValueType::<V>::fmt(&v, &mut buf).unwrap();
+        assert_eq!(&buf, expected);
+    }
+
+    #[test]
+    fn url() {
+        let url = url::Url::parse("http://example.com/my report.txt").unwrap();
+        test_case::<value_types::Uri>(&url, "http://example.com/my%20report.txt");
+        test_case::<value_types::Uri>(url, "http://example.com/my%20report.txt");
+    }
+
+    #[test]
+    fn url_sub_delims() {
+        let url = url::Url::parse("https://example.com/a;b?x=1,2").unwrap();
+        test_case::<value_types::Uri>(&url, "https://example.com/a;b?x=1,2");
+
+        let url = url::Url::parse("https://example.com/a|b^c").unwrap();
+        test_case::<value_types::Uri>(&url, "https://example.com/a%7Cb%5Ec");
+    }
+
+    #[test]
+    fn url_property() -> std::fmt::Result {
+        use crate::{structure::icalstream::properties::relationship::Url, write::Writer};
+
+        let url = url::Url::parse("https://example.com/a;b?x=1,2").unwrap();
+
+        let mut buf = String::new();
+        Writer::with_fmt(&mut buf).simple_property(Url, &url)?;
+        assert_eq!(buf, "URL:https://example.com/a;b?x=1,2\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn cal_address() {
+        let url = url::Url::parse("mailto:jane_doe@example.com").unwrap();
+        let address = CalAddress::try_from(&url).unwrap();
+        assert_eq!(address.as_str(), "mailto:jane_doe@example.com");
+
+        let url = url::Url::parse("http://example.com/").unwrap();
+        assert_eq!(CalAddress::try_from(url), Err(UriError::NotMailto));
+    }
+}
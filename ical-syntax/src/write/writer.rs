@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     fmt::Write,
     marker::PhantomData,
     ops::{Deref, DerefMut},
@@ -9,7 +10,7 @@ use crate::structure::*;
 use super::{
     composite_value_types::AsCompositeValueType,
     content_line::{ParamValueWriter, ValueListWriter, ValueTupleWriter},
-    ContentLine, LineStream,
+    ContentLine, DynamicName, LineStream,
 };
 
 pub trait AsParamValueItem<To: ParamValueItem> {
@@ -27,8 +28,10 @@ where
     fn write_to<W: Write, P: ParamValueWriter<W>>(self, w: &mut P) -> std::fmt::Result {
         if Inner::QUOTED {
             let mut w = w.param_value_quoted_writer()?;
-            self.fmt(&mut w)?;
+            // Close the quoted string even when the value is invalid
+            let result = self.fmt(&mut w);
             w.close()?;
+            result?;
         } else {
             let mut w = w.param_value_unquoted_writer()?;
             self.fmt(&mut w)?;
@@ -50,17 +53,67 @@ where
     }
 }
 
+/// The time zones referenced by TZID parameters in written values, with the
+/// earliest and latest referenced points in time as UNIX timestamps.
+#[derive(Debug, Default)]
+pub struct TimeZoneReferences {
+    time_zones: BTreeMap<String, (i64, i64)>,
+}
+
+impl TimeZoneReferences {
+    pub fn add(&mut self, tzid: &str, timestamp: i64) {
+        match self.time_zones.get_mut(tzid) {
+            Some((min, max)) => {
+                *min = timestamp.min(*min);
+                *max = timestamp.max(*max);
+            }
+            None => {
+                self.time_zones
+                    .insert(tzid.to_owned(), (timestamp, timestamp));
+            }
+        }
+    }
+
+    pub fn remove(&mut self, tzid: &str) {
+        self.time_zones.remove(tzid);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.time_zones.is_empty()
+    }
+
+    /// Iterate over the referenced time zones as `(tzid, earliest, latest)`
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64, i64)> {
+        self.time_zones
+            .iter()
+            .map(|(tzid, &(min, max))| (tzid.as_str(), min, max))
+    }
+}
+
 pub struct Writer<W> {
     inner: LineStream<W>,
+    time_zones: TimeZoneReferences,
 }
 
 impl<W: Write> Writer<W> {
     pub fn with_fmt(inner: W) -> Self {
         Self {
             inner: LineStream::new(inner),
+            time_zones: TimeZoneReferences::default(),
         }
     }
 
+    /// The time zones referenced by the values written so far
+    pub fn time_zone_references(&self) -> &TimeZoneReferences {
+        &self.time_zones
+    }
+
+    /// Take the time zones referenced by the values written so far, and start
+    /// over with an empty set
+    pub fn take_time_zone_references(&mut self) -> TimeZoneReferences {
+        std::mem::take(&mut self.time_zones)
+    }
+
     pub fn component<'x, 'y: 'x, C: Component>(
         &'y mut self,
         component: C,
@@ -72,7 +125,9 @@ impl<W: Write> Writer<W> {
         &'y mut self,
         _property: P,
     ) -> Result<PropertyWriter<'x, W, P>, std::fmt::Error> {
-        PropertyWriter::new(&mut self.inner)
+        let mut property = PropertyWriter::new(&mut self.inner)?;
+        property.time_zones = Some(&mut self.time_zones);
+        Ok(property)
     }
 
     pub fn simple_property<P: Property>(
@@ -84,6 +139,26 @@ impl<W: Write> Writer<W> {
         p.value(value)?;
         p.end()
     }
+
+    /// Begin a component with a name that is only known at runtime, such as
+    /// a non-standard `X-` component
+    pub fn dynamic_component<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicComponentWriter<'x, W>, std::fmt::Error> {
+        DynamicComponentWriter::new(self, name)
+    }
+
+    /// Begin a property with a name that is only known at runtime, such as
+    /// a non-standard `X-` property
+    pub fn dynamic_property<'x, 'y: 'x>(
+        &'y mut self,
+        name: &DynamicName,
+    ) -> Result<DynamicPropertyWriter<'x, W>, std::fmt::Error> {
+        let mut property = DynamicPropertyWriter::new(&mut self.inner, name)?;
+        property.time_zones = Some(&mut self.time_zones);
+        Ok(property)
+    }
 }
 
 impl<W: std::io::Write> Writer<crate::write::io_adapters::FmtToIo<W>> {
@@ -148,6 +223,7 @@ impl<W: Write, C: Component> DerefMut for ComponentWriter<'_, W, C> {
 
 pub struct PropertyWriter<'a, W, P> {
     content_line: ContentLine<&'a mut W>,
+    time_zones: Option<&'a mut TimeZoneReferences>,
     is_closed: bool,
     _property: PhantomData<P>,
 }
@@ -159,26 +235,51 @@ impl<'a, W: Write, P: Property> PropertyWriter<'a, W, P> {
 
         Ok(Self {
             content_line,
+            time_zones: None,
             is_closed: false,
             _property: PhantomData,
         })
     }
 
+    /// Write a parameter. Only the parameters that are allowed on the
+    /// property can be written this way, see [AcceptsParam].
     pub fn param<'x, 'y: 'x, PP: Param>(
         &'y mut self,
         _param: PP,
         value: impl AsParamValue<PP::ParamValueType>,
-    ) -> std::fmt::Result {
+    ) -> std::fmt::Result
+    where
+        P: AcceptsParam<PP>,
+    {
         self.content_line.param_name(PP::NAME)?;
         value.write_to(&mut self.content_line)
     }
 
+    /// Write a parameter with a name that is only known at runtime, such as
+    /// an IANA or non-standard parameter, which are allowed on all
+    /// properties. The value is quoted if it contains `;`, `:` or `,`.
+    pub fn dynamic_param(
+        &mut self,
+        name: &DynamicName,
+        value: impl std::fmt::Display,
+    ) -> std::fmt::Result {
+        let value = value.to_string();
+        if value.contains([';', ':', ',']) {
+            self.content_line.param_quoted(name, value)
+        } else {
+            self.content_line.param_unquoted(name, value)
+        }
+    }
+
     pub fn value(
         &mut self,
         value: impl AsCompositeValueType<P::CompositeValueType>,
     ) -> std::fmt::Result {
         assert!(!self.is_closed);
-        value.write_into(PropertyValueWriter::new(&mut self.content_line))
+        let mut prop_value_writer =
+            PropertyValueWriter::new(&mut self.content_line, self.time_zones.as_deref_mut());
+        prop_value_writer.explicit_value_type = P::EXPLICIT_VALUE_TYPE;
+        value.write_into(prop_value_writer)
     }
 
     pub fn end(mut self) -> std::fmt::Result {
@@ -186,6 +287,13 @@ impl<'a, W: Write, P: Property> PropertyWriter<'a, W, P> {
         self.is_closed = true;
         self.content_line.eol()
     }
+
+    /// Give up on a property that could not be written, without ending it.
+    /// Only for properties written to a scratch buffer.
+    #[cfg(any(feature = "jcal", test))]
+    pub(crate) fn discard(self) {
+        self.content_line.discard()
+    }
 }
 
 // TODO figure out what to do with Drop here and in ContentLine
@@ -196,13 +304,208 @@ impl<'a, W: Write, P: Property> PropertyWriter<'a, W, P> {
 //     }
 // }
 
+/// Writer for a component with a name that is only known at runtime. See
+/// [ComponentWriter].
+pub struct DynamicComponentWriter<'a, W> {
+    inner: &'a mut Writer<W>,
+    name: DynamicName,
+    is_closed: bool,
+}
+
+impl<'a, W: Write> DynamicComponentWriter<'a, W> {
+    pub fn new(inner: &'a mut Writer<W>, name: &DynamicName) -> Result<Self, std::fmt::Error> {
+        inner.inner.simple_line(BEGIN_COMPONENT, name)?;
+
+        Ok(Self {
+            inner,
+            name: name.clone(),
+            is_closed: false,
+        })
+    }
+
+    pub fn end(mut self) -> std::fmt::Result {
+        self.is_closed = true;
+
+        self.inner.inner.simple_line(END_COMPONENT, &self.name)
+    }
+}
+
+impl<W> Drop for DynamicComponentWriter<'_, W> {
+    fn drop(&mut self) {
+        assert!(
+            self.is_closed,
+            "DynamicComponentWriter::end() must be called before drop"
+        );
+    }
+}
+
+impl<W: Write> Deref for DynamicComponentWriter<'_, W> {
+    type Target = Writer<W>;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<W: Write> DerefMut for DynamicComponentWriter<'_, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+    }
+}
+
+/// Writer for a property with a name that is only known at runtime. See
+/// [PropertyWriter].
+///
+/// The value type is selected when writing the value. The default value type
+/// of non-standard properties is TEXT, so the VALUE parameter is set for all
+/// other value types.
+///
+/// Reference: [RFC 5545
+/// 3.8.8.2](https://www.rfc-editor.org/rfc/rfc5545#section-3.8.8.2)
+pub struct DynamicPropertyWriter<'a, W> {
+    content_line: ContentLine<&'a mut W>,
+    time_zones: Option<&'a mut TimeZoneReferences>,
+    is_closed: bool,
+}
+
+impl<'a, W: Write> DynamicPropertyWriter<'a, W> {
+    pub fn new(inner: &'a mut LineStream<W>, name: &DynamicName) -> Result<Self, std::fmt::Error> {
+        let mut content_line = inner.content_line();
+        content_line.name(name)?;
+
+        Ok(Self {
+            content_line,
+            time_zones: None,
+            is_closed: false,
+        })
+    }
+
+    pub fn param<'x, 'y: 'x, PP: Param>(
+        &'y mut self,
+        _param: PP,
+        value: impl AsParamValue<PP::ParamValueType>,
+    ) -> std::fmt::Result {
+        self.content_line.param_name(PP::NAME)?;
+        value.write_to(&mut self.content_line)
+    }
+
+    /// Write a parameter with a name that is only known at runtime. The value
+    /// is quoted if it contains `;`, `:` or `,`.
+    pub fn dynamic_param(
+        &mut self,
+        name: &DynamicName,
+        value: impl std::fmt::Display,
+    ) -> std::fmt::Result {
+        let value = value.to_string();
+        if value.contains([';', ':', ',']) {
+            self.content_line.param_quoted(name, value)
+        } else {
+            self.content_line.param_unquoted(name, value)
+        }
+    }
+
+    /// Write a parameter with several values and a name that is only known
+    /// at runtime. Each value is quoted if it contains `;`, `:` or `,`.
+    pub fn dynamic_param_values(
+        &mut self,
+        name: &DynamicName,
+        values: impl IntoIterator<Item = impl std::fmt::Display>,
+    ) -> std::fmt::Result {
+        self.content_line.param_name(name)?;
+        for value in values {
+            let value = value.to_string();
+            if value.contains([';', ':', ',']) {
+                self.content_line.param_value_quoted(value)?;
+            } else {
+                self.content_line.param_value_unquoted(value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a value that is already formatted and escaped as iCalendar,
+    /// without setting the VALUE parameter
+    #[cfg(feature = "jcal")]
+    pub(crate) fn raw_value(&mut self, value: &str) -> std::fmt::Result {
+        assert!(!self.is_closed);
+        let mut tw = self.content_line.value_tuple_writer()?;
+        tw.next_raw_value_writer()?.write_str(value)
+    }
+
+    /// Write the value as the value type `V`
+    pub fn value<V: ValueType>(&mut self, value: impl AsCompositeValueType<V>) -> std::fmt::Result {
+        self.value_type_param::<V>()?;
+        value.write_into(PropertyValueWriter::new(
+            &mut self.content_line,
+            self.time_zones.as_deref_mut(),
+        ))
+    }
+
+    /// Write the values as a list of the value type `V`
+    pub fn list_value<V: ValueType>(
+        &mut self,
+        values: impl AsCompositeValueType<composite_value_types::List<V>>,
+    ) -> std::fmt::Result {
+        self.value_type_param::<V>()?;
+        values.write_into(PropertyValueWriter::new(
+            &mut self.content_line,
+            self.time_zones.as_deref_mut(),
+        ))
+    }
+
+    fn value_type_param<V: ValueType>(&mut self) -> std::fmt::Result {
+        assert!(!self.is_closed);
+
+        if V::NAME != value_types::Text::NAME {
+            self.param(icalstream::parameters::Value, V::NAME)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn end(mut self) -> std::fmt::Result {
+        self.is_closed = true;
+        self.content_line.eol()
+    }
+
+    /// Give up on a property that could not be written, without ending it.
+    /// Only for properties written to a scratch buffer.
+    #[cfg(feature = "jcal")]
+    pub(crate) fn discard(self) {
+        self.content_line.discard()
+    }
+}
+
 pub struct PropertyValueWriter<'a, 'b, W> {
     inner: &'a mut ContentLine<&'b mut W>,
+    time_zones: Option<&'a mut TimeZoneReferences>,
+    explicit_value_type: bool,
 }
 
 impl<'a, 'b: 'a, W: Write> PropertyValueWriter<'a, 'b, W> {
-    fn new(inner: &'a mut ContentLine<&'b mut W>) -> Self {
-        Self { inner }
+    fn new(
+        inner: &'a mut ContentLine<&'b mut W>,
+        time_zones: Option<&'a mut TimeZoneReferences>,
+    ) -> Self {
+        Self {
+            inner,
+            time_zones,
+            explicit_value_type: false,
+        }
+    }
+
+    /// Whether the VALUE parameter must be written even for the default value
+    /// type, see [Property::EXPLICIT_VALUE_TYPE]
+    pub fn is_value_type_explicit(&self) -> bool {
+        self.explicit_value_type
+    }
+
+    /// Record that the value refers to the time zone `tzid` at the given
+    /// point in time, as a UNIX timestamp.
+    pub fn reference_time_zone(&mut self, tzid: &str, timestamp: i64) {
+        if let Some(time_zones) = &mut self.time_zones {
+            time_zones.add(tzid, timestamp);
+        }
     }
 
     pub fn param<'x, 'y: 'x, PP: Param>(
@@ -251,32 +554,12 @@ mod test {
         type CompositeValueType = TextValue;
     }
 
-    pub struct TextValue;
-    impl ValueType for TextValue {
-        const NAME: &'static str = "TEXT";
-    }
-
-    impl<T> AsValueType<TextValue> for T
-    where
-        T: Display,
-    {
-        fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
-            write!(w, "{}", self)
-        }
-    }
-
-    impl ToValueType for &str {
-        type ValueType = TextValue;
-    }
+    use crate::structure::value_types::Text as TextValue;
 
     impl ToValueType for &&str {
         type ValueType = TextValue;
     }
 
-    impl ToValueType for String {
-        type ValueType = TextValue;
-    }
-
     impl ToValueType for fmt::Arguments<'_> {
         type ValueType = TextValue;
     }
@@ -321,6 +604,29 @@ mod test {
     impl IsA<Any2<TextValue, UnitValue>> for TextValue {}
     impl IsA<Any2<TextValue, UnitValue>> for UnitValue {}
 
+    impl IsA<Any2<TextValue, (UnitValue, UnitValue)>> for (UnitValue, UnitValue) {}
+
+    // A structured value with parts of different types has its own value
+    // type, with an explicit name
+    pub struct MixedValue;
+    impl ValueType for MixedValue {
+        const NAME: &'static str = "X-MIXED";
+
+        const ESCAPED: bool = false;
+    }
+
+    impl AsValueType<MixedValue> for ((), &str) {
+        fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+            write!(w, "();{}", self.1)
+        }
+    }
+
+    impl ToValueType for ((), &str) {
+        type ValueType = MixedValue;
+    }
+
+    impl IsA<Any2<(TextValue, TextValue), MixedValue>> for MixedValue {}
+
     #[test]
     fn singular_parameter() -> std::fmt::Result {
         struct SingularParam;
@@ -329,6 +635,7 @@ mod test {
 
             type ParamValueType = One<TextParamItem>;
         }
+        impl AcceptsParam<SingularParam> for TestProp {}
 
         let mut buf = String::new();
         let mut line_stream = LineStream::new(&mut buf);
@@ -352,6 +659,7 @@ mod test {
 
             type ParamValueType = SetOf<TextParamItem>;
         }
+        impl AcceptsParam<PluralParam> for TestProp {}
 
         let mut buf = String::new();
         let mut line_stream = LineStream::new(&mut buf);
@@ -378,6 +686,7 @@ mod test {
 
             type ParamValueType = One<CustomValue>;
         }
+        impl AcceptsParam<CustomParam> for TestProp {}
 
         struct CustomValue;
         impl ParamValueItem for CustomValue {
@@ -412,6 +721,22 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn dynamic_param() -> std::fmt::Result {
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut prop = PropertyWriter::<_, TestProp>::new(&mut line_stream)?;
+
+        prop.dynamic_param(&DynamicName::new("X-VENDOR-FLAG").unwrap(), "on")?;
+        prop.dynamic_param(&DynamicName::new("X-NOTE").unwrap(), "a;b")?;
+        prop.value("brille")?;
+        prop.end()?;
+
+        assert_eq!(&buf, "TEST;X-VENDOR-FLAG=on;X-NOTE=\"a;b\":brille\r\n");
+
+        Ok(())
+    }
+
     #[test]
     fn value_escaping() -> std::fmt::Result {
         let mut buf = String::new();
@@ -468,6 +793,110 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn value_tuple_escaping() -> std::fmt::Result {
+        struct TupleTestProp;
+        impl Property for TupleTestProp {
+            const NAME: &'static str = "TEST";
+
+            type CompositeValueType = (TextValue, TextValue);
+        }
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+        let mut prop = PropertyWriter::<_, TupleTestProp>::new(&mut line_stream)?;
+
+        prop.value(("a;b", "c,d"))?;
+        prop.end()?;
+
+        assert_eq!(&buf, "TEST:a\\;b;c\\,d\r\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn value_tuple_choice() -> std::fmt::Result {
+        struct ChoiceTestProp;
+        impl Property for ChoiceTestProp {
+            const NAME: &'static str = "TEST";
+
+            type CompositeValueType = Any2<TextValue, (TextValue, TextValue)>;
+        }
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, ChoiceTestProp>::new(&mut line_stream)?;
+        prop.value("2.0")?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, ChoiceTestProp>::new(&mut line_stream)?;
+        prop.value(("1.0", "2.0"))?;
+        prop.end()?;
+
+        // Both choices are TEXT, so there is no VALUE parameter
+        assert_eq!(&buf, "TEST:2.0\r\nTEST:1.0;2.0\r\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn value_tuple_name() -> std::fmt::Result {
+        struct ChoiceTestProp;
+        impl Property for ChoiceTestProp {
+            const NAME: &'static str = "TEST";
+
+            type CompositeValueType = Any2<(TextValue, TextValue), MixedValue>;
+        }
+
+        struct UnitTestProp;
+        impl Property for UnitTestProp {
+            const NAME: &'static str = "TEST";
+
+            type CompositeValueType = Any2<TextValue, (UnitValue, UnitValue)>;
+        }
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, UnitTestProp>::new(&mut line_stream)?;
+        prop.value(((), ()))?;
+        prop.end()?;
+
+        let mut prop = PropertyWriter::<_, ChoiceTestProp>::new(&mut line_stream)?;
+        prop.value(((), "x"))?;
+        prop.end()?;
+
+        assert_eq!(
+            &buf,
+            "TEST;VALUE=UNIT:();()\r\n\
+            TEST;VALUE=X-MIXED:();x\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn value_list_of_tuples() -> std::fmt::Result {
+        struct ListTestProp;
+        impl Property for ListTestProp {
+            const NAME: &'static str = "TEST";
+
+            type CompositeValueType = List<(TextValue, TextValue)>;
+        }
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, ListTestProp>::new(&mut line_stream)?;
+        prop.value([("a", "b"), ("c", "d")])?;
+        prop.end()?;
+
+        assert_eq!(&buf, "TEST:a;b,c;d\r\n");
+
+        Ok(())
+    }
+
     #[test]
     fn value_list() -> std::fmt::Result {
         struct ListTestProp;
@@ -525,6 +954,91 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn value_choice_positions() -> std::fmt::Result {
+        use crate::structure::composite_value_types::{Any3, Any4, Any5, Any6, Any7, Any8};
+
+        // A value type and a matching Rust type for each position
+        macro_rules! positional_value_types {
+            ($( $v:ident / $r:ident ),+) => { $(
+                struct $v;
+                impl ValueType for $v {
+                    const NAME: &'static str = stringify!($v);
+                }
+
+                struct $r;
+                impl AsValueType<$v> for $r {
+                    fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                        write!(w, stringify!($r))
+                    }
+                }
+                impl ToValueType for $r {
+                    type ValueType = $v;
+                }
+            )+ };
+        }
+
+        positional_value_types!(
+            P0 / R0,
+            P1 / R1,
+            P2 / R2,
+            P3 / R3,
+            P4 / R4,
+            P5 / R5,
+            P6 / R6,
+            P7 / R7
+        );
+
+        macro_rules! check_choice {
+            ($any:ty : $( $v:ident / $r:ident ),+) => {{
+                $( impl IsA<$any> for $v {} )+
+
+                struct ChoiceTestProp;
+                impl Property for ChoiceTestProp {
+                    const NAME: &'static str = "TEST";
+
+                    type CompositeValueType = $any;
+                }
+
+                let mut buf = String::new();
+                let mut line_stream = LineStream::new(&mut buf);
+                $(
+                    let mut prop = PropertyWriter::<_, ChoiceTestProp>::new(&mut line_stream)?;
+                    prop.value($r)?;
+                    prop.end()?;
+                )+
+
+                // Only the first, default, type goes without a VALUE parameter
+                let expected: String = (0..[$( stringify!($r) ),+].len())
+                    .map(|i| match i {
+                        0 => "TEST:R0\r\n".to_owned(),
+                        i => format!("TEST;VALUE=P{i}:R{i}\r\n"),
+                    })
+                    .collect();
+                assert_eq!(buf, expected);
+            }};
+        }
+
+        check_choice!(Any2<P0, P1> : P0 / R0, P1 / R1);
+        check_choice!(Any3<P0, P1, P2> : P0 / R0, P1 / R1, P2 / R2);
+        check_choice!(Any4<P0, P1, P2, P3> : P0 / R0, P1 / R1, P2 / R2, P3 / R3);
+        check_choice!(Any5<P0, P1, P2, P3, P4> : P0 / R0, P1 / R1, P2 / R2, P3 / R3, P4 / R4);
+        check_choice!(
+            Any6<P0, P1, P2, P3, P4, P5> :
+            P0 / R0, P1 / R1, P2 / R2, P3 / R3, P4 / R4, P5 / R5
+        );
+        check_choice!(
+            Any7<P0, P1, P2, P3, P4, P5, P6> :
+            P0 / R0, P1 / R1, P2 / R2, P3 / R3, P4 / R4, P5 / R5, P6 / R6
+        );
+        check_choice!(
+            Any8<P0, P1, P2, P3, P4, P5, P6, P7> :
+            P0 / R0, P1 / R1, P2 / R2, P3 / R3, P4 / R4, P5 / R5, P6 / R6, P7 / R7
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn value_list_choice() -> std::fmt::Result {
         struct ListTestProp;
@@ -573,4 +1087,51 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn list_with_different_params() -> std::fmt::Result {
+        struct Zoned(&'static str);
+
+        impl AsValueType<UnitValue> for Zoned {
+            fn fmt<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+                write!(w, "()")
+            }
+
+            fn write_params<W: Write>(
+                &self,
+                w: &mut PropertyValueWriter<'_, '_, W>,
+            ) -> std::fmt::Result {
+                w.param(
+                    crate::structure::icalstream::parameters::TimeZoneIdentifier,
+                    self.0,
+                )
+            }
+
+            fn has_same_params(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl ToValueType for Zoned {
+            type ValueType = UnitValue;
+        }
+
+        struct ListTestProp;
+        impl Property for ListTestProp {
+            const NAME: &'static str = "TEST";
+
+            type CompositeValueType = List<UnitValue>;
+        }
+
+        let mut buf = String::new();
+        let mut line_stream = LineStream::new(&mut buf);
+
+        let mut prop = PropertyWriter::<_, ListTestProp>::new(&mut line_stream)?;
+        assert!(prop.value([Zoned("A"), Zoned("B")]).is_err());
+        prop.discard();
+
+        // Nothing but the name has been written
+        assert_eq!(&buf, "TEST");
+        Ok(())
+    }
 }